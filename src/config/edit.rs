@@ -0,0 +1,767 @@
+use std::collections::{HashMap, HashSet};
+
+use toml_edit::{ArrayOfTables, DocumentMut, Item, Table, TableLike, Value};
+
+use crate::config::profile::ProfileConfig;
+use crate::error::ConfigError;
+
+/// Update `doc` in place so it holds the same data as `updated` (a serialized
+/// config), keeping everything the user wrote that is still valid.
+///
+/// Unchanged values keep their formatting, changed values keep the comments
+/// around them, removed keys are dropped, and new tables are placed after the
+/// table that precedes them in `updated` rather than at the end of the file.
+pub fn update_document(doc: &mut DocumentMut, updated: &str) -> Result<(), ConfigError> {
+    let updated: DocumentMut = updated.parse()?;
+
+    let mut old_positions: HashMap<String, usize> = HashMap::new();
+    for (path, position) in table_paths(doc.as_table()) {
+        if let Some(position) = position {
+            old_positions.insert(path, position);
+        }
+    }
+
+    update_table(doc.as_table_mut(), updated.as_table());
+
+    let merged: Vec<String> = table_paths(doc.as_table())
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect();
+    let mut new_order: Vec<(String, Option<usize>)> = table_paths(updated.as_table());
+    new_order.sort_by_key(|(_, position)| *position);
+    let order: Vec<String> = table_order(&merged, &old_positions, &new_order);
+    let positions: HashMap<String, usize> = order
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| (path, index + 1))
+        .collect();
+    assign_positions(doc.as_table_mut(), "", &positions);
+    Ok(())
+}
+
+fn update_table(old: &mut dyn TableLike, new: &dyn TableLike) {
+    let stale: Vec<String> = old
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key: &String| !new.contains_key(key))
+        .collect();
+    for key in stale {
+        old.remove(&key);
+    }
+
+    for (key, new_item) in new.iter() {
+        match old.get_mut(key) {
+            Some(old_item) => update_item(old_item, new_item),
+            None => {
+                old.insert(key, new_item.clone());
+            }
+        }
+    }
+}
+
+fn update_item(old: &mut Item, new: &Item) {
+    if let (Some(old_table), Some(new_table)) = (old.as_table_like_mut(), new.as_table_like()) {
+        update_table(old_table, new_table);
+        return;
+    }
+    match (old, new) {
+        (Item::ArrayOfTables(old_array), Item::ArrayOfTables(new_array)) => {
+            update_array_of_tables(old_array, new_array);
+        }
+        (Item::Value(old_value), Item::ArrayOfTables(new_array)) => {
+            // The user wrote the array inline, so keep it inline.
+            let new_value: Value = Value::Array(new_array.clone().into_array());
+            update_value(old_value, &new_value);
+        }
+        (Item::Value(old_value), Item::Value(new_value)) => update_value(old_value, new_value),
+        (old, new) => *old = new.clone(),
+    }
+}
+
+/// Fields that identify an entry in an array of tables, most specific first.
+const ENTRY_KEYS: &[&str] = &["repo", "name", "title", "url"];
+
+/// Match entries by their identifying field rather than by index, so an
+/// entry's comments stay with it when entries are reordered or removed.
+/// Entries without one are matched by index against other such entries.
+fn update_array_of_tables(old: &mut ArrayOfTables, new: &ArrayOfTables) {
+    let mut remaining: Vec<Option<Table>> = old.iter().cloned().map(Some).collect();
+    let mut merged = ArrayOfTables::new();
+    for (index, new_table) in new.iter().enumerate() {
+        let key: Option<(&str, &str)> = entry_key(new_table);
+        let matched: Option<usize> = remaining.iter().enumerate().position(
+            |(i, old_table): (usize, &Option<Table>)| match (old_table, key) {
+                (Some(old_table), Some(_)) => entry_key(old_table) == key,
+                (Some(old_table), None) => i == index && entry_key(old_table).is_none(),
+                (None, _) => false,
+            },
+        );
+        let table: Table = match matched.and_then(|i: usize| remaining[i].take()) {
+            Some(mut old_table) => {
+                update_table(&mut old_table, new_table);
+                old_table
+            }
+            None => new_table.clone(),
+        };
+        merged.push(table);
+    }
+    *old = merged;
+}
+
+fn entry_key(table: &Table) -> Option<(&'static str, &str)> {
+    ENTRY_KEYS.iter().find_map(|key: &&'static str| {
+        table
+            .get(key)
+            .and_then(Item::as_str)
+            .map(|value: &str| (*key, value))
+    })
+}
+
+/// Replace a value only if it changed, keeping the comments around it.
+fn update_value(old: &mut Value, new: &Value) {
+    if values_equal(old, new) {
+        return;
+    }
+    let decor = old.decor().clone();
+    *old = new.clone();
+    *old.decor_mut() = decor;
+}
+
+/// Compare values by content, ignoring formatting.
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|(a, b): (&Value, &Value)| values_equal(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(key, value): (&str, &Value)| {
+                    b.get(key)
+                        .is_some_and(|other: &Value| values_equal(value, other))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Every table with a header, keyed by path (`projects.featured[0]`), with its position.
+fn table_paths(table: &Table) -> Vec<(String, Option<usize>)> {
+    let mut paths: Vec<(String, Option<usize>)> = Vec::new();
+    collect_table_paths(table, "", &mut paths);
+    paths
+}
+
+fn collect_table_paths(table: &Table, prefix: &str, out: &mut Vec<(String, Option<usize>)>) {
+    for (key, item) in table.iter() {
+        let path: String = join_path(prefix, key);
+        match item {
+            Item::Table(child) => {
+                out.push((path.clone(), child.position()));
+                collect_table_paths(child, &path, out);
+            }
+            Item::ArrayOfTables(array) => {
+                for (index, child) in array.iter().enumerate() {
+                    let entry: String = format!("{}[{}]", path, index);
+                    out.push((entry.clone(), child.position()));
+                    collect_table_paths(child, &entry, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Existing tables keep their order; each new table goes after its predecessor
+/// in `new_order` (and after that predecessor's subtables).
+fn table_order(
+    merged: &[String],
+    old_positions: &HashMap<String, usize>,
+    new_order: &[(String, Option<usize>)],
+) -> Vec<String> {
+    let mut order: Vec<String> = merged
+        .iter()
+        .filter(|path: &&String| old_positions.contains_key(*path))
+        .cloned()
+        .collect();
+    order.sort_by_key(|path: &String| old_positions[path]);
+
+    let merged: HashSet<&String> = merged.iter().collect();
+    let mut previous: Option<&String> = None;
+    for (path, _) in new_order {
+        if !merged.contains(path) {
+            continue;
+        }
+        if !order.contains(path) {
+            let index: usize = match previous {
+                Some(previous) => {
+                    let mut index: usize = order
+                        .iter()
+                        .position(|p: &String| p == previous)
+                        .map_or(order.len(), |i: usize| i + 1);
+                    while index < order.len() && is_descendant(&order[index], previous) {
+                        index += 1;
+                    }
+                    index
+                }
+                None => 0,
+            };
+            order.insert(index, path.clone());
+        }
+        previous = Some(path);
+    }
+    order
+}
+
+fn is_descendant(path: &str, ancestor: &str) -> bool {
+    path.strip_prefix(ancestor)
+        .is_some_and(|rest: &str| rest.starts_with('.') || rest.starts_with('['))
+}
+
+fn assign_positions(table: &mut Table, prefix: &str, positions: &HashMap<String, usize>) {
+    for (key, item) in table.iter_mut() {
+        let path: String = join_path(prefix, key.get());
+        match item {
+            Item::Table(child) => {
+                if let Some(position) = positions.get(&path) {
+                    child.set_position(*position);
+                }
+                assign_positions(child, &path, positions);
+            }
+            Item::ArrayOfTables(array) => {
+                for (index, child) in array.iter_mut().enumerate() {
+                    let entry: String = format!("{}[{}]", path, index);
+                    if let Some(position) = positions.get(&entry) {
+                        child.set_position(*position);
+                    }
+                    assign_positions(child, &entry, positions);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// One step of a field path: a table key or an array index.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a dotted field path such as `about.role` or `projects.featured[0].description`.
+fn parse_path(path: &str) -> Result<Vec<Segment>, ConfigError> {
+    let invalid = || ConfigError::InvalidPath(path.to_string());
+    let mut segments: Vec<Segment> = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest): (&str, &str) = match part.find('[') {
+            Some(i) => (&part[..i], &part[i..]),
+            None => (part, ""),
+        };
+        if key.is_empty() {
+            return Err(invalid());
+        }
+        segments.push(Segment::Key(key.to_string()));
+        while let Some(inner) = rest.strip_prefix('[') {
+            let end: usize = inner.find(']').ok_or_else(invalid)?;
+            let index: usize = inner[..end].parse().map_err(|_| invalid())?;
+            segments.push(Segment::Index(index));
+            rest = &inner[end + 1..];
+        }
+        if !rest.is_empty() {
+            return Err(invalid());
+        }
+    }
+    Ok(segments)
+}
+
+/// Set a field from its command-line spelling, e.g. `("stats.top_langs", "true")`.
+///
+/// The raw text is tried as a plain string first and then as a TOML literal
+/// (`8`, `true`, `["Rust", "Go"]`); the first one the field accepts wins.
+pub fn set_field(table: &mut toml::Table, path: &str, raw: &str) -> Result<(), ConfigError> {
+    let segments: Vec<Segment> = parse_path(path)?;
+    let mut candidates: Vec<toml::Value> = vec![toml::Value::String(raw.to_string())];
+    if let Ok(mut literal) = toml::from_str::<toml::Table>(&format!("value = {}", raw))
+        && let Some(value) = literal.remove("value")
+        && !value.is_str()
+    {
+        candidates.push(value);
+    }
+
+    let mut last_error: Option<ConfigError> = None;
+    for candidate in candidates {
+        let mut trial = toml::Value::Table(table.clone());
+        set_at(&mut trial, &segments, candidate, path)?;
+        let toml::Value::Table(trial) = trial else {
+            unreachable!("the root is always a table")
+        };
+        match validate(&trial, &segments, path) {
+            Ok(()) => {
+                *table = trial;
+                return Ok(());
+            }
+            Err(err @ ConfigError::UnknownField(_)) => return Err(err),
+            Err(err) => last_error = Some(err),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| ConfigError::InvalidPath(path.to_string())))
+}
+
+/// Append an entry to the array at `path`, creating it if needed.
+///
+/// Entries are compared by their label (the string itself, or the first field
+/// of a table such as `repo` or `title`), ignoring case.
+pub fn append_entry(
+    table: &mut toml::Table,
+    path: &str,
+    entry: toml::Value,
+) -> Result<(), ConfigError> {
+    let segments: Vec<Segment> = parse_path(path)?;
+    let mut trial = toml::Value::Table(table.clone());
+    let label: String = entry_label(&entry).unwrap_or_default();
+    match get_mut(&mut trial, &segments) {
+        Some(toml::Value::Array(items)) => {
+            let duplicate: bool = items.iter().any(|item: &toml::Value| {
+                entry_label(item).is_some_and(|l: String| l.eq_ignore_ascii_case(&label))
+            });
+            if duplicate {
+                return Err(ConfigError::DuplicateEntry {
+                    path: path.to_string(),
+                    value: label,
+                });
+            }
+            items.push(entry);
+        }
+        Some(_) => return Err(ConfigError::InvalidPath(path.to_string())),
+        None => set_at(&mut trial, &segments, toml::Value::Array(vec![entry]), path)?,
+    }
+    let toml::Value::Table(trial) = trial else {
+        unreachable!("the root is always a table")
+    };
+    validate(&trial, &segments, path)?;
+    *table = trial;
+    Ok(())
+}
+
+/// Remove the field at `path`, along with any tables or arrays left empty.
+pub fn remove_field(table: &mut toml::Table, path: &str) -> Result<(), ConfigError> {
+    let segments: Vec<Segment> = parse_path(path)?;
+    let (last, parents) = segments
+        .split_last()
+        .ok_or_else(|| ConfigError::InvalidPath(path.to_string()))?;
+    let mut root = toml::Value::Table(std::mem::take(table));
+    let removed: bool = match (get_mut(&mut root, parents), last) {
+        (Some(toml::Value::Table(parent)), Segment::Key(key)) => parent.remove(key).is_some(),
+        (Some(toml::Value::Array(parent)), Segment::Index(index)) if *index < parent.len() => {
+            parent.remove(*index);
+            true
+        }
+        _ => false,
+    };
+    prune_empty(&mut root, parents);
+    if let toml::Value::Table(root) = root {
+        *table = root;
+    }
+    if removed {
+        Ok(())
+    } else {
+        Err(ConfigError::MissingField(path.to_string()))
+    }
+}
+
+/// Remove the entry matching `value` from the array at `path`.
+///
+/// A string entry matches if it equals `value`; a table entry matches if any
+/// of its string fields does. Comparison ignores case.
+pub fn remove_entry(table: &mut toml::Table, path: &str, value: &str) -> Result<(), ConfigError> {
+    let segments: Vec<Segment> = parse_path(path)?;
+    let mut root = toml::Value::Table(std::mem::take(table));
+    let removed: bool = match get_mut(&mut root, &segments) {
+        Some(toml::Value::Array(items)) => {
+            match items
+                .iter()
+                .position(|item: &toml::Value| entry_matches(item, value))
+            {
+                Some(index) => {
+                    items.remove(index);
+                    true
+                }
+                None => false,
+            }
+        }
+        _ => false,
+    };
+    prune_empty(&mut root, &segments);
+    if let toml::Value::Table(root) = root {
+        *table = root;
+    }
+    if removed {
+        Ok(())
+    } else {
+        Err(ConfigError::MissingField(format!("{} ({})", path, value)))
+    }
+}
+
+fn get_mut<'a>(value: &'a mut toml::Value, segments: &[Segment]) -> Option<&'a mut toml::Value> {
+    segments.iter().try_fold(
+        value,
+        |current: &mut toml::Value, segment: &Segment| match (current, segment) {
+            (toml::Value::Table(table), Segment::Key(key)) => table.get_mut(key),
+            (toml::Value::Array(items), Segment::Index(index)) => items.get_mut(*index),
+            _ => None,
+        },
+    )
+}
+
+fn get<'a>(value: &'a toml::Value, segments: &[Segment]) -> Option<&'a toml::Value> {
+    segments
+        .iter()
+        .try_fold(value, |current: &toml::Value, segment: &Segment| {
+            match (current, segment) {
+                (toml::Value::Table(table), Segment::Key(key)) => table.get(key),
+                (toml::Value::Array(items), Segment::Index(index)) => items.get(*index),
+                _ => None,
+            }
+        })
+}
+
+/// Set the value at `segments`, creating missing tables along the way.
+fn set_at(
+    root: &mut toml::Value,
+    segments: &[Segment],
+    value: toml::Value,
+    path: &str,
+) -> Result<(), ConfigError> {
+    let invalid = || ConfigError::InvalidPath(path.to_string());
+    let (last, parents) = segments.split_last().ok_or_else(invalid)?;
+    let mut current: &mut toml::Value = root;
+    for segment in parents {
+        current = match (current, segment) {
+            (toml::Value::Table(table), Segment::Key(key)) => table
+                .entry(key.clone())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new())),
+            (toml::Value::Array(items), Segment::Index(index)) => {
+                items.get_mut(*index).ok_or_else(invalid)?
+            }
+            _ => return Err(invalid()),
+        };
+    }
+    match (current, last) {
+        (toml::Value::Table(table), Segment::Key(key)) => {
+            table.insert(key.clone(), value);
+        }
+        (toml::Value::Array(items), Segment::Index(index)) if *index < items.len() => {
+            items[*index] = value;
+        }
+        _ => return Err(invalid()),
+    }
+    Ok(())
+}
+
+/// Drop tables and arrays along `segments` that were left empty, deepest first.
+fn prune_empty(value: &mut toml::Value, segments: &[Segment]) {
+    let Some((first, rest)) = segments.split_first() else {
+        return;
+    };
+    let toml::Value::Table(table) = value else {
+        return;
+    };
+    let Segment::Key(key) = first else {
+        return;
+    };
+    let Some(child) = table.get_mut(key) else {
+        return;
+    };
+    prune_empty(child, rest);
+    let empty: bool = match child {
+        toml::Value::Table(t) => t.is_empty(),
+        toml::Value::Array(a) => a.is_empty(),
+        _ => false,
+    };
+    if empty {
+        table.remove(key);
+    }
+}
+
+fn entry_label(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Table(table) => table.values().next()?.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn entry_matches(value: &toml::Value, needle: &str) -> bool {
+    match value {
+        toml::Value::String(s) => s.eq_ignore_ascii_case(needle),
+        toml::Value::Table(table) => table.values().any(|v: &toml::Value| {
+            v.as_str()
+                .is_some_and(|s: &str| s.eq_ignore_ascii_case(needle))
+        }),
+        _ => false,
+    }
+}
+
+/// Check that `path` names a real `ProfileConfig` field and holds a value of
+/// the right type. Only the section being edited is checked, so unrelated
+/// `${VAR}` or `{ file = ... }` values elsewhere in the file don't get in the way.
+fn validate(table: &toml::Table, segments: &[Segment], path: &str) -> Result<(), ConfigError> {
+    let Some(Segment::Key(section)) = segments.first() else {
+        return Err(ConfigError::InvalidPath(path.to_string()));
+    };
+    let mut meta = toml::Table::new();
+    meta.insert("username".to_string(), toml::Value::from(""));
+    let mut probe = toml::Table::new();
+    probe.insert("meta".to_string(), toml::Value::Table(meta));
+    if let Some(value) = table.get(section) {
+        let mut value: toml::Value = value.clone();
+        if let (toml::Value::Table(meta), "meta") = (&mut value, section.as_str()) {
+            meta.entry("username")
+                .or_insert_with(|| toml::Value::from(""));
+        }
+        probe.insert(section.clone(), value);
+    }
+
+    let config: ProfileConfig =
+        toml::Value::Table(probe)
+            .try_into()
+            .map_err(|e: toml::de::Error| ConfigError::InvalidValue {
+                path: path.to_string(),
+                message: e.message().trim().to_string(),
+            })?;
+    let written = toml::Value::try_from(&config)?;
+    if get(&written, segments).is_none() {
+        return Err(ConfigError::UnknownField(path.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(original: &str, updated: &str) -> String {
+        let mut doc: DocumentMut = original.parse().unwrap();
+        update_document(&mut doc, updated).unwrap();
+        doc.to_string()
+    }
+
+    #[test]
+    fn test_update_keeps_comments_and_order() {
+        let original = "# My profile\n[meta]\nusername = \"alice\"  # GitHub login\nname = \"Alice\"\n\n# What I do\n[about]\nrole = \"Engineer\"\n";
+        let updated = "[meta]\nusername = \"alice\"\nname = \"Alice\"\n\n[about]\nrole = \"Staff Engineer\"\n";
+
+        assert_eq!(
+            update(original, updated),
+            "# My profile\n[meta]\nusername = \"alice\"  # GitHub login\nname = \"Alice\"\n\n# What I do\n[about]\nrole = \"Staff Engineer\"\n"
+        );
+    }
+
+    #[test]
+    fn test_update_removes_and_adds_keys() {
+        let original = "[meta]\nusername = \"alice\"\nname = \"Alice\"\n";
+        let updated = "[meta]\nusername = \"alice\"\nschema_version = 2\n";
+
+        assert_eq!(
+            update(original, updated),
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n"
+        );
+    }
+
+    #[test]
+    fn test_new_table_goes_after_its_predecessor() {
+        let original = "[meta]\nusername = \"alice\"\n\n# Stats\n[stats]\ntheme = \"dark\"\n";
+        let updated = "[meta]\nusername = \"alice\"\n\n[about]\nrole = \"Engineer\"\n\n[stats]\ntheme = \"dark\"\n";
+
+        assert_eq!(
+            update(original, updated),
+            "[meta]\nusername = \"alice\"\n\n[about]\nrole = \"Engineer\"\n\n# Stats\n[stats]\ntheme = \"dark\"\n"
+        );
+    }
+
+    #[test]
+    fn test_new_table_skips_predecessor_subtables() {
+        let original =
+            "[meta]\nusername = \"alice\"\n\n[[projects.featured]]\nrepo = \"alice/a\"\n";
+        let updated = "[meta]\nusername = \"alice\"\n\n[[projects.featured]]\nrepo = \"alice/a\"\n\n[[projects.featured]]\nrepo = \"alice/b\"\n\n[blog]\nyoutube = \"https://youtube.com/@alice\"\n";
+
+        let result: String = update(original, updated);
+        let a: usize = result.find("alice/a").unwrap();
+        let b: usize = result.find("alice/b").unwrap();
+        let blog: usize = result.find("[blog]").unwrap();
+        assert!(a < b && b < blog, "{}", result);
+    }
+
+    #[test]
+    fn test_array_entries_keep_comments_when_reordered() {
+        let original = "[meta]\nusername = \"alice\"\n\n# Main project\n[[projects.featured]]\nrepo = \"alice/a\"\n\n# Side project\n[[projects.featured]]\nrepo = \"alice/b\"\n\n# Old project\n[[projects.featured]]\nrepo = \"alice/c\"\n";
+        let updated = "[meta]\nusername = \"alice\"\n\n[[projects.featured]]\nrepo = \"alice/b\"\n\n[[projects.featured]]\nrepo = \"alice/a\"\ndescription = \"Main\"\n";
+
+        assert_eq!(
+            update(original, updated),
+            "[meta]\nusername = \"alice\"\n\n# Side project\n[[projects.featured]]\nrepo = \"alice/b\"\n\n# Main project\n[[projects.featured]]\nrepo = \"alice/a\"\ndescription = \"Main\"\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_values_stay_inline() {
+        let original = "skills = { languages = [\"Rust\"] }\nprojects = { featured = [{ repo = \"alice/a\" }] }\n\n[meta]\nusername = \"alice\"\n";
+        let updated = "[meta]\nusername = \"alice\"\n\n[skills]\nlanguages = [\"Rust\", \"Go\"]\n\n[[projects.featured]]\nrepo = \"alice/b\"\n";
+
+        let result: String = update(original, updated);
+        assert!(result.contains("skills = { languages = [\"Rust\", \"Go\"] }"));
+        assert!(result.contains("featured = [{ repo = \"alice/b\" }]"));
+    }
+
+    #[test]
+    fn test_unchanged_values_keep_formatting() {
+        let original = "[skills]\nlanguages = [ 'Rust',  'Go' ]\n";
+        let updated = "[skills]\nlanguages = [\"Rust\", \"Go\"]\n";
+
+        assert_eq!(update(original, updated), original);
+    }
+
+    fn table(content: &str) -> toml::Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("projects.featured[1].repo").unwrap(),
+            vec![
+                Segment::Key("projects".to_string()),
+                Segment::Key("featured".to_string()),
+                Segment::Index(1),
+                Segment::Key("repo".to_string()),
+            ]
+        );
+        for bad in ["", "about..role", "a[x]", "a[1", "a[1]b"] {
+            assert!(
+                matches!(parse_path(bad), Err(ConfigError::InvalidPath(_))),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_field_picks_the_field_type() {
+        let mut config: toml::Table = table("[meta]\nusername = \"alice\"\n");
+        set_field(&mut config, "about.role", "Staff Engineer").unwrap();
+        set_field(&mut config, "stats.top_langs", "true").unwrap();
+        set_field(&mut config, "stats.top_langs_count", "8").unwrap();
+        set_field(&mut config, "dynamic.stackoverflow_uid", "12345").unwrap();
+        set_field(&mut config, "skills.languages", "[\"Rust\", \"Go\"]").unwrap();
+
+        assert_eq!(config["about"]["role"].as_str(), Some("Staff Engineer"));
+        assert_eq!(config["stats"]["top_langs"].as_bool(), Some(true));
+        assert_eq!(config["stats"]["top_langs_count"].as_integer(), Some(8));
+        assert_eq!(
+            config["dynamic"]["stackoverflow_uid"].as_str(),
+            Some("12345")
+        );
+        assert_eq!(config["skills"]["languages"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_set_field_rejects_unknown_and_mistyped() {
+        let mut config: toml::Table = table("[meta]\nusername = \"alice\"\n");
+        assert!(matches!(
+            set_field(&mut config, "about.rolee", "x"),
+            Err(ConfigError::UnknownField(_))
+        ));
+        let err: ConfigError = set_field(&mut config, "layout.template", "fancy").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown variant `fancy`"),
+            "{}",
+            err
+        );
+        assert!(matches!(
+            set_field(&mut config, "stats.top_langs_count", "lots"),
+            Err(ConfigError::InvalidValue { .. })
+        ));
+        assert_eq!(config, table("[meta]\nusername = \"alice\"\n"));
+    }
+
+    #[test]
+    fn test_set_field_ignores_other_sections() {
+        // `{ file = ... }` is only valid before interpolation; editing [about]
+        // must not trip over it.
+        let mut config: toml::Table = table(
+            "[meta]\nusername = \"alice\"\n\n[extras]\ncustom_blocks = [{ file = \"a.md\" }]\n",
+        );
+        set_field(&mut config, "about.role", "Engineer").unwrap();
+        assert_eq!(config["about"]["role"].as_str(), Some("Engineer"));
+    }
+
+    #[test]
+    fn test_append_entry() {
+        let mut config: toml::Table = table("[meta]\nusername = \"alice\"\n");
+        append_entry(&mut config, "skills.languages", "Rust".into()).unwrap();
+        append_entry(&mut config, "skills.languages", "Go".into()).unwrap();
+        assert!(matches!(
+            append_entry(&mut config, "skills.languages", "rust".into()),
+            Err(ConfigError::DuplicateEntry { .. })
+        ));
+        assert!(matches!(
+            append_entry(&mut config, "skills.lang", "Rust".into()),
+            Err(ConfigError::UnknownField(_))
+        ));
+
+        let project: toml::Value = toml::Value::Table(table("repo = \"alice/tool\"\n"));
+        append_entry(&mut config, "projects.featured", project).unwrap();
+        assert_eq!(
+            config["projects"]["featured"][0]["repo"].as_str(),
+            Some("alice/tool")
+        );
+        assert_eq!(config["skills"]["languages"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_remove_field_prunes_empty_tables() {
+        let mut config: toml::Table = table(
+            "[meta]\nusername = \"alice\"\n\n[social]\ntwitter = \"https://twitter.com/alice\"\n",
+        );
+        remove_field(&mut config, "social.twitter").unwrap();
+        assert!(!config.contains_key("social"));
+        assert!(matches!(
+            remove_field(&mut config, "social.twitter"),
+            Err(ConfigError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn test_remove_entry_matches_labels() {
+        let mut config: toml::Table = table(
+            "[skills]\nlanguages = [\"Rust\", \"Go\"]\n\n[[projects.featured]]\nrepo = \"alice/a\"\n\n[[projects.featured]]\nrepo = \"alice/b\"\n",
+        );
+        remove_entry(&mut config, "skills.languages", "rust").unwrap();
+        remove_entry(&mut config, "projects.featured", "alice/a").unwrap();
+        assert_eq!(
+            config["skills"]["languages"].as_array().unwrap(),
+            &vec![toml::Value::from("Go")]
+        );
+        assert_eq!(
+            config["projects"]["featured"][0]["repo"].as_str(),
+            Some("alice/b")
+        );
+        assert!(remove_entry(&mut config, "skills.languages", "Zig").is_err());
+    }
+}
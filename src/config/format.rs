@@ -0,0 +1,181 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::error::ConfigError;
+
+/// On-disk syntax of a profile config.
+///
+/// All formats share the TOML data model: YAML and JSON documents are
+/// converted to TOML tables before migration, interpolation and merging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigFormat {
+    #[default]
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub const ALL: [ConfigFormat; 3] = [ConfigFormat::Toml, ConfigFormat::Yaml, ConfigFormat::Json];
+
+    /// Guess the format from a file extension (`.toml`, `.yaml`/`.yml`, `.json`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+
+    /// An explicit format wins; otherwise use the extension, falling back to TOML.
+    pub fn resolve(path: &Path, explicit: Option<Self>) -> Self {
+        explicit
+            .or_else(|| Self::from_path(path))
+            .unwrap_or_default()
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConfigFormat::Toml => "toml",
+            ConfigFormat::Yaml => "yaml",
+            ConfigFormat::Json => "json",
+        }
+    }
+
+    /// Parse a document into a TOML table. `null` values are dropped, since
+    /// TOML has no null and every optional field treats "absent" the same way.
+    pub fn parse_table(&self, content: &str) -> Result<toml::Table, ConfigError> {
+        let value: serde_json::Value = match self {
+            ConfigFormat::Toml => return Ok(toml::from_str(content)?),
+            ConfigFormat::Yaml => serde_yaml_ng::from_str(content)?,
+            ConfigFormat::Json => serde_json::from_str(content)?,
+        };
+        let value: serde_json::Value = strip_nulls(value);
+        let table: toml::Table = match value {
+            serde_json::Value::Null => toml::Table::new(),
+            other => toml::Table::try_from(other)?,
+        };
+        Ok(table)
+    }
+
+    /// Re-encode a document as TOML text so the TOML-based pipeline can read it.
+    pub fn to_toml_string(&self, content: &str) -> Result<String, ConfigError> {
+        match self {
+            ConfigFormat::Toml => Ok(content.to_string()),
+            _ => Ok(toml::to_string(&self.parse_table(content)?)?),
+        }
+    }
+
+    /// Serialize a value in this format. Unset optional fields are omitted.
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, ConfigError> {
+        // Going through a TOML value drops `None` fields in every format.
+        let value: toml::Value = toml::Value::try_from(value)?;
+        match self {
+            ConfigFormat::Toml => Ok(toml::to_string_pretty(&value)?),
+            ConfigFormat::Yaml => Ok(serde_yaml_ng::to_string(&value)?),
+            ConfigFormat::Json => Ok(format!("{}\n", serde_json::to_string_pretty(&value)?)),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "toml" => Ok(ConfigFormat::Toml),
+            "yaml" | "yml" => Ok(ConfigFormat::Yaml),
+            "json" => Ok(ConfigFormat::Json),
+            _ => Err(ConfigError::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+fn strip_nulls(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .filter(|v: &serde_json::Value| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_from_path() {
+        let cases: [(&str, Option<ConfigFormat>); 5] = [
+            ("profile.toml", Some(ConfigFormat::Toml)),
+            ("profile.yaml", Some(ConfigFormat::Yaml)),
+            ("profile.YML", Some(ConfigFormat::Yaml)),
+            ("profile.json", Some(ConfigFormat::Json)),
+            ("profile", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(ConfigFormat::from_path(&PathBuf::from(path)), expected);
+        }
+        assert_eq!(
+            ConfigFormat::resolve(Path::new("profile.json"), Some(ConfigFormat::Yaml)),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::resolve(Path::new("profile.txt"), None),
+            ConfigFormat::Toml
+        );
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("YAML".parse::<ConfigFormat>().unwrap(), ConfigFormat::Yaml);
+        assert!(matches!(
+            "ini".parse::<ConfigFormat>(),
+            Err(ConfigError::UnknownFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_table_drops_nulls() {
+        let json = r#"{"meta": {"username": "alice", "name": null}, "skills": {"languages": ["Rust", null]}}"#;
+        let table: toml::Table = ConfigFormat::Json.parse_table(json).unwrap();
+        assert_eq!(table["meta"]["username"].as_str(), Some("alice"));
+        assert!(table["meta"].get("name").is_none());
+        assert_eq!(table["skills"]["languages"].as_array().unwrap().len(), 1);
+
+        let yaml = "meta:\n  username: bob\n  name: ~\n";
+        let table: toml::Table = ConfigFormat::Yaml.parse_table(yaml).unwrap();
+        assert_eq!(table["meta"]["username"].as_str(), Some("bob"));
+        assert!(table["meta"].get("name").is_none());
+    }
+
+    #[test]
+    fn test_yaml_to_toml_string() {
+        let toml_str: String = ConfigFormat::Yaml
+            .to_toml_string("meta:\n  username: alice\nprojects:\n  repos: [a/b]\n")
+            .unwrap();
+        assert!(toml_str.contains("[meta]"));
+        assert!(toml_str.contains("repos = [\"a/b\"]"));
+    }
+}
@@ -0,0 +1,360 @@
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::error::ConfigError;
+
+/// Resolve `${VAR}` placeholders and `{ file = "..." }` includes in a config table.
+///
+/// - `${VAR}` is replaced by the variable's value; a missing variable is an error.
+/// - `${VAR:-fallback}` uses `fallback` when the variable is unset or empty.
+/// - `$$` is a literal `$`.
+/// - A table of exactly `{ file = "path" }` is replaced by that file's contents,
+///   with trailing newlines removed. Paths are relative to `base_dir`.
+///
+/// Only the identifier and URL fields in [`EXPANDED_FIELDS`] are expanded;
+/// display text is left as written, so existing text containing `${` keeps
+/// working. Includes apply everywhere.
+///
+/// `env` looks up a variable by name, so callers and tests can supply their own.
+pub fn interpolate(
+    table: &mut Table,
+    base_dir: &Path,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), ConfigError> {
+    for (key, value) in table.iter_mut() {
+        interpolate_value(value, key, base_dir, env)?;
+    }
+    Ok(())
+}
+
+/// Identifier and URL fields, where `${VAR}` is expanded. Array indices are
+/// ignored when matching and `*` stands for any one key.
+pub const EXPANDED_FIELDS: &[&str] = &[
+    "extends",
+    "meta.username",
+    "meta.name",
+    "header.banner_url",
+    "social.*",
+    "social.*.url",
+    "social.custom.logo",
+    "skills.custom_icons.*.slug",
+    "projects.featured.repo",
+    "blog.rss_urls",
+    "blog.articles.url",
+    "blog.youtube",
+    "blog.newsletter",
+    "dynamic.spotify_uid",
+    "dynamic.stackoverflow_uid",
+    "sponsors.kofi",
+    "sponsors.buy_me_a_coffee",
+    "extras.pgp_fingerprint",
+    "extras.xbox",
+    "extras.steam",
+    "extras.psn",
+    "organization.contributing_url",
+    "organization.community.url",
+    "organization.members",
+    "services.*",
+];
+
+/// Whether `key`, e.g. `blog.articles[2].url`, is one of [`EXPANDED_FIELDS`].
+fn is_expanded(key: &str) -> bool {
+    let parts: Vec<&str> = key
+        .split('.')
+        .map(|part: &str| part.split('[').next().unwrap_or(part))
+        .collect();
+    EXPANDED_FIELDS.iter().any(|field: &&str| {
+        let pattern: Vec<&str> = field.split('.').collect();
+        pattern.len() == parts.len()
+            && pattern
+                .iter()
+                .zip(&parts)
+                .all(|(p, part): (&&str, &&str)| *p == "*" || p == part)
+    })
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    key: &str,
+    base_dir: &Path,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), ConfigError> {
+    match value {
+        Value::String(s) if is_expanded(key) => {
+            *s = expand(s, key, env)?;
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                interpolate_value(item, &format!("{}[{}]", key, i), base_dir, env)?;
+            }
+        }
+        Value::Table(table) => {
+            if let Some(include) = include_path(table) {
+                let path: PathBuf = base_dir.join(expand(include, key, env)?);
+                let content: String =
+                    std::fs::read_to_string(&path).map_err(|source| ConfigError::Include {
+                        path: path.clone(),
+                        key: key.to_string(),
+                        source,
+                    })?;
+                *value = Value::String(content.trim_end_matches(['\n', '\r']).to_string());
+            } else {
+                for (child_key, child) in table.iter_mut() {
+                    interpolate_value(child, &format!("{}.{}", key, child_key), base_dir, env)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The path of a `{ file = "..." }` include table, if `table` is one.
+fn include_path(table: &Table) -> Option<&str> {
+    if table.len() != 1 {
+        return None;
+    }
+    table.get("file").and_then(Value::as_str)
+}
+
+/// Expand `${VAR}`, `${VAR:-fallback}` and `$$` in a single string.
+fn expand(
+    input: &str,
+    key: &str,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, ConfigError> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest: &str = input;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after: &str = &rest[pos + 1..];
+
+        if let Some(tail) = after.strip_prefix('$') {
+            out.push('$');
+            rest = tail;
+        } else if let Some(tail) = after.strip_prefix('{') {
+            let end: usize = tail
+                .find('}')
+                .ok_or_else(|| ConfigError::InvalidInterpolation {
+                    key: key.to_string(),
+                    message: "unterminated `${`".to_string(),
+                })?;
+            let expr: &str = &tail[..end];
+            let (name, fallback): (&str, Option<&str>) = match expr.split_once(":-") {
+                Some((name, fallback)) => (name, Some(fallback)),
+                None => (expr, None),
+            };
+            if !is_valid_name(name) {
+                return Err(ConfigError::InvalidInterpolation {
+                    key: key.to_string(),
+                    message: format!("`{}` is not a valid variable name", name),
+                });
+            }
+
+            let resolved: String = match (env(name).filter(|v: &String| !v.is_empty()), fallback) {
+                (Some(v), _) => v,
+                (None, Some(fallback)) => fallback.to_string(),
+                (None, None) => {
+                    return Err(ConfigError::MissingEnvVar {
+                        name: name.to_string(),
+                        key: key.to_string(),
+                    });
+                }
+            };
+            out.push_str(&resolved);
+            rest = &tail[end + 1..];
+        } else {
+            out.push('$');
+            rest = after;
+        }
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c: char| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "GITHUB_ACTOR" => Some("alice".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn table(src: &str) -> Table {
+        toml::from_str(src).unwrap()
+    }
+
+    #[test]
+    fn test_expand_variable() {
+        assert_eq!(expand("${GITHUB_ACTOR}", "k", &env).unwrap(), "alice");
+        assert_eq!(
+            expand("https://github.com/${GITHUB_ACTOR}/x", "k", &env).unwrap(),
+            "https://github.com/alice/x"
+        );
+    }
+
+    #[test]
+    fn test_expand_default() {
+        assert_eq!(expand("${NOPE:-bob}", "k", &env).unwrap(), "bob");
+        assert_eq!(expand("${EMPTY:-bob}", "k", &env).unwrap(), "bob");
+        assert_eq!(expand("${GITHUB_ACTOR:-bob}", "k", &env).unwrap(), "alice");
+        assert_eq!(expand("${NOPE:-}", "k", &env).unwrap(), "");
+    }
+
+    #[test]
+    fn test_expand_escapes_and_plain_dollars() {
+        assert_eq!(expand("$${HOME}", "k", &env).unwrap(), "${HOME}");
+        assert_eq!(expand("costs $5", "k", &env).unwrap(), "costs $5");
+    }
+
+    #[test]
+    fn test_expand_missing_variable() {
+        let err = expand("${SPOTIFY_UID}", "dynamic.spotify_uid", &env).unwrap_err();
+        let msg: String = err.to_string();
+        assert!(msg.contains("SPOTIFY_UID"));
+        assert!(msg.contains("dynamic.spotify_uid"));
+    }
+
+    #[test]
+    fn test_expand_invalid() {
+        assert!(matches!(
+            expand("${OOPS", "k", &env),
+            Err(ConfigError::InvalidInterpolation { .. })
+        ));
+        assert!(matches!(
+            expand("${not a var}", "k", &env),
+            Err(ConfigError::InvalidInterpolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_interpolate_nested_tables_and_arrays() {
+        let mut t = table(
+            r#"
+[meta]
+username = "${GITHUB_ACTOR}"
+
+[blog]
+rss_urls = ["https://a.example/feed", "https://${HOST:-b.example}/feed"]
+
+[[social.custom]]
+label = "Site"
+url = "https://${GITHUB_ACTOR}.example"
+"#,
+        );
+        interpolate(&mut t, Path::new("."), &env).unwrap();
+        assert_eq!(t["meta"]["username"].as_str(), Some("alice"));
+        assert_eq!(
+            t["blog"]["rss_urls"][1].as_str(),
+            Some("https://b.example/feed")
+        );
+        assert_eq!(
+            t["social"]["custom"][0]["url"].as_str(),
+            Some("https://alice.example")
+        );
+    }
+
+    #[test]
+    fn test_interpolate_missing_reports_array_key() {
+        let mut t = table("[organization]\nmembers = [\"alice\", \"${MEMBER2}\"]\n");
+        let err = interpolate(&mut t, Path::new("."), &env).unwrap_err();
+        assert!(err.to_string().contains("organization.members[1]"));
+    }
+
+    #[test]
+    fn test_interpolate_file_include() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("bio.md"), "I build **tools**.\n\n").unwrap();
+        let mut t =
+            table("[[extras.collapsible]]\nsummary = \"Bio\"\ncontent = { file = \"bio.md\" }\n");
+        interpolate(&mut t, dir.path(), &env).unwrap();
+        assert_eq!(
+            t["extras"]["collapsible"][0]["content"].as_str(),
+            Some("I build **tools**.")
+        );
+    }
+
+    #[test]
+    fn test_interpolate_leaves_display_text_literal() {
+        // Configs written before interpolation existed must still load.
+        let mut t = table(
+            r#"
+[meta]
+username = "alice"
+
+[header]
+tagline = "Writes shell like ${HOME_PRICE} all day"
+
+[about]
+bio = "Templating with ${name} and $${other}"
+fun_fact = "${"
+
+[skills]
+languages = ["${LANG}"]
+"#,
+        );
+        let before: Table = t.clone();
+        interpolate(&mut t, Path::new("."), &env).unwrap();
+        assert_eq!(t, before);
+    }
+
+    #[test]
+    fn test_interpolate_leaves_markdown_fields_literal() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("bio.md"), "Costs ${PRICE}").unwrap();
+        let mut t = table(
+            r#"
+[header]
+typing_lines = ["echo ${HOME}"]
+
+[extras]
+custom_blocks = ["Use `${VAR}` in templates, or $$ for a dollar"]
+
+[[extras.collapsible]]
+summary = "${NOT_SET}"
+content = { file = "bio.md" }
+"#,
+        );
+        interpolate(&mut t, dir.path(), &env).unwrap();
+        assert_eq!(
+            t["extras"]["custom_blocks"][0].as_str(),
+            Some("Use `${VAR}` in templates, or $$ for a dollar")
+        );
+        assert_eq!(
+            t["header"]["typing_lines"][0].as_str(),
+            Some("echo ${HOME}")
+        );
+        assert_eq!(
+            t["extras"]["collapsible"][0]["summary"].as_str(),
+            Some("${NOT_SET}")
+        );
+        assert_eq!(
+            t["extras"]["collapsible"][0]["content"].as_str(),
+            Some("Costs ${PRICE}")
+        );
+    }
+
+    #[test]
+    fn test_interpolate_missing_include() {
+        let dir = TempDir::new().unwrap();
+        let mut t = table("[about]\nfun_fact = { file = \"nope.md\" }\n");
+        let err = interpolate(&mut t, dir.path(), &env).unwrap_err();
+        let msg: String = err.to_string();
+        assert!(msg.contains("nope.md"));
+        assert!(msg.contains("about.fun_fact"));
+    }
+}
@@ -0,0 +1,147 @@
+use toml::{Table, Value};
+
+use crate::config::profile::{ArrayMerge, Merge};
+
+/// Deep-merge `overlay` on top of `base`.
+///
+/// Tables are merged key by key, scalars in `overlay` win, and arrays are
+/// replaced unless `options` asks for them to be appended. Appended arrays keep
+/// the base items first and skip items the base already contains.
+pub fn merge_tables(base: &mut Table, overlay: Table, options: &Merge) {
+    merge_at(base, overlay, options, "");
+}
+
+fn merge_at(base: &mut Table, overlay: Table, options: &Merge, prefix: &str) {
+    for (key, value) in overlay {
+        let path: String = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        let merged: Value = match (base.remove(&key), value) {
+            (Some(Value::Table(mut base_table)), Value::Table(overlay_table)) => {
+                merge_at(&mut base_table, overlay_table, options, &path);
+                Value::Table(base_table)
+            }
+            (Some(Value::Array(mut base_items)), Value::Array(overlay_items))
+                if array_strategy(options, &path) == ArrayMerge::Append =>
+            {
+                for item in overlay_items {
+                    if !base_items.contains(&item) {
+                        base_items.push(item);
+                    }
+                }
+                Value::Array(base_items)
+            }
+            (_, value) => value,
+        };
+        base.insert(key, merged);
+    }
+}
+
+/// The array strategy for a dotted path; explicit paths beat the default.
+fn array_strategy(options: &Merge, path: &str) -> ArrayMerge {
+    let listed = |paths: &Option<Vec<String>>| -> bool {
+        paths
+            .as_ref()
+            .is_some_and(|p: &Vec<String>| p.iter().any(|entry: &String| entry == path))
+    };
+
+    if listed(&options.replace) {
+        ArrayMerge::Replace
+    } else if listed(&options.append) {
+        ArrayMerge::Append
+    } else {
+        options.arrays.unwrap_or(ArrayMerge::Replace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(src: &str) -> Table {
+        toml::from_str(src).unwrap()
+    }
+
+    #[test]
+    fn test_merge_tables_deep() {
+        let mut base = table(
+            r#"
+[stats]
+theme = "tokyonight"
+streak = true
+
+[layout]
+template = "full"
+"#,
+        );
+        let overlay = table(
+            r#"
+[stats]
+theme = "radical"
+
+[about]
+role = "Engineer"
+"#,
+        );
+        merge_tables(&mut base, overlay, &Merge::default());
+
+        assert_eq!(base["stats"]["theme"].as_str(), Some("radical"));
+        assert_eq!(base["stats"]["streak"].as_bool(), Some(true));
+        assert_eq!(base["layout"]["template"].as_str(), Some("full"));
+        assert_eq!(base["about"]["role"].as_str(), Some("Engineer"));
+    }
+
+    #[test]
+    fn test_merge_arrays_replace_by_default() {
+        let mut base = table("[skills]\nlanguages = [\"Go\", \"Rust\"]\n");
+        let overlay = table("[skills]\nlanguages = [\"Python\"]\n");
+        merge_tables(&mut base, overlay, &Merge::default());
+
+        let langs: Vec<&str> = base["skills"]["languages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(langs, vec!["Python"]);
+    }
+
+    #[test]
+    fn test_merge_arrays_append_for_listed_path() {
+        let mut base = table("[skills]\nlanguages = [\"Go\", \"Rust\"]\ntools = [\"Git\"]\n");
+        let overlay = table("[skills]\nlanguages = [\"Rust\", \"Python\"]\ntools = [\"Vim\"]\n");
+        let options = Merge {
+            append: Some(vec!["skills.languages".to_string()]),
+            ..Merge::default()
+        };
+        merge_tables(&mut base, overlay, &options);
+
+        let langs: Vec<&str> = base["skills"]["languages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(langs, vec!["Go", "Rust", "Python"]);
+        assert_eq!(base["skills"]["tools"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_arrays_replace_overrides_default_append() {
+        let mut base = table("[skills]\nlanguages = [\"Go\"]\ntools = [\"Git\"]\n");
+        let overlay = table("[skills]\nlanguages = [\"Rust\"]\ntools = [\"Vim\"]\n");
+        let options = Merge {
+            arrays: Some(ArrayMerge::Append),
+            replace: Some(vec!["skills.tools".to_string()]),
+            ..Merge::default()
+        };
+        merge_tables(&mut base, overlay, &options);
+
+        assert_eq!(base["skills"]["languages"].as_array().unwrap().len(), 2);
+        assert_eq!(base["skills"]["tools"].as_array().unwrap().len(), 1);
+        assert_eq!(base["skills"]["tools"][0].as_str(), Some("Vim"));
+    }
+}
@@ -0,0 +1,321 @@
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, Value, value};
+
+use crate::error::ConfigError;
+
+/// The schema version written by this build.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Version assumed for files that have no `meta.schema_version`.
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// A single upgrade step from `from` to `from + 1`.
+struct Migration {
+    from: u32,
+    apply: fn(&mut DocumentMut, &mut Vec<String>),
+}
+
+/// All known upgrade steps, in ascending order of `from`.
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 1,
+    apply: migrate_v1_to_v2,
+}];
+
+/// Outcome of upgrading a config document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Deprecated fields that were found and rewritten.
+    pub warnings: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Whether any migration step was applied.
+    pub fn migrated(&self) -> bool {
+        self.from_version != self.to_version
+    }
+}
+
+/// Read `meta.schema_version` from a document, defaulting to the legacy version.
+pub fn schema_version(doc: &DocumentMut) -> Result<u32, ConfigError> {
+    let version: Option<&Item> = doc
+        .get("meta")
+        .and_then(|meta: &Item| meta.get("schema_version"));
+    match version {
+        None => Ok(LEGACY_SCHEMA_VERSION),
+        Some(item) => item
+            .as_integer()
+            .and_then(|v: i64| u32::try_from(v).ok())
+            .filter(|v: &u32| *v > 0)
+            .ok_or(ConfigError::InvalidSchemaVersion),
+    }
+}
+
+/// Upgrade a document in place to `CURRENT_SCHEMA_VERSION`.
+///
+/// Comments and formatting outside the rewritten keys are preserved.
+pub fn migrate_document(doc: &mut DocumentMut) -> Result<MigrationReport, ConfigError> {
+    let from_version: u32 = schema_version(doc)?;
+    if from_version > CURRENT_SCHEMA_VERSION {
+        return Err(ConfigError::UnsupportedSchemaVersion {
+            found: from_version,
+            supported: CURRENT_SCHEMA_VERSION,
+        });
+    }
+
+    let mut warnings: Vec<String> = Vec::new();
+    for migration in MIGRATIONS {
+        if migration.from >= from_version {
+            (migration.apply)(doc, &mut warnings);
+        }
+    }
+
+    if from_version < CURRENT_SCHEMA_VERSION {
+        set_schema_version(doc, CURRENT_SCHEMA_VERSION);
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: CURRENT_SCHEMA_VERSION,
+        warnings,
+    })
+}
+
+/// Parse, upgrade and re-serialize a TOML config, keeping its comments.
+pub fn migrate_str(content: &str) -> Result<(String, MigrationReport), ConfigError> {
+    let mut doc: DocumentMut = content.parse()?;
+    let report: MigrationReport = migrate_document(&mut doc)?;
+    Ok((doc.to_string(), report))
+}
+
+fn set_schema_version(doc: &mut DocumentMut, version: u32) {
+    if !doc.contains_key("meta") {
+        doc["meta"] = Item::Table(Table::new());
+    }
+    if let Some(meta) = doc["meta"].as_table_like_mut() {
+        meta.insert("schema_version", value(i64::from(version)));
+    }
+}
+
+/// v1 → v2: `projects.repos = ["a/b"]` becomes `[[projects.featured]] repo = "a/b"`.
+/// Repos are appended to any `featured` entries already present, skipping
+/// ones that are already featured.
+fn migrate_v1_to_v2(doc: &mut DocumentMut, warnings: &mut Vec<String>) {
+    let Some(projects) = doc.get_mut("projects") else {
+        return;
+    };
+
+    match projects {
+        Item::Table(table) => {
+            let Some(repos) = table.remove("repos") else {
+                return;
+            };
+            let featured: &mut Item = table
+                .entry("featured")
+                .or_insert(Item::ArrayOfTables(ArrayOfTables::new()));
+            match featured {
+                Item::ArrayOfTables(entries) => {
+                    let existing: Vec<String> = entries
+                        .iter()
+                        .filter_map(|entry: &Table| entry.get("repo")?.as_str().map(str::to_string))
+                        .collect();
+                    for repo in repo_names(&repos) {
+                        if !existing.contains(&repo) {
+                            let mut entry = Table::new();
+                            entry.insert("repo", value(repo));
+                            entries.push(entry);
+                        }
+                    }
+                    if entries.is_empty() {
+                        table.remove("featured");
+                    }
+                }
+                Item::Value(Value::Array(entries)) => push_inline_repos(entries, &repos),
+                _ => {}
+            }
+        }
+        Item::Value(Value::InlineTable(table)) => {
+            let Some(repos) = table.remove("repos") else {
+                return;
+            };
+            let featured: &mut Value = table
+                .entry("featured")
+                .or_insert(Value::Array(Array::new()));
+            if let Value::Array(entries) = featured {
+                push_inline_repos(entries, &Item::Value(repos));
+            }
+        }
+        _ => return,
+    }
+
+    warnings.push(
+        "projects.repos is deprecated; use [[projects.featured]] entries with `repo` and optional `description`"
+            .to_string(),
+    );
+}
+
+/// Append `{ repo = "..." }` tables to an inline `featured` array, skipping repos
+/// it already lists.
+fn push_inline_repos(entries: &mut Array, repos: &Item) {
+    let existing: Vec<String> = entries
+        .iter()
+        .filter_map(|entry: &Value| {
+            entry
+                .as_inline_table()?
+                .get("repo")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .collect();
+    for repo in repo_names(repos) {
+        if !existing.contains(&repo) {
+            let mut entry = InlineTable::new();
+            entry.insert("repo", repo.into());
+            entries.push(entry);
+        }
+    }
+}
+
+fn repo_names(repos: &Item) -> Vec<String> {
+    repos
+        .as_array()
+        .map(|arr: &Array| {
+            arr.iter()
+                .filter_map(|v: &Value| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::profile::{FeaturedProject, ProfileConfig};
+
+    const LEGACY: &str = r#"# My profile
+[meta]
+username = "alice" # GitHub login
+
+# Things I built
+[projects]
+repos = ["alice/cool-cli", "other-lib"]
+display = "markdown_table"
+"#;
+
+    #[test]
+    fn test_schema_version_defaults_to_legacy() {
+        let doc: DocumentMut = "[meta]\nusername = \"alice\"\n".parse().unwrap();
+        assert_eq!(schema_version(&doc).unwrap(), LEGACY_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_schema_version_rejects_non_integer() {
+        let doc: DocumentMut = "[meta]\nschema_version = \"two\"\n".parse().unwrap();
+        assert!(matches!(
+            schema_version(&doc),
+            Err(ConfigError::InvalidSchemaVersion)
+        ));
+    }
+
+    #[test]
+    fn test_migrate_v1_projects_repos() {
+        let (migrated, report) = migrate_str(LEGACY).unwrap();
+        assert_eq!(report.from_version, 1);
+        assert_eq!(report.to_version, CURRENT_SCHEMA_VERSION);
+        assert!(report.migrated());
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("projects.repos"));
+
+        let config: ProfileConfig = toml::from_str(&migrated).unwrap();
+        assert_eq!(config.meta.schema_version, Some(CURRENT_SCHEMA_VERSION));
+        assert_eq!(
+            config.projects.unwrap().featured,
+            Some(vec![
+                FeaturedProject {
+                    repo: "alice/cool-cli".to_string(),
+                    description: None,
+                },
+                FeaturedProject {
+                    repo: "other-lib".to_string(),
+                    description: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_migrate_preserves_comments() {
+        let (migrated, _) = migrate_str(LEGACY).unwrap();
+        assert!(migrated.starts_with("# My profile"));
+        assert!(migrated.contains("# GitHub login"));
+        assert!(migrated.contains("# Things I built"));
+        assert!(migrated.contains("[[projects.featured]]"));
+        assert!(!migrated.contains("repos ="));
+    }
+
+    #[test]
+    fn test_migrate_inline_projects_table() {
+        let legacy = "projects = { repos = [\"alice/a\"] }\n[meta]\nusername = \"alice\"\n";
+        let (migrated, _) = migrate_str(legacy).unwrap();
+        let config: ProfileConfig = toml::from_str(&migrated).unwrap();
+        assert_eq!(
+            config.projects.unwrap().featured.unwrap()[0].repo,
+            "alice/a"
+        );
+    }
+
+    #[test]
+    fn test_migrate_merges_repos_into_existing_featured() {
+        let legacy = "[meta]\nusername = \"alice\"\n\n[projects]\nrepos = [\"alice/a\", \"alice/b\"]\n\n[[projects.featured]]\nrepo = \"alice/a\"\ndescription = \"Kept\"\n";
+        let (migrated, _) = migrate_str(legacy).unwrap();
+        let config: ProfileConfig = toml::from_str(&migrated).unwrap();
+        assert_eq!(
+            config.projects.unwrap().featured,
+            Some(vec![
+                FeaturedProject {
+                    repo: "alice/a".to_string(),
+                    description: Some("Kept".to_string()),
+                },
+                FeaturedProject {
+                    repo: "alice/b".to_string(),
+                    description: None,
+                },
+            ])
+        );
+
+        let inline = "projects = { repos = [\"alice/b\"], featured = [{ repo = \"alice/a\" }] }\n[meta]\nusername = \"alice\"\n";
+        let (migrated, _) = migrate_str(inline).unwrap();
+        let config: ProfileConfig = toml::from_str(&migrated).unwrap();
+        let repos: Vec<String> = config
+            .projects
+            .unwrap()
+            .featured
+            .unwrap()
+            .into_iter()
+            .map(|p: FeaturedProject| p.repo)
+            .collect();
+        assert_eq!(repos, vec!["alice/a", "alice/b"]);
+    }
+
+    #[test]
+    fn test_migrate_current_is_noop() {
+        let current = "[meta]\nusername = \"alice\"\nschema_version = 2\n";
+        let (migrated, report) = migrate_str(current).unwrap();
+        assert!(!report.migrated());
+        assert!(report.warnings.is_empty());
+        assert_eq!(migrated, current);
+    }
+
+    #[test]
+    fn test_migrate_rejects_future_version() {
+        let future = "[meta]\nusername = \"alice\"\nschema_version = 99\n";
+        let result = migrate_str(future);
+        assert!(matches!(
+            result,
+            Err(ConfigError::UnsupportedSchemaVersion {
+                found: 99,
+                supported: CURRENT_SCHEMA_VERSION
+            })
+        ));
+    }
+}
@@ -1,2 +1,11 @@
+pub mod edit;
+pub mod format;
+pub mod interpolate;
+pub mod merge;
+pub mod migrate;
+pub mod presets;
 pub mod profile;
+pub mod starter;
+pub mod team;
 pub mod toml_io;
+pub(crate) mod values;
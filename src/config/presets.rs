@@ -0,0 +1,488 @@
+use crate::config::profile::*;
+use crate::config::values::{link, skills, strings, text};
+
+/// Placeholder written into every preset; `init` users replace it.
+const USERNAME: &str = "your-github-username";
+
+/// A curated starting point for `init --preset`.
+pub struct Preset {
+    pub name: &'static str,
+    pub description: &'static str,
+    build: fn() -> ProfileConfig,
+}
+
+impl Preset {
+    /// The preset's config, with the current schema version stamped.
+    pub fn config(&self) -> ProfileConfig {
+        let mut config: ProfileConfig = (self.build)();
+        config.meta.schema_version = Some(crate::config::migrate::CURRENT_SCHEMA_VERSION);
+        config
+    }
+
+    /// One-line summary of what the preset fills in, for `--list-presets`.
+    pub fn summary(&self) -> String {
+        let config: ProfileConfig = self.config();
+        let sections: Vec<String> = toml::Table::try_from(&config)
+            .map(|table: toml::Table| {
+                table
+                    .keys()
+                    .filter(|key: &&String| key.as_str() != "meta")
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut summary: String = sections.join(", ");
+        if let Some(template) = config
+            .layout
+            .as_ref()
+            .and_then(|l: &Layout| l.template.as_ref())
+        {
+            summary.push_str(&format!("; template {}", template_name(template)));
+        }
+        if let Some(theme) = config.stats.as_ref().and_then(|s: &Stats| s.theme.as_ref()) {
+            summary.push_str(&format!("; theme {}", theme));
+        }
+        summary
+    }
+}
+
+/// All built-in presets, in the order `--list-presets` shows them.
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        name: "minimal",
+        description: "A short intro and a few links, nothing else",
+        build: minimal,
+    },
+    Preset {
+        name: "rustacean",
+        description: "Systems programmer with crates, stats and a typing header",
+        build: rustacean,
+    },
+    Preset {
+        name: "data-scientist",
+        description: "Python/R stack, notebooks, articles and language stats",
+        build: data_scientist,
+    },
+    Preset {
+        name: "devrel",
+        description: "Talks, posts and videos up front, with every social link",
+        build: devrel,
+    },
+    Preset {
+        name: "student",
+        description: "What you're learning, streaks and trophies",
+        build: student,
+    },
+    Preset {
+        name: "open-source-maintainer",
+        description: "Featured projects, contributor stats and sponsor buttons",
+        build: open_source_maintainer,
+    },
+    Preset {
+        name: "organization",
+        description: "Organization profile with mission, community links and members",
+        build: organization,
+    },
+];
+
+/// Look up a preset by name.
+pub fn find_preset(name: &str) -> Option<&'static Preset> {
+    PRESETS.iter().find(|p: &&Preset| p.name == name)
+}
+
+fn template_name(template: &Template) -> &'static str {
+    match template {
+        Template::Minimal => "minimal",
+        Template::Full => "full",
+        Template::DeveloperCard => "developer_card",
+        Template::MultiColumn => "multi_column",
+    }
+}
+
+fn meta() -> Meta {
+    Meta {
+        username: USERNAME.to_string(),
+        name: text("Your Display Name"),
+        kind: None,
+        schema_version: None,
+    }
+}
+
+fn github_link() -> Option<SocialEntry> {
+    Some(format!("https://github.com/{}", USERNAME).into())
+}
+
+fn featured(repos: &[(&str, &str)]) -> Option<Projects> {
+    Some(Projects {
+        featured: Some(
+            repos
+                .iter()
+                .map(|(repo, description): &(&str, &str)| FeaturedProject {
+                    repo: format!("{}/{}", USERNAME, repo),
+                    description: text(description),
+                })
+                .collect(),
+        ),
+        display: Some(ProjectDisplay::PinCards),
+    })
+}
+
+fn minimal() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::Text),
+            tagline: text("Software engineer"),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Software Engineer"),
+            location: text("City, Country"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            website: link("https://yoursite.com"),
+            ..Social::default()
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Minimal),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn rustacean() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::TypingSvg),
+            typing_lines: strings(&["Rustacean 🦀", "Systems programmer", "Fearless concurrency"]),
+            typing_font: text("Fira Code"),
+            typing_color: text("dea584"),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Systems Engineer"),
+            current_work: text("a fast CLI in Rust"),
+            learning: text("async Rust and embedded"),
+            fun_fact: text("Ferris is my spirit animal"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            mastodon: link("https://hachyderm.io/@username"),
+            ..Social::default()
+        }),
+        skills: Some(Skills {
+            languages: skills(&["Rust", "C", "Python"]),
+            frameworks: skills(&["Axum", "Actix"]),
+            tools: skills(&["Git", "Docker", "Neovim", "Linux"]),
+            ..Skills::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            streak: Some(true),
+            theme: text("gruvbox"),
+            hide_border: Some(true),
+            top_langs_layout: text("compact"),
+            ..Stats::default()
+        }),
+        projects: featured(&[
+            ("my-crate", "A crate that does one thing well"),
+            ("my-cli", "A command-line tool written in Rust"),
+        ]),
+        layout: Some(Layout {
+            template: Some(Template::DeveloperCard),
+            dark_mode: Some(true),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn data_scientist() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::Wave),
+            tagline: text("Turning data into decisions"),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Data Scientist"),
+            current_work: text("forecasting models"),
+            learning: text("causal inference"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            linkedin: link("https://linkedin.com/in/username"),
+            medium: link("https://medium.com/@username"),
+            ..Social::default()
+        }),
+        skills: Some(Skills {
+            languages: skills(&["Python", "R", "SQL"]),
+            frameworks: skills(&["PyTorch", "pandas", "scikit-learn"]),
+            tools: skills(&["Jupyter", "Git", "Docker"]),
+            databases: skills(&["PostgreSQL", "BigQuery"]),
+            cloud: skills(&["GCP"]),
+            ..Skills::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            theme: text("default"),
+            top_langs_count: Some(6),
+            ..Stats::default()
+        }),
+        projects: featured(&[("analysis-notebooks", "Exploratory analyses and write-ups")]),
+        blog: Some(Blog {
+            rss_urls: strings(&["https://medium.com/feed/@username"]),
+            ..Blog::default()
+        }),
+        dynamic: Some(Dynamic {
+            wakatime: Some(true),
+            ..Dynamic::default()
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn devrel() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::TypingSvg),
+            typing_lines: strings(&["Developer Advocate", "Speaker", "Writer"]),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Developer Advocate"),
+            company: text("Your Company"),
+            reach_me: text("talks, workshops and podcasts"),
+            pronouns: text("they/them"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            twitter: link("https://twitter.com/username"),
+            linkedin: link("https://linkedin.com/in/username"),
+            mastodon: link("https://mastodon.social/@username"),
+            bluesky: link("https://bsky.app/profile/username"),
+            youtube: link("https://youtube.com/@username"),
+            devto: link("https://dev.to/username"),
+            website: link("https://yoursite.com"),
+            ..Social::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            profile_views: Some(true),
+            theme: text("radical"),
+            ..Stats::default()
+        }),
+        blog: Some(Blog {
+            rss_urls: strings(&["https://yoursite.com/feed.xml"]),
+            youtube: text("https://youtube.com/@username"),
+            newsletter: text("https://yoursite.com/newsletter"),
+            ..Blog::default()
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            centered: Some(true),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn student() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::Text),
+            tagline: text("CS student and lifelong learner"),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Computer Science Student"),
+            company: text("Your University"),
+            learning: text("algorithms and web development"),
+            fun_fact: text("I debug best at 2am"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            linkedin: link("https://linkedin.com/in/username"),
+            ..Social::default()
+        }),
+        skills: Some(Skills {
+            languages: skills(&["Python", "Java", "JavaScript"]),
+            tools: skills(&["Git", "VS Code"]),
+            ..Skills::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            streak: Some(true),
+            trophies: Some(true),
+            contribution_snake: Some(true),
+            theme: text("tokyonight"),
+            ..Stats::default()
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn open_source_maintainer() -> ProfileConfig {
+    ProfileConfig {
+        meta: meta(),
+        header: Some(Header {
+            style: Some(HeaderStyle::Text),
+            tagline: text("Maintaining tools people depend on"),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: text("Open Source Maintainer"),
+            current_work: text("the next major release"),
+            reach_me: text("issues and discussions on my projects"),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: github_link(),
+            mastodon: link("https://fosstodon.org/@username"),
+            website: link("https://yoursite.com"),
+            ..Social::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            contributor_stats: Some(true),
+            trophies: Some(true),
+            theme: text("github_dark"),
+            ..Stats::default()
+        }),
+        projects: featured(&[
+            ("project-one", "The project most people know me for"),
+            ("project-two", "A library used by project-one"),
+            ("project-three", "Something new and experimental"),
+        ]),
+        sponsors: Some(Sponsors {
+            github_sponsors: Some(true),
+            kofi: text("https://ko-fi.com/username"),
+            ..Sponsors::default()
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+fn organization() -> ProfileConfig {
+    ProfileConfig {
+        meta: Meta {
+            username: "your-org".to_string(),
+            name: text("Your Organization"),
+            kind: Some(ProfileKind::Organization),
+            schema_version: None,
+        },
+        header: Some(Header {
+            style: Some(HeaderStyle::Text),
+            tagline: text("Building open tools together"),
+            ..Header::default()
+        }),
+        social: Some(Social {
+            website: link("https://your-org.dev"),
+            mastodon: link("https://fosstodon.org/@your-org"),
+            ..Social::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            profile_views: Some(true),
+            ..Stats::default()
+        }),
+        projects: Some(Projects {
+            featured: Some(vec![FeaturedProject {
+                repo: "your-org/flagship".to_string(),
+                description: text("Our main project"),
+            }]),
+            display: Some(ProjectDisplay::MarkdownTable),
+        }),
+        sponsors: Some(Sponsors {
+            github_sponsors: Some(true),
+            ..Sponsors::default()
+        }),
+        organization: Some(Organization {
+            mission: text("What your organization is building, and why"),
+            contributing: text("We welcome issues and pull requests!"),
+            contributing_url: text("https://github.com/your-org/.github/blob/main/CONTRIBUTING.md"),
+            community: Some(vec![CommunityLink {
+                label: "Discussions".to_string(),
+                url: "https://github.com/orgs/your-org/discussions".to_string(),
+            }]),
+            members: strings(&["alice", "bob"]),
+            members_columns: Some(6),
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            ..Layout::default()
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::toml_io;
+    use crate::render::markdown;
+    use std::collections::HashSet;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_presets_have_unique_names() {
+        let names: HashSet<&str> = PRESETS.iter().map(|p: &Preset| p.name).collect();
+        assert_eq!(names.len(), PRESETS.len());
+        assert!(find_preset("rustacean").is_some());
+        assert!(find_preset("nope").is_none());
+    }
+
+    #[test]
+    fn test_presets_load_cleanly_and_render() {
+        let dir = TempDir::new().unwrap();
+        for preset in PRESETS {
+            let path = dir.path().join(format!("{}.toml", preset.name));
+            let config: ProfileConfig = preset.config();
+            toml_io::save_config(&config, &path).unwrap();
+
+            let loaded = toml_io::load_config_with_warnings(&path).unwrap();
+            assert_eq!(loaded.config, config, "{}", preset.name);
+            assert!(
+                loaded.warnings.is_empty(),
+                "{}: {:?}",
+                preset.name,
+                loaded.warnings
+            );
+            assert!(!markdown::render(&config).trim().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_preset_summary() {
+        let summary: String = find_preset("organization").unwrap().summary();
+        assert!(summary.contains("organization"));
+        assert!(summary.contains("template full"));
+        assert!(!summary.contains("meta"));
+    }
+}
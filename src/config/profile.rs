@@ -1,8 +1,13 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Top-level profile configuration. All sections except `meta` are optional.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ProfileConfig {
+    /// Base configs to inherit from, relative to this file. Later entries win.
+    pub extends: Option<Vec<String>>,
     pub meta: Meta,
     pub header: Option<Header>,
     pub about: Option<About>,
@@ -15,27 +20,73 @@ pub struct ProfileConfig {
     pub layout: Option<Layout>,
     pub sponsors: Option<Sponsors>,
     pub extras: Option<Extras>,
+    pub organization: Option<Organization>,
+    pub services: Option<Services>,
+    pub merge: Option<Merge>,
 }
 
+/// Top-level keys of [`ProfileConfig`] in declaration order, which is also
+/// the order they are written in.
+pub const SECTIONS: &[&str] = &[
+    "extends",
+    "meta",
+    "header",
+    "about",
+    "social",
+    "skills",
+    "stats",
+    "projects",
+    "blog",
+    "dynamic",
+    "layout",
+    "sponsors",
+    "extras",
+    "organization",
+    "services",
+    "merge",
+];
+
 /// Required metadata — at minimum, the GitHub username.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Meta {
+    /// Your GitHub login; used in every card and badge URL.
     pub username: String,
+    /// Display name for the header; defaults to the username.
     pub name: Option<String>,
+    /// Whether this profile is for a person (default) or an organization.
+    pub kind: Option<ProfileKind>,
+    /// Config schema version. Files without it are treated as version 1.
+    pub schema_version: Option<u32>,
+}
+
+impl Meta {
+    pub fn is_organization(&self) -> bool {
+        self.kind == Some(ProfileKind::Organization)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileKind {
+    Person,
+    Organization,
 }
 
 /// Header section: banner, typing SVG, or text greeting.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Header {
     pub style: Option<HeaderStyle>,
+    /// Image shown when `style = "banner"`.
     pub banner_url: Option<String>,
+    /// Lines cycled by the typing SVG.
     pub typing_lines: Option<Vec<String>>,
     pub typing_font: Option<String>,
+    /// Hex color without the leading `#`.
     pub typing_color: Option<String>,
     pub tagline: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum HeaderStyle {
     TypingSvg,
@@ -45,7 +96,7 @@ pub enum HeaderStyle {
 }
 
 /// About Me section.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct About {
     pub role: Option<String>,
     pub company: Option<String>,
@@ -58,41 +109,317 @@ pub struct About {
     pub timezone: Option<String>,
 }
 
-/// Social media links.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+/// Social media links, each a full URL or a handle such as `@alice`, or a table
+/// like `{ url = "@alice", label = "@alice", verify = true }` to show custom text
+/// or add a `rel="me"` verification link. Mastodon links are verified by default.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Social {
-    pub github: Option<String>,
-    pub twitter: Option<String>,
-    pub linkedin: Option<String>,
-    pub mastodon: Option<String>,
-    pub bluesky: Option<String>,
-    pub instagram: Option<String>,
-    pub youtube: Option<String>,
-    pub discord: Option<String>,
-    pub devto: Option<String>,
-    pub hashnode: Option<String>,
-    pub medium: Option<String>,
-    pub stackoverflow: Option<String>,
-    pub reddit: Option<String>,
-    pub twitch: Option<String>,
-    pub website: Option<String>,
-    pub email: Option<String>,
-    pub kofi: Option<String>,
-    pub rss: Option<String>,
+    pub github: Option<SocialEntry>,
+    pub twitter: Option<SocialEntry>,
+    pub linkedin: Option<SocialEntry>,
+    pub mastodon: Option<SocialEntry>,
+    pub bluesky: Option<SocialEntry>,
+    pub instagram: Option<SocialEntry>,
+    pub youtube: Option<SocialEntry>,
+    pub discord: Option<SocialEntry>,
+    pub devto: Option<SocialEntry>,
+    pub hashnode: Option<SocialEntry>,
+    pub medium: Option<SocialEntry>,
+    pub stackoverflow: Option<SocialEntry>,
+    pub reddit: Option<SocialEntry>,
+    pub twitch: Option<SocialEntry>,
+    pub website: Option<SocialEntry>,
+    pub email: Option<SocialEntry>,
+    pub kofi: Option<SocialEntry>,
+    pub rss: Option<SocialEntry>,
+    pub threads: Option<SocialEntry>,
+    pub gitlab: Option<SocialEntry>,
+    pub codeberg: Option<SocialEntry>,
+    pub keybase: Option<SocialEntry>,
+    pub matrix: Option<SocialEntry>,
+    pub telegram: Option<SocialEntry>,
+    pub leetcode: Option<SocialEntry>,
+    pub kaggle: Option<SocialEntry>,
+    pub huggingface: Option<SocialEntry>,
+    pub orcid: Option<SocialEntry>,
+    pub polywork: Option<SocialEntry>,
+    pub peerlist: Option<SocialEntry>,
+    /// Networks without a built-in field, rendered after the built-ins.
+    pub custom: Option<Vec<CustomSocial>>,
+    pub display: Option<SocialDisplay>,
+}
+
+impl Social {
+    /// Each built-in platform with its `[social]` key, in render order.
+    pub fn platforms(&self) -> [(&'static str, &Option<SocialEntry>); 30] {
+        [
+            ("github", &self.github),
+            ("twitter", &self.twitter),
+            ("linkedin", &self.linkedin),
+            ("mastodon", &self.mastodon),
+            ("bluesky", &self.bluesky),
+            ("instagram", &self.instagram),
+            ("youtube", &self.youtube),
+            ("discord", &self.discord),
+            ("devto", &self.devto),
+            ("hashnode", &self.hashnode),
+            ("medium", &self.medium),
+            ("stackoverflow", &self.stackoverflow),
+            ("reddit", &self.reddit),
+            ("twitch", &self.twitch),
+            ("website", &self.website),
+            ("email", &self.email),
+            ("kofi", &self.kofi),
+            ("rss", &self.rss),
+            ("threads", &self.threads),
+            ("gitlab", &self.gitlab),
+            ("codeberg", &self.codeberg),
+            ("keybase", &self.keybase),
+            ("matrix", &self.matrix),
+            ("telegram", &self.telegram),
+            ("leetcode", &self.leetcode),
+            ("kaggle", &self.kaggle),
+            ("huggingface", &self.huggingface),
+            ("orcid", &self.orcid),
+            ("polywork", &self.polywork),
+            ("peerlist", &self.peerlist),
+        ]
+    }
+
+    /// The field for a built-in platform key, if there is one.
+    pub fn platform_mut(&mut self, key: &str) -> Option<&mut Option<SocialEntry>> {
+        Some(match key {
+            "github" => &mut self.github,
+            "twitter" => &mut self.twitter,
+            "linkedin" => &mut self.linkedin,
+            "mastodon" => &mut self.mastodon,
+            "bluesky" => &mut self.bluesky,
+            "instagram" => &mut self.instagram,
+            "youtube" => &mut self.youtube,
+            "discord" => &mut self.discord,
+            "devto" => &mut self.devto,
+            "hashnode" => &mut self.hashnode,
+            "medium" => &mut self.medium,
+            "stackoverflow" => &mut self.stackoverflow,
+            "reddit" => &mut self.reddit,
+            "twitch" => &mut self.twitch,
+            "website" => &mut self.website,
+            "email" => &mut self.email,
+            "kofi" => &mut self.kofi,
+            "rss" => &mut self.rss,
+            "threads" => &mut self.threads,
+            "gitlab" => &mut self.gitlab,
+            "codeberg" => &mut self.codeberg,
+            "keybase" => &mut self.keybase,
+            "matrix" => &mut self.matrix,
+            "telegram" => &mut self.telegram,
+            "leetcode" => &mut self.leetcode,
+            "kaggle" => &mut self.kaggle,
+            "huggingface" => &mut self.huggingface,
+            "orcid" => &mut self.orcid,
+            "polywork" => &mut self.polywork,
+            "peerlist" => &mut self.peerlist,
+            _ => return None,
+        })
+    }
+}
+
+/// A social link as a plain URL or handle, or with a custom label.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SocialEntry {
+    Url(String),
+    Detailed(DetailedSocial),
+}
+
+impl SocialEntry {
+    /// The URL or handle as written.
+    pub fn url(&self) -> &str {
+        match self {
+            SocialEntry::Url(url) => url,
+            SocialEntry::Detailed(link) => &link.url,
+        }
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            SocialEntry::Url(_) => None,
+            SocialEntry::Detailed(link) => link.label.as_deref(),
+        }
+    }
+
+    pub fn verify(&self) -> Option<bool> {
+        match self {
+            SocialEntry::Url(_) => None,
+            SocialEntry::Detailed(link) => link.verify,
+        }
+    }
+}
+
+impl From<&str> for SocialEntry {
+    fn from(url: &str) -> Self {
+        SocialEntry::Url(url.to_string())
+    }
+}
+
+impl From<String> for SocialEntry {
+    fn from(url: String) -> Self {
+        SocialEntry::Url(url)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DetailedSocial {
+    pub url: String,
+    /// Text shown instead of the platform name, e.g. "@alice".
+    pub label: Option<String>,
+    /// Link with `rel="me"` so the profile can be verified from this page.
+    pub verify: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SocialDisplay {
+    /// for-the-badge shields.
+    #[default]
+    Badges,
+    /// A row of small platform logos.
+    Icons,
+    /// Inline links separated by `·`.
+    Text,
+    /// Platform and handle pairs.
+    Table,
+}
+
+/// A link to a network without a built-in `[social]` field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CustomSocial {
+    pub label: String,
+    pub url: String,
+    /// simple-icons slug for the badge logo.
+    pub logo: Option<String>,
+    /// Badge background as a hex color without `#`.
+    pub color: Option<String>,
+    /// Link with `rel="me"` so the profile can be verified from this page.
+    pub verify: Option<bool>,
 }
 
 /// Skills / Tech Stack, organized by category.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Skills {
-    pub languages: Option<Vec<String>>,
-    pub frameworks: Option<Vec<String>>,
-    pub tools: Option<Vec<String>>,
-    pub databases: Option<Vec<String>>,
-    pub cloud: Option<Vec<String>>,
+    /// Plain names, or tables like `{ name = "Rust", level = "expert", years = 6, primary = true }`.
+    /// Levels are expert, comfortable or learning; every list accepts both forms.
+    pub languages: Option<Vec<SkillEntry>>,
+    pub frameworks: Option<Vec<SkillEntry>>,
+    pub tools: Option<Vec<SkillEntry>>,
+    pub databases: Option<Vec<SkillEntry>>,
+    pub cloud: Option<Vec<SkillEntry>>,
+    /// badges (with "Rust | 6y" messages when years are set), tiers grouped by level, or bars.
+    pub display: Option<SkillDisplay>,
+    /// Icons for skills missing from the built-in catalog, keyed by skill name.
+    pub custom_icons: Option<BTreeMap<String, CustomIcon>>,
+}
+
+impl Skills {
+    /// Each list with its key, in section order.
+    pub fn lists(&self) -> [(&'static str, &Option<Vec<SkillEntry>>); 5] {
+        [
+            ("languages", &self.languages),
+            ("frameworks", &self.frameworks),
+            ("tools", &self.tools),
+            ("databases", &self.databases),
+            ("cloud", &self.cloud),
+        ]
+    }
+}
+
+/// A skill as a plain name, or with proficiency details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SkillEntry {
+    Name(String),
+    Detailed(DetailedSkill),
+}
+
+impl SkillEntry {
+    pub fn name(&self) -> &str {
+        match self {
+            SkillEntry::Name(name) => name,
+            SkillEntry::Detailed(skill) => &skill.name,
+        }
+    }
+
+    pub fn level(&self) -> Option<SkillLevel> {
+        match self {
+            SkillEntry::Name(_) => None,
+            SkillEntry::Detailed(skill) => skill.level,
+        }
+    }
+
+    pub fn years(&self) -> Option<u32> {
+        match self {
+            SkillEntry::Name(_) => None,
+            SkillEntry::Detailed(skill) => skill.years,
+        }
+    }
+
+    pub fn is_primary(&self) -> bool {
+        matches!(self, SkillEntry::Detailed(skill) if skill.primary == Some(true))
+    }
+}
+
+impl From<&str> for SkillEntry {
+    fn from(name: &str) -> Self {
+        SkillEntry::Name(name.to_string())
+    }
+}
+
+impl From<String> for SkillEntry {
+    fn from(name: String) -> Self {
+        SkillEntry::Name(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DetailedSkill {
+    pub name: String,
+    pub level: Option<SkillLevel>,
+    pub years: Option<u32>,
+    /// Primary skills are listed first.
+    pub primary: Option<bool>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillLevel {
+    Expert,
+    Comfortable,
+    Learning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillDisplay {
+    #[default]
+    Badges,
+    Tiers,
+    Bars,
+}
+
+/// A user-supplied skill icon; `slug` is a simple-icons slug.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CustomIcon {
+    pub slug: String,
+    /// Badge background as a hex color without `#`.
+    pub color: Option<String>,
+    /// Other spellings that should resolve to this skill.
+    pub aliases: Option<Vec<String>>,
 }
 
 /// GitHub stats cards configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Stats {
     pub stats_card: Option<bool>,
     pub top_langs: Option<bool>,
@@ -101,20 +428,139 @@ pub struct Stats {
     pub trophies: Option<bool>,
     pub contribution_snake: Option<bool>,
     pub profile_views: Option<bool>,
+    /// Any github-readme-stats theme name.
     pub theme: Option<String>,
     pub hide_border: Option<bool>,
+    /// compact, normal, donut, donut-vertical or pie.
     pub top_langs_layout: Option<String>,
     pub top_langs_count: Option<u32>,
+    /// Further github-readme-stats options for the stats card.
+    pub card: Option<StatsCardOptions>,
+    /// Further github-readme-stats options for the top languages card.
+    pub langs: Option<TopLangsOptions>,
+    /// Color overrides for both cards, taking precedence over the theme.
+    pub colors: Option<CardColors>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct StatsCardOptions {
+    /// Rows to leave out of the card.
+    pub hide: Option<Vec<StatsRow>>,
+    /// Extra rows to add to the card.
+    pub show: Option<Vec<StatsExtraRow>>,
+    pub count_private: Option<bool>,
+    /// Count commits from all years rather than only the current one.
+    pub include_all_commits: Option<bool>,
+    pub custom_title: Option<String>,
+    /// Card language, e.g. "de" or "pt-br".
+    pub locale: Option<String>,
+    pub rank_icon: Option<RankIcon>,
+    /// Width in pixels.
+    pub card_width: Option<u32>,
+    /// How long the card may be cached, between 21600 and 86400 seconds.
+    pub cache_seconds: Option<u32>,
+    pub disable_animations: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsRow {
+    Stars,
+    Commits,
+    Prs,
+    Issues,
+    Contribs,
+}
+
+impl StatsRow {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatsRow::Stars => "stars",
+            StatsRow::Commits => "commits",
+            StatsRow::Prs => "prs",
+            StatsRow::Issues => "issues",
+            StatsRow::Contribs => "contribs",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsExtraRow {
+    Reviews,
+    DiscussionsStarted,
+    DiscussionsAnswered,
+    PrsMerged,
+    PrsMergedPercentage,
+}
+
+impl StatsExtraRow {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatsExtraRow::Reviews => "reviews",
+            StatsExtraRow::DiscussionsStarted => "discussions_started",
+            StatsExtraRow::DiscussionsAnswered => "discussions_answered",
+            StatsExtraRow::PrsMerged => "prs_merged",
+            StatsExtraRow::PrsMergedPercentage => "prs_merged_percentage",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RankIcon {
+    Default,
+    Github,
+    Percentile,
+}
+
+impl RankIcon {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RankIcon::Default => "default",
+            RankIcon::Github => "github",
+            RankIcon::Percentile => "percentile",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TopLangsOptions {
+    /// Repositories, by name, whose languages are not counted.
+    pub exclude_repo: Option<Vec<String>>,
+    /// Languages to leave out of the card.
+    pub hide: Option<Vec<String>>,
+    /// How much a language's byte count matters when ranking, default 1.
+    pub size_weight: Option<f64>,
+    /// How much the number of repos using a language matters, default 0.
+    pub count_weight: Option<f64>,
+}
+
+/// Hex colors; a leading `#` is accepted and dropped.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CardColors {
+    pub title: Option<String>,
+    pub text: Option<String>,
+    pub icon: Option<String>,
+    pub bg: Option<String>,
+    pub border: Option<String>,
 }
 
 /// Featured projects.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Projects {
-    pub repos: Option<Vec<String>>,
+    pub featured: Option<Vec<FeaturedProject>>,
     pub display: Option<ProjectDisplay>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A single featured repository, as `owner/repo` or just `repo`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FeaturedProject {
+    pub repo: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProjectDisplay {
     PinCards,
@@ -122,23 +568,25 @@ pub enum ProjectDisplay {
 }
 
 /// Blog / Content section.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Blog {
+    /// Feeds for the blog-post workflow to fill in.
     pub rss_urls: Option<Vec<String>>,
     pub articles: Option<Vec<Article>>,
     pub youtube: Option<String>,
     pub newsletter: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Article {
     pub title: String,
     pub url: String,
 }
 
 /// Dynamic / real-time integrations.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Dynamic {
+    /// Spotify user ID for the "now playing" card.
     pub spotify_uid: Option<String>,
     pub wakatime: Option<bool>,
     pub github_activity: Option<bool>,
@@ -146,14 +594,14 @@ pub struct Dynamic {
 }
 
 /// Layout and theming.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Layout {
     pub template: Option<Template>,
     pub dark_mode: Option<bool>,
     pub centered: Option<bool>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Template {
     Minimal,
@@ -163,7 +611,7 @@ pub enum Template {
 }
 
 /// Sponsors section.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Sponsors {
     pub github_sponsors: Option<bool>,
     pub kofi: Option<String>,
@@ -171,23 +619,101 @@ pub struct Sponsors {
 }
 
 /// Extras: PGP, gaming, certifications, custom blocks.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Extras {
     pub pgp_fingerprint: Option<String>,
     pub xbox: Option<String>,
     pub steam: Option<String>,
     pub psn: Option<String>,
     pub certifications: Option<Vec<String>>,
+    /// Raw markdown inserted as-is.
     pub custom_blocks: Option<Vec<String>>,
+    /// `<details>` blocks that expand on click.
     pub collapsible: Option<Vec<CollapsibleSection>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct CollapsibleSection {
     pub summary: String,
     pub content: String,
 }
 
+/// Organization-only sections, used when `meta.kind = "organization"`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Organization {
+    pub mission: Option<String>,
+    pub contributing: Option<String>,
+    pub contributing_url: Option<String>,
+    pub community: Option<Vec<CommunityLink>>,
+    /// GitHub usernames shown in the members grid.
+    pub members: Option<Vec<String>>,
+    /// Avatars per row in the members grid.
+    pub members_columns: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CommunityLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Base URLs of self-hosted card and badge services, replacing the public
+/// deployments, e.g. `readme_stats = "https://stats.example.com"`, and which
+/// provider draws each kind of card, e.g. `badge = "badgen"`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Services {
+    /// github-readme-stats: the stats, top languages and pin cards.
+    pub readme_stats: Option<String>,
+    /// github-readme-streak-stats.
+    pub streak_stats: Option<String>,
+    /// github-profile-trophy.
+    pub trophies: Option<String>,
+    /// readme-typing-svg, for the typing header.
+    pub typing_svg: Option<String>,
+    /// github-contributor-stats, for the contributor stats card.
+    pub contributor_stats: Option<String>,
+    /// shields.io, for skill, social, sponsor and other badges.
+    pub shields: Option<String>,
+    /// badgen.net, used when `badge = "badgen"`.
+    pub badgen: Option<String>,
+    /// simple-icons CDN, for the icon social display.
+    pub simple_icons: Option<String>,
+    /// komarev profile views counter.
+    pub profile_views: Option<String>,
+    /// spotify-github-profile.
+    pub spotify: Option<String>,
+    /// Provider of the stats card, default "readme_stats".
+    pub stats_card: Option<String>,
+    /// Provider of the top languages card, default "readme_stats".
+    pub language_card: Option<String>,
+    /// Provider of the streak card, default "streak_stats".
+    pub streak: Option<String>,
+    /// Provider of project pin cards, default "readme_stats".
+    pub pin_card: Option<String>,
+    /// Provider of static badges: "shields" (default) or "badgen".
+    pub badge: Option<String>,
+    /// Provider of the profile views counter, default "profile_views".
+    pub views: Option<String>,
+}
+
+/// How this file is merged on top of its `extends` bases.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Merge {
+    /// Default strategy for arrays; tables are always deep-merged.
+    pub arrays: Option<ArrayMerge>,
+    /// Dotted paths (e.g. `skills.languages`) whose arrays are appended to the base.
+    pub append: Option<Vec<String>>,
+    /// Dotted paths whose arrays replace the base even when `arrays = "append"`.
+    pub replace: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayMerge {
+    Replace,
+    Append,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,14 +734,70 @@ mod tests {
         assert!(config.layout.is_none());
         assert!(config.sponsors.is_none());
         assert!(config.extras.is_none());
+        assert!(config.extends.is_none());
+        assert!(config.organization.is_none());
+        assert!(config.merge.is_none());
+        assert!(!config.meta.is_organization());
+    }
+
+    #[test]
+    fn test_organization_profile_toml() {
+        let toml_str = r#"
+[meta]
+username = "acme"
+kind = "organization"
+
+[organization]
+mission = "Tools for everyone"
+contributing_url = "https://github.com/acme/.github/blob/main/CONTRIBUTING.md"
+members = ["alice", "bob"]
+
+[[organization.community]]
+label = "Forum"
+url = "https://forum.acme.dev"
+"#;
+        let config: ProfileConfig = toml::from_str(toml_str).expect("deserialize org");
+        assert!(config.meta.is_organization());
+        let org: Organization = config.organization.unwrap();
+        assert_eq!(org.mission.as_deref(), Some("Tools for everyone"));
+        assert_eq!(org.members.unwrap().len(), 2);
+        assert_eq!(org.community.unwrap()[0].label, "Forum");
+    }
+
+    #[test]
+    fn test_skill_entries_accept_names_and_tables() {
+        let toml_str = r#"
+[meta]
+username = "alice"
+
+[skills]
+display = "bars"
+languages = ["Go", { name = "Rust", level = "expert", years = 6, primary = true }]
+"#;
+        let config: ProfileConfig = toml::from_str(toml_str).expect("deserialize skills");
+        let skills: Skills = config.skills.unwrap();
+        assert_eq!(skills.display, Some(SkillDisplay::Bars));
+        let languages: Vec<SkillEntry> = skills.languages.unwrap();
+        assert_eq!(languages[0], SkillEntry::from("Go"));
+        assert_eq!(languages[1].name(), "Rust");
+        assert_eq!(languages[1].level(), Some(SkillLevel::Expert));
+        assert_eq!(languages[1].years(), Some(6));
+        assert!(languages[1].is_primary());
+        assert!(!languages[0].is_primary());
+
+        let bad = "[meta]\nusername = \"a\"\n[skills]\nlanguages = [{ name = \"Rust\", level = \"guru\" }]\n";
+        assert!(toml::from_str::<ProfileConfig>(bad).is_err());
     }
 
     #[test]
     fn test_profile_config_round_trip() {
         let config = ProfileConfig {
+            extends: Some(vec!["../team/base.toml".to_string()]),
             meta: Meta {
                 username: "alice".to_string(),
                 name: Some("Alice".to_string()),
+                kind: None,
+                schema_version: Some(2),
             },
             header: Some(Header {
                 style: Some(HeaderStyle::TypingSvg),
@@ -237,16 +819,18 @@ mod tests {
                 timezone: Some("PST".to_string()),
             }),
             social: Some(Social {
-                twitter: Some("https://twitter.com/alice".to_string()),
-                linkedin: Some("https://linkedin.com/in/alice".to_string()),
+                twitter: Some("https://twitter.com/alice".into()),
+                linkedin: Some("https://linkedin.com/in/alice".into()),
                 ..Social::default()
             }),
             skills: Some(Skills {
-                languages: Some(vec!["Rust".to_string(), "Python".to_string()]),
-                frameworks: Some(vec!["Actix".to_string()]),
-                tools: Some(vec!["Docker".to_string()]),
-                databases: Some(vec!["PostgreSQL".to_string()]),
-                cloud: Some(vec!["AWS".to_string()]),
+                languages: Some(vec!["Rust".into(), "Python".into()]),
+                frameworks: Some(vec!["Actix".into()]),
+                tools: Some(vec!["Docker".into()]),
+                databases: Some(vec!["PostgreSQL".into()]),
+                cloud: Some(vec!["AWS".into()]),
+                display: Some(SkillDisplay::Tiers),
+                custom_icons: None,
             }),
             stats: Some(Stats {
                 stats_card: Some(true),
@@ -260,9 +844,15 @@ mod tests {
                 hide_border: Some(false),
                 top_langs_layout: Some("compact".to_string()),
                 top_langs_count: Some(8),
+                card: None,
+                langs: None,
+                colors: None,
             }),
             projects: Some(Projects {
-                repos: Some(vec!["alice/cool-cli".to_string()]),
+                featured: Some(vec![FeaturedProject {
+                    repo: "alice/cool-cli".to_string(),
+                    description: Some("A cool CLI".to_string()),
+                }]),
                 display: Some(ProjectDisplay::PinCards),
             }),
             blog: Some(Blog {
@@ -302,11 +892,64 @@ mod tests {
                     content: "Hidden details".to_string(),
                 }]),
             }),
+            organization: None,
+            services: None,
+            merge: Some(Merge {
+                arrays: Some(ArrayMerge::Replace),
+                append: Some(vec!["skills.languages".to_string()]),
+                replace: None,
+            }),
         };
 
         let toml_str: String = toml::to_string(&config).expect("serialize");
         let deserialized: ProfileConfig = toml::from_str(&toml_str).expect("deserialize");
         assert_eq!(config, deserialized);
+
+        let table: toml::Table = toml::Table::try_from(&config).expect("serialize");
+        let order: Vec<usize> = table
+            .keys()
+            .map(|key: &String| SECTIONS.iter().position(|s: &&str| s == key).unwrap())
+            .collect();
+        assert!(order.is_sorted(), "SECTIONS is out of date");
+    }
+
+    #[test]
+    fn test_social_platform_lists_agree() {
+        // Social's fields, platforms(), platform_mut() and the badge table in
+        // social_platform_info() are kept in sync by hand.
+        let schema = serde_json::to_value(schemars::schema_for!(Social)).unwrap();
+        let fields: Vec<&str> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .filter(|key: &&str| !matches!(*key, "custom" | "display"))
+            .collect();
+        let keys: Vec<&str> = Social::default()
+            .platforms()
+            .iter()
+            .map(|(key, _)| *key)
+            .collect();
+        assert_eq!(keys, fields);
+
+        for key in keys {
+            let mut social = Social::default();
+            *social
+                .platform_mut(key)
+                .unwrap_or_else(|| panic!("platform_mut misses {}", key)) = Some("x".into());
+            let set: Vec<&str> = social
+                .platforms()
+                .iter()
+                .filter(|(_, entry)| entry.is_some())
+                .map(|(name, _)| *name)
+                .collect();
+            assert_eq!(set, vec![key], "platform_mut({}) sets the wrong field", key);
+            assert!(
+                crate::services::urls::social_platform_info(key).is_some(),
+                "social_platform_info misses {}",
+                key
+            );
+        }
     }
 
     #[test]
@@ -335,6 +978,7 @@ role = "Developer"
 [meta]
 username = "alice"
 name = "Alice"
+schema_version = 2
 
 [header]
 style = "typing_svg"
@@ -375,9 +1019,12 @@ top_langs_layout = "compact"
 top_langs_count = 8
 
 [projects]
-repos = ["alice/cool-cli"]
 display = "pin_cards"
 
+[[projects.featured]]
+repo = "alice/cool-cli"
+description = "A cool CLI"
+
 [blog]
 rss_urls = ["https://alice.dev/feed.xml"]
 youtube = "https://youtube.com/@alice"
@@ -0,0 +1,502 @@
+//! The commented starter written by `init`, generated from the data model.
+//!
+//! Keys, order and example values come from serializing [`example_config`], so
+//! serde renames and skipped fields are honoured; doc comments and enum
+//! options come from the config's JSON schema. Prose is written as `## ...`
+//! and settings as `# key = value`, so stripping one `# ` from every line
+//! after the header yields a complete, valid config.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::config::migrate::CURRENT_SCHEMA_VERSION;
+use crate::config::profile::*;
+use crate::config::values::{link, skills, strings, text};
+
+/// The first lines of the starter, above the generated sections.
+pub const HEADER: &str = "# gh-profile-gen profile configuration\n# Uncomment and fill in the sections you want in your README.\n";
+
+/// Settings left uncommented so the starter is a loadable config as-is.
+const UNCOMMENTED: &[&str] = &["meta.username", "meta.schema_version"];
+
+/// The JSON schema of [`ProfileConfig`].
+fn schema() -> Value {
+    serde_json::to_value(schemars::schema_for!(ProfileConfig)).expect("schema serializes")
+}
+
+/// How a field is written, judged from its schema.
+enum Shape<'a> {
+    /// A struct, written as `[table]` or `[[table]]`.
+    Section(&'a Value),
+    /// A map of structs keyed by name, written as a table of inline tables.
+    Map,
+    /// Anything else, written as `key = value`, with its options if it is an enum.
+    Value(Vec<&'a str>),
+}
+
+/// Generate the commented starter TOML.
+pub fn commented_starter() -> String {
+    let schema: Value = schema();
+    let example: toml::Table =
+        toml::Table::try_from(example_config()).expect("example config serializes to a table");
+
+    let mut out = String::from(HEADER);
+    let mut starter = Starter {
+        defs: schema["$defs"].as_object().expect("schema has definitions"),
+        out: &mut out,
+    };
+    starter.write_struct(&schema, &example, "");
+    out
+}
+
+struct Starter<'a> {
+    defs: &'a Map<String, Value>,
+    out: &'a mut String,
+}
+
+impl<'a> Starter<'a> {
+    /// Write a struct's fields: plain values first, then sub-tables, as TOML requires.
+    fn write_struct(&mut self, def: &'a Value, values: &toml::Table, path: &str) {
+        let properties: &Value = &def["properties"];
+
+        for (name, value) in values {
+            let field: &Value = &properties[name];
+            let Shape::Value(options) = self.shape(field) else {
+                continue;
+            };
+            if path.is_empty() {
+                self.out.push('\n');
+            }
+            self.write_docs(description(field));
+            let key: String = join(path, name);
+            let prefix: &str = if UNCOMMENTED.contains(&key.as_str()) {
+                ""
+            } else {
+                "# "
+            };
+            let mut line: String = format!("{}{} = {}", prefix, toml_edit::Key::new(name), value);
+            if !options.is_empty() {
+                line.push_str(&format!("  # Options: {}", options.join(", ")));
+            }
+            self.out.push_str(&line);
+            self.out.push('\n');
+        }
+
+        for (name, value) in values {
+            let field: &Value = &properties[name];
+            let key: String = join(path, name);
+            match (self.shape(field), value) {
+                (Shape::Map, toml::Value::Table(entries)) => {
+                    self.out.push('\n');
+                    self.write_docs(description(field));
+                    self.out.push_str(&format!("# [{}]\n", key));
+                    for (name, entry) in entries {
+                        let name = toml_edit::Key::new(name.as_str());
+                        self.out.push_str(&format!("# {} = {}\n", name, entry));
+                    }
+                }
+                (Shape::Section(child), toml::Value::Table(table)) => {
+                    self.out.push('\n');
+                    self.write_docs(description(field).or(description(child)));
+                    let prefix: &str = if key == "meta" { "" } else { "# " };
+                    self.out.push_str(&format!("{}[{}]\n", prefix, key));
+                    self.write_struct(child, table, &key);
+                }
+                (Shape::Section(child), toml::Value::Array(items)) => {
+                    for table in items.iter().filter_map(toml::Value::as_table) {
+                        self.out.push_str("#\n");
+                        self.write_docs(description(field).or(description(child)));
+                        self.out.push_str(&format!("# [[{}]]\n", key));
+                        self.write_struct(child, table, &key);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn write_docs(&mut self, docs: Option<&str>) {
+        for doc in docs.unwrap_or_default().lines() {
+            if doc.is_empty() {
+                self.out.push_str("##\n");
+            } else {
+                self.out.push_str(&format!("## {}\n", doc));
+            }
+        }
+    }
+
+    fn shape(&self, field: &'a Value) -> Shape<'a> {
+        let field: &Value = non_null(field);
+        if field["additionalProperties"].is_object() {
+            return Shape::Map;
+        }
+        let item: &Value = self.resolve(field.get("items").map_or(field, non_null));
+        if item["properties"].is_object() {
+            return Shape::Section(item);
+        }
+        Shape::Value(enum_options(item))
+    }
+
+    /// Follow a `$ref` to its definition.
+    fn resolve(&self, schema: &'a Value) -> &'a Value {
+        schema["$ref"]
+            .as_str()
+            .and_then(|reference: &str| reference.strip_prefix("#/$defs/"))
+            .and_then(|name: &str| self.defs.get(name))
+            .unwrap_or(schema)
+    }
+}
+
+/// `Option<T>` is `anyOf: [T, null]`; unwrap it to `T`.
+fn non_null(schema: &Value) -> &Value {
+    match schema["anyOf"].as_array().map(Vec::as_slice) {
+        Some([inner, null]) if null["type"] == "null" => inner,
+        _ => schema,
+    }
+}
+
+/// The values of a unit-variant enum, as written in TOML.
+fn enum_options(schema: &Value) -> Vec<&str> {
+    if let Some(values) = schema["enum"].as_array() {
+        return values.iter().filter_map(Value::as_str).collect();
+    }
+    schema["oneOf"]
+        .as_array()
+        .map(|variants: &Vec<Value>| {
+            variants
+                .iter()
+                .filter_map(|variant: &Value| variant["const"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn description(schema: &Value) -> Option<&str> {
+    schema["description"].as_str()
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// A config with every field set, used for the example values in the starter.
+pub fn example_config() -> ProfileConfig {
+    ProfileConfig {
+        extends: strings(&["../team/base.toml"]),
+        meta: Meta {
+            username: "your-github-username".to_string(),
+            name: text("Your Display Name"),
+            kind: Some(ProfileKind::Person),
+            schema_version: Some(CURRENT_SCHEMA_VERSION),
+        },
+        header: Some(Header {
+            style: Some(HeaderStyle::TypingSvg),
+            banner_url: text("https://example.com/banner.png"),
+            typing_lines: strings(&["Hello", "World"]),
+            typing_font: text("Fira Code"),
+            typing_color: text("f75c7e"),
+            tagline: text("Your tagline here"),
+        }),
+        about: Some(About {
+            role: text("Your Role"),
+            company: text("Your Company"),
+            current_work: text("project-name"),
+            learning: text("Something new"),
+            reach_me: text("your@email.com"),
+            fun_fact: text("Something fun"),
+            pronouns: text("they/them"),
+            location: text("City, Country"),
+            timezone: text("UTC"),
+        }),
+        social: Some(Social {
+            github: link("https://github.com/username"),
+            twitter: Some(SocialEntry::Detailed(DetailedSocial {
+                url: "@username".to_string(),
+                label: text("@username"),
+                verify: None,
+            })),
+            linkedin: link("username"),
+            mastodon: link("@username@mastodon.social"),
+            bluesky: link("https://bsky.app/profile/username"),
+            instagram: link("https://instagram.com/username"),
+            youtube: link("https://youtube.com/@username"),
+            discord: link("https://discord.gg/invite"),
+            devto: link("https://dev.to/username"),
+            hashnode: link("https://hashnode.com/@username"),
+            medium: link("https://medium.com/@username"),
+            stackoverflow: link("https://stackoverflow.com/users/id"),
+            reddit: link("https://reddit.com/u/username"),
+            twitch: link("https://twitch.tv/username"),
+            website: link("https://yoursite.com"),
+            email: link("your@email.com"),
+            kofi: link("https://ko-fi.com/username"),
+            rss: link("https://yoursite.com/feed.xml"),
+            threads: link("https://threads.net/@username"),
+            gitlab: link("https://gitlab.com/username"),
+            codeberg: link("https://codeberg.org/username"),
+            keybase: link("https://keybase.io/username"),
+            matrix: link("https://matrix.to/#/@username:matrix.org"),
+            telegram: link("https://t.me/username"),
+            leetcode: link("https://leetcode.com/u/username"),
+            kaggle: link("https://kaggle.com/username"),
+            huggingface: link("https://huggingface.co/username"),
+            orcid: link("https://orcid.org/0000-0000-0000-0000"),
+            polywork: link("https://polywork.com/username"),
+            peerlist: link("https://peerlist.io/username"),
+            custom: Some(vec![CustomSocial {
+                label: "Lobsters".to_string(),
+                url: "https://lobste.rs/~username".to_string(),
+                logo: text("lobsters"),
+                color: text("AC130D"),
+                verify: Some(false),
+            }]),
+            display: Some(SocialDisplay::Badges),
+        }),
+        skills: Some(Skills {
+            languages: Some(vec![
+                SkillEntry::Detailed(DetailedSkill {
+                    name: "Rust".to_string(),
+                    level: Some(SkillLevel::Expert),
+                    years: Some(6),
+                    primary: Some(true),
+                }),
+                "Python".into(),
+                "TypeScript".into(),
+            ]),
+            frameworks: skills(&["Actix", "React"]),
+            tools: skills(&["Docker", "Git", "Neovim"]),
+            databases: skills(&["PostgreSQL", "Redis"]),
+            cloud: skills(&["AWS", "Vercel"]),
+            display: Some(SkillDisplay::Badges),
+            custom_icons: Some(BTreeMap::from([(
+                "My Framework".to_string(),
+                CustomIcon {
+                    slug: "rust".to_string(),
+                    color: text("B7410E"),
+                    aliases: strings(&["myfw"]),
+                },
+            )])),
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            streak: Some(true),
+            contributor_stats: Some(false),
+            trophies: Some(false),
+            contribution_snake: Some(false),
+            profile_views: Some(true),
+            theme: text("tokyonight"),
+            hide_border: Some(false),
+            top_langs_layout: text("compact"),
+            top_langs_count: Some(8),
+            card: Some(StatsCardOptions {
+                hide: Some(vec![StatsRow::Contribs]),
+                show: Some(vec![StatsExtraRow::Reviews, StatsExtraRow::PrsMerged]),
+                count_private: Some(true),
+                include_all_commits: Some(false),
+                custom_title: text("My GitHub Stats"),
+                locale: text("en"),
+                rank_icon: Some(RankIcon::Github),
+                card_width: Some(495),
+                cache_seconds: Some(21600),
+                disable_animations: Some(false),
+            }),
+            langs: Some(TopLangsOptions {
+                exclude_repo: strings(&["dotfiles"]),
+                hide: strings(&["HTML", "CSS"]),
+                size_weight: Some(1.0),
+                count_weight: Some(0.0),
+            }),
+            colors: Some(CardColors {
+                title: text("70A5FD"),
+                text: text("38BDAE"),
+                icon: text("BF91F3"),
+                bg: text("1A1B27"),
+                border: text("E4E2E2"),
+            }),
+        }),
+        projects: Some(Projects {
+            featured: Some(vec![FeaturedProject {
+                repo: "username/repo1".to_string(),
+                description: text("What this project does"),
+            }]),
+            display: Some(ProjectDisplay::PinCards),
+        }),
+        blog: Some(Blog {
+            rss_urls: strings(&["https://yoursite.com/feed.xml"]),
+            articles: Some(vec![Article {
+                title: "My First Post".to_string(),
+                url: "https://yoursite.com/first-post".to_string(),
+            }]),
+            youtube: text("https://youtube.com/@username"),
+            newsletter: text("https://yoursite.com/newsletter"),
+        }),
+        dynamic: Some(Dynamic {
+            spotify_uid: text("your-spotify-user-id"),
+            wakatime: Some(true),
+            github_activity: Some(true),
+            stackoverflow_uid: text("12345"),
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            dark_mode: Some(true),
+            centered: Some(false),
+        }),
+        sponsors: Some(Sponsors {
+            github_sponsors: Some(true),
+            kofi: text("https://ko-fi.com/username"),
+            buy_me_a_coffee: text("https://buymeacoffee.com/username"),
+        }),
+        extras: Some(Extras {
+            pgp_fingerprint: text("ABCD 1234 EFGH 5678"),
+            xbox: text("YourGamertag"),
+            steam: text("YourSteamID"),
+            psn: text("YourPSNID"),
+            certifications: strings(&["AWS Certified Developer"]),
+            custom_blocks: strings(&["Any raw markdown you want inserted"]),
+            collapsible: Some(vec![CollapsibleSection {
+                summary: "Click to expand".to_string(),
+                content: "Hidden content here".to_string(),
+            }]),
+        }),
+        organization: Some(Organization {
+            mission: text("What your organization is building, and why"),
+            contributing: text("We welcome issues and pull requests!"),
+            contributing_url: text("https://github.com/your-org/.github/blob/main/CONTRIBUTING.md"),
+            community: Some(vec![CommunityLink {
+                label: "Discussions".to_string(),
+                url: "https://github.com/orgs/your-org/discussions".to_string(),
+            }]),
+            members: strings(&["alice", "bob"]),
+            members_columns: Some(6),
+        }),
+        services: Some(Services {
+            readme_stats: text("https://github-readme-stats.vercel.app"),
+            streak_stats: text("https://streak-stats.demolab.com"),
+            trophies: text("https://github-profile-trophy.vercel.app"),
+            typing_svg: text("https://readme-typing-svg.demolab.com"),
+            contributor_stats: text("https://github-contributor-stats.vercel.app"),
+            shields: text("https://img.shields.io"),
+            badgen: text("https://badgen.net"),
+            simple_icons: text("https://cdn.simpleicons.org"),
+            profile_views: text("https://komarev.com"),
+            spotify: text("https://spotify-github-profile.kittinan.vercel.app"),
+            stats_card: text("readme_stats"),
+            language_card: text("readme_stats"),
+            streak: text("streak_stats"),
+            pin_card: text("readme_stats"),
+            badge: text("shields"),
+            views: text("profile_views"),
+        }),
+        merge: Some(Merge {
+            arrays: Some(ArrayMerge::Replace),
+            append: strings(&["skills.languages"]),
+            replace: strings(&["projects.featured"]),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uncomment(starter: &str) -> String {
+        starter
+            .strip_prefix(HEADER)
+            .expect("starter begins with the header")
+            .lines()
+            .map(|line: &str| {
+                if line == "#" {
+                    ""
+                } else {
+                    line.strip_prefix("# ").unwrap_or(line)
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    /// Every property in the schema of `def` has a value in `value`.
+    fn assert_populated(starter: &Starter, def: &Value, value: &toml::Value, path: &str) {
+        let table: &toml::Table = value.as_table().unwrap();
+        for (name, field) in def["properties"].as_object().unwrap() {
+            let key: String = join(path, name);
+            let child: &toml::Value = table
+                .get(name)
+                .unwrap_or_else(|| panic!("{} is missing from the starter", key));
+            let field: &Value = non_null(field);
+            if let Shape::Map = starter.shape(field) {
+                let entries: &toml::Table = child.as_table().unwrap();
+                assert!(!entries.is_empty(), "{} has no example entry", key);
+                let entry_def: &Value = starter.resolve(&field["additionalProperties"]);
+                for (name, entry) in entries {
+                    assert_populated(starter, entry_def, entry, &join(&key, name));
+                }
+                continue;
+            }
+            if let Shape::Section(child_def) = starter.shape(field) {
+                match child {
+                    toml::Value::Array(items) => {
+                        assert!(!items.is_empty(), "{} has no example entry", key);
+                        for item in items {
+                            assert_populated(starter, child_def, item, &key);
+                        }
+                    }
+                    other => assert_populated(starter, child_def, other, &key),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_follows_the_model() {
+        let schema: Value = schema();
+        let names: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+        assert_eq!(names, SECTIONS);
+        assert_eq!(
+            enum_options(&schema["$defs"]["Template"]),
+            vec!["minimal", "full", "developer_card", "multi_column"]
+        );
+        assert_eq!(
+            description(&schema["$defs"]["Meta"]["properties"]["kind"]),
+            Some("Whether this profile is for a person (default) or an organization.")
+        );
+    }
+
+    #[test]
+    fn test_uncommented_starter_is_fully_populated() {
+        let schema: Value = schema();
+        let uncommented: String = uncomment(&commented_starter());
+
+        let config: ProfileConfig =
+            toml::from_str(&uncommented).expect("uncommented starter should parse");
+        assert_eq!(config, example_config());
+        let value = toml::Value::try_from(&config).unwrap();
+        let mut out = String::new();
+        let starter = Starter {
+            defs: schema["$defs"].as_object().unwrap(),
+            out: &mut out,
+        };
+        assert_populated(&starter, &schema, &value, "");
+    }
+
+    #[test]
+    fn test_starter_shows_docs_and_options() {
+        let starter: String = commented_starter();
+        assert!(starter.starts_with(HEADER));
+        assert!(starter.contains(
+            "## Whether this profile is for a person (default) or an organization.\n# kind = \"person\"  # Options: person, organization\n"
+        ));
+        assert!(starter.contains("## Organization-only sections"));
+        assert!(starter.contains("# frameworks = [\"Actix\", \"React\"]\n"));
+        assert!(starter.contains("# [[projects.featured]]\n# repo = \"username/repo1\"\n"));
+        assert!(starter.contains(
+            "# [skills.custom_icons]\n# \"My Framework\" = { slug = \"rust\", color = \"B7410E\", aliases = [\"myfw\"] }\n"
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::profile::ProfileConfig;
+use crate::config::toml_io;
+
+/// Manifest describing an organization-wide team directory page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamManifest {
+    pub title: String,
+    pub description: Option<String>,
+    /// Members per grid row (default 4).
+    pub columns: Option<u32>,
+    /// Ordering of members within each group (default: manifest order).
+    pub sort: Option<TeamSort>,
+    /// How many skills to show per member (default 5).
+    pub top_skills: Option<u32>,
+    pub groups: Vec<TeamGroup>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamSort {
+    Manifest,
+    Name,
+    Username,
+    Role,
+}
+
+/// A titled group of members; paths are relative to the manifest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamGroup {
+    pub name: String,
+    pub members: Vec<String>,
+}
+
+/// A manifest with every member profile loaded, grouped as in the manifest.
+#[derive(Debug, Clone)]
+pub struct Team {
+    pub manifest: TeamManifest,
+    pub groups: Vec<(String, Vec<ProfileConfig>)>,
+}
+
+/// Load a team manifest and every member profile it lists.
+pub fn load_team(path: &Path) -> Result<Team> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    let manifest: TeamManifest =
+        toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))?;
+
+    let dir: &Path = path.parent().unwrap_or(Path::new(""));
+    let mut groups: Vec<(String, Vec<ProfileConfig>)> = Vec::new();
+    for group in &manifest.groups {
+        let mut members: Vec<ProfileConfig> = Vec::new();
+        for member in &group.members {
+            let member_path: PathBuf = dir.join(member);
+            let config: ProfileConfig = toml_io::load_config(&member_path)
+                .with_context(|| format!("in team group {:?}", group.name))?;
+            members.push(config);
+        }
+        groups.push((group.name.clone(), members));
+    }
+
+    Ok(Team { manifest, groups })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_team() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("people")).unwrap();
+        std::fs::write(
+            dir.path().join("people/alice.toml"),
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n",
+        )
+        .unwrap();
+        let path: PathBuf = dir.path().join("team.toml");
+        std::fs::write(
+            &path,
+            r#"
+title = "Acme Engineering"
+sort = "name"
+
+[[groups]]
+name = "Platform"
+members = ["people/alice.toml"]
+"#,
+        )
+        .unwrap();
+
+        let team: Team = load_team(&path).unwrap();
+        assert_eq!(team.manifest.title, "Acme Engineering");
+        assert_eq!(team.manifest.sort, Some(TeamSort::Name));
+        assert_eq!(team.groups.len(), 1);
+        assert_eq!(team.groups[0].0, "Platform");
+        assert_eq!(team.groups[0].1[0].meta.username, "alice");
+    }
+
+    #[test]
+    fn test_load_team_missing_member() {
+        let dir = TempDir::new().unwrap();
+        let path: PathBuf = dir.path().join("team.toml");
+        std::fs::write(
+            &path,
+            "title = \"T\"\n[[groups]]\nname = \"G\"\nmembers = [\"ghost.toml\"]\n",
+        )
+        .unwrap();
+
+        let err_msg = format!("{:#}", load_team(&path).unwrap_err());
+        assert!(err_msg.contains("in team group \"G\""));
+        assert!(err_msg.contains("ghost.toml"));
+    }
+}
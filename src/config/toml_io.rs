@@ -1,136 +1,405 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use toml_edit::DocumentMut;
 
-use crate::config::profile::ProfileConfig;
+use crate::config::format::ConfigFormat;
+use crate::config::migrate::{self, MigrationReport};
+use crate::config::profile::*;
+use crate::config::{edit, interpolate, merge, starter};
+use crate::error::ConfigError;
 
-/// Load a ProfileConfig from a TOML file.
+/// A loaded config plus what happened while reading it.
+#[derive(Debug, Clone)]
+pub struct LoadedConfig {
+    /// The effective config, with `extends` bases merged in.
+    pub config: ProfileConfig,
+    /// Schema migration applied to the file itself.
+    pub migration: MigrationReport,
+    /// Human-readable warnings to show the user, e.g. for deprecated fields.
+    pub warnings: Vec<String>,
+}
+
+/// Load a ProfileConfig from a TOML, YAML or JSON file, chosen by extension.
 pub fn load_config(path: &Path) -> Result<ProfileConfig> {
+    Ok(load_config_with_warnings(path)?.config)
+}
+
+/// Load a ProfileConfig from a configuration file (TOML, YAML or JSON),
+/// resolving `extends`, `${VAR}` and `{ file = "..." }` interpolation, and
+/// upgrading older schema versions in memory.
+///
+/// Bases are merged in the order listed, each one recursively resolved first;
+/// the file itself is merged last, so it always wins.
+pub fn load_config_with_warnings(path: &Path) -> Result<LoadedConfig> {
+    load_config_with_format(path, None)
+}
+
+/// Like [`load_config_with_warnings`], but `format` overrides the extension of
+/// `path`. Bases named in `extends` are always read by their own extension.
+pub fn load_config_with_format(path: &Path, format: Option<ConfigFormat>) -> Result<LoadedConfig> {
+    let mut chain: Vec<PathBuf> = Vec::new();
+    let mut warnings: Vec<String> = Vec::new();
+    let format: ConfigFormat = ConfigFormat::resolve(path, format);
+    let (table, migration) = load_resolved_table(path, format, &mut chain, &mut warnings)?;
+
+    if !migration.warnings.is_empty() {
+        warnings.push(format!(
+            "config uses schema version {} (current is {}); run `gh-profile-gen migrate` to upgrade the file",
+            migration.from_version, migration.to_version
+        ));
+    }
+
+    let config: ProfileConfig = toml::Value::Table(table)
+        .try_into()
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    warnings.extend(kind_warnings(&config));
+    Ok(LoadedConfig {
+        config,
+        migration,
+        warnings,
+    })
+}
+
+/// Flag settings that have no effect for the profile's `meta.kind`.
+fn kind_warnings(config: &ProfileConfig) -> Vec<String> {
+    let mut ignored: Vec<&str> = Vec::new();
+
+    if config.meta.is_organization() {
+        if config.about.is_some() {
+            ignored.push("about");
+        }
+        if config.skills.is_some() {
+            ignored.push("skills");
+        }
+        if config.dynamic.is_some() {
+            ignored.push("dynamic");
+        }
+        if config.blog.is_some() {
+            ignored.push("blog");
+        }
+        if let Some(stats) = &config.stats {
+            let user_only: [(&str, Option<bool>); 5] = [
+                ("stats.top_langs", stats.top_langs),
+                ("stats.streak", stats.streak),
+                ("stats.contributor_stats", stats.contributor_stats),
+                ("stats.trophies", stats.trophies),
+                ("stats.contribution_snake", stats.contribution_snake),
+            ];
+            for (key, enabled) in user_only {
+                if enabled == Some(true) {
+                    ignored.push(key);
+                }
+            }
+        }
+        ignored
+            .into_iter()
+            .map(|key: &str| format!("{} is ignored for organization profiles", key))
+            .collect()
+    } else if config.organization.is_some() {
+        vec!["[organization] is ignored unless meta.kind = \"organization\"".to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Read one file, migrate it, and merge it on top of its `extends` bases.
+///
+/// The returned table has `extends` and `merge` removed.
+fn load_resolved_table(
+    path: &Path,
+    format: ConfigFormat,
+    chain: &mut Vec<PathBuf>,
+    warnings: &mut Vec<String>,
+) -> Result<(toml::Table, MigrationReport)> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    let canonical: PathBuf = path
+        .canonicalize()
+        .with_context(|| format!("could not open {}", path.display()))?;
+    if chain.contains(&canonical) {
+        let cycle: Vec<String> = chain
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p: &PathBuf| p.display().to_string())
+            .collect();
+        anyhow::bail!("circular extends: {}", cycle.join(" -> "));
+    }
+
+    let content: String = format
+        .to_toml_string(&content)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let mut doc: DocumentMut = content
+        .parse()
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let migration: MigrationReport = migrate::migrate_document(&mut doc)
+        .with_context(|| format!("failed to migrate {}", path.display()))?;
+    let is_base: bool = !chain.is_empty();
+    for warning in &migration.warnings {
+        if is_base {
+            warnings.push(format!("in base {}: {}", path.display(), warning));
+        } else {
+            warnings.push(warning.clone());
+        }
+    }
+
+    let mut table: toml::Table = toml::from_str(&doc.to_string())
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let dir: &Path = path.parent().unwrap_or(Path::new(""));
+    interpolate::interpolate(&mut table, dir, &|name: &str| std::env::var(name).ok())
+        .with_context(|| format!("failed to load {}", path.display()))?;
+    let extends: Vec<String> = match table.remove("extends") {
+        Some(value) => value
+            .try_into()
+            .with_context(|| format!("{}: extends must be a list of paths", path.display()))?,
+        None => Vec::new(),
+    };
+    let options: Merge = match table.remove("merge") {
+        Some(value) => value
+            .try_into()
+            .with_context(|| format!("failed to parse [merge] in {}", path.display()))?,
+        None => Merge::default(),
+    };
+
+    if extends.is_empty() {
+        return Ok((table, migration));
+    }
+
+    chain.push(canonical);
+    let mut resolved = toml::Table::new();
+    for base in &extends {
+        let base_path: PathBuf = dir.join(base);
+        let base_format: ConfigFormat = ConfigFormat::resolve(&base_path, None);
+        let (base_table, _) = load_resolved_table(&base_path, base_format, chain, warnings)?;
+        merge::merge_tables(&mut resolved, base_table, &options);
+    }
+    merge::merge_tables(&mut resolved, table, &options);
+    chain.pop();
+
+    Ok((resolved, migration))
+}
+
+/// Serialize the effective config, with all `extends` bases merged in.
+///
+/// Written in `format` if given, otherwise in the format of `path`.
+pub fn resolved_config(path: &Path, format: Option<ConfigFormat>) -> Result<String> {
+    let format: ConfigFormat = ConfigFormat::resolve(path, format);
+    let config: ProfileConfig = load_config_with_format(path, Some(format))?.config;
+    format
+        .serialize(&config)
+        .context("failed to serialize profile configuration")
+}
+
+/// Upgrade a config file to the current schema version in place.
+///
+/// For TOML, comments and key order are kept; only deprecated keys are
+/// rewritten. YAML and JSON files are rewritten in their own format.
+pub fn migrate_config_file(path: &Path, format: Option<ConfigFormat>) -> Result<MigrationReport> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("could not open {}", path.display()))?;
-    let config: ProfileConfig =
-        toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))?;
-    Ok(config)
+    let format: ConfigFormat = ConfigFormat::resolve(path, format);
+    let content: String = format
+        .to_toml_string(&content)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let (migrated, report) = migrate::migrate_str(&content)
+        .with_context(|| format!("failed to migrate {}", path.display()))?;
+    if report.migrated() {
+        let migrated: String = match format {
+            ConfigFormat::Toml => migrated,
+            other => {
+                let table: toml::Table = toml::from_str(&migrated)
+                    .with_context(|| format!("failed to migrate {}", path.display()))?;
+                other
+                    .serialize(&table)
+                    .context("failed to serialize profile configuration")?
+            }
+        };
+        std::fs::write(path, migrated)
+            .with_context(|| format!("could not write {}", path.display()))?;
+    }
+    Ok(report)
 }
 
-/// Save a ProfileConfig to a TOML file.
+/// Save a ProfileConfig in the format matching the extension of `path`,
+/// stamping the current schema version if unset.
 pub fn save_config(config: &ProfileConfig, path: &Path) -> Result<()> {
-    let content =
-        toml::to_string_pretty(config).context("failed to serialize profile configuration")?;
+    save_config_as(config, path, ConfigFormat::resolve(path, None))
+}
+
+/// Save a ProfileConfig in an explicit format, stamping the current schema version if unset.
+///
+/// An existing TOML file is updated in place, so comments, commented-out
+/// sections and key order survive. YAML and JSON files are rewritten.
+pub fn save_config_as(config: &ProfileConfig, path: &Path, format: ConfigFormat) -> Result<()> {
+    let content: String = serialize_config(config, format)?;
+    let content: String = if format == ConfigFormat::Toml && path.exists() {
+        let existing = std::fs::read_to_string(path)
+            .with_context(|| format!("could not open {}", path.display()))?;
+        let mut doc: DocumentMut = existing
+            .parse()
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        edit::update_document(&mut doc, &content)
+            .context("failed to serialize profile configuration")?;
+        doc.to_string()
+    } else {
+        content
+    };
     std::fs::write(path, content).with_context(|| format!("could not write {}", path.display()))?;
     Ok(())
 }
 
-/// Generate a starter TOML string with all sections shown as examples.
-pub fn generate_starter_toml() -> String {
-    r#"# gh-profile-gen profile configuration
-# Uncomment and fill in the sections you want in your README.
+/// Apply `edit` to the raw values of a config file and write it back.
+///
+/// Works on the file itself rather than the resolved config, so `extends`,
+/// `${VAR}` and `{ file = ... }` values are left as written. TOML files keep
+/// their comments and layout. Older schema versions must be migrated first.
+pub fn edit_config_file<F>(path: &Path, format: Option<ConfigFormat>, edit: F) -> Result<()>
+where
+    F: FnOnce(&mut toml::Table) -> Result<(), ConfigError>,
+{
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    let format: ConfigFormat = ConfigFormat::resolve(path, format);
+    let mut table: toml::Table = format
+        .parse_table(&content)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
 
-[meta]
-username = "your-github-username"
-# name = "Your Display Name"
-
-# [header]
-# style = "typing_svg"  # Options: typing_svg, text, banner, wave
-# typing_lines = ["Hello", "World"]
-# typing_font = "Fira Code"
-# typing_color = "f75c7e"
-# tagline = "Your tagline here"
-# banner_url = "https://example.com/banner.png"
-
-# [about]
-# role = "Your Role"
-# company = "Your Company"
-# current_work = "project-name"
-# learning = "Something new"
-# reach_me = "your@email.com"
-# fun_fact = "Something fun"
-# pronouns = "they/them"
-# location = "City, Country"
-# timezone = "UTC"
-
-# [social]
-# github = "https://github.com/username"
-# twitter = "https://twitter.com/username"
-# linkedin = "https://linkedin.com/in/username"
-# mastodon = "https://mastodon.social/@username"
-# bluesky = "https://bsky.app/profile/username"
-# instagram = "https://instagram.com/username"
-# youtube = "https://youtube.com/@username"
-# discord = "https://discord.gg/invite"
-# devto = "https://dev.to/username"
-# hashnode = "https://hashnode.com/@username"
-# medium = "https://medium.com/@username"
-# stackoverflow = "https://stackoverflow.com/users/id"
-# reddit = "https://reddit.com/u/username"
-# twitch = "https://twitch.tv/username"
-# website = "https://yoursite.com"
-# email = "your@email.com"
-# kofi = "https://ko-fi.com/username"
-# rss = "https://yoursite.com/feed.xml"
-
-# [skills]
-# languages = ["Rust", "Python", "TypeScript"]
-# frameworks = ["Actix", "React"]
-# tools = ["Docker", "Git", "Neovim"]
-# databases = ["PostgreSQL", "Redis"]
-# cloud = ["AWS", "Vercel"]
-
-# [stats]
-# stats_card = true
-# top_langs = true
-# streak = true
-# contributor_stats = false
-# trophies = false
-# contribution_snake = false
-# profile_views = true
-# theme = "tokyonight"
-# hide_border = false
-# top_langs_layout = "compact"
-# top_langs_count = 8
-
-# [projects]
-# repos = ["username/repo1", "username/repo2"]
-# display = "pin_cards"  # Options: pin_cards, markdown_table
-
-# [blog]
-# rss_urls = ["https://yoursite.com/feed.xml"]
-# youtube = "https://youtube.com/@username"
-# newsletter = "https://yoursite.com/newsletter"
-#
-# [[blog.articles]]
-# title = "My First Post"
-# url = "https://yoursite.com/first-post"
-
-# [dynamic]
-# spotify_uid = "your-spotify-user-id"
-# wakatime = true
-# github_activity = true
-# stackoverflow_uid = "12345"
-
-# [layout]
-# template = "full"  # Options: minimal, full, developer_card, multi_column
-# dark_mode = true
-# centered = false
-
-# [sponsors]
-# github_sponsors = true
-# kofi = "https://ko-fi.com/username"
-# buy_me_a_coffee = "https://buymeacoffee.com/username"
-
-# [extras]
-# pgp_fingerprint = "ABCD 1234 EFGH 5678"
-# xbox = "YourGamertag"
-# steam = "YourSteamID"
-# psn = "YourPSNID"
-# certifications = ["AWS Certified Developer"]
-# custom_blocks = ["Any raw markdown you want inserted"]
-#
-# [[extras.collapsible]]
-# summary = "Click to expand"
-# content = "Hidden content here"
-"#
-    .to_string()
+    let version: Option<i64> = table
+        .get("meta")
+        .and_then(|meta: &toml::Value| meta.get("schema_version"))
+        .and_then(toml::Value::as_integer);
+    if version.is_none_or(|v: i64| v < i64::from(migrate::CURRENT_SCHEMA_VERSION)) {
+        anyhow::bail!(
+            "{} uses an older schema version; run `gh-profile-gen migrate` first",
+            path.display()
+        );
+    }
+
+    edit(&mut table)?;
+
+    let mut sorted = toml::Table::new();
+    for section in SECTIONS {
+        if let Some(value) = table.remove(*section) {
+            sorted.insert(section.to_string(), value);
+        }
+    }
+    sorted.extend(table);
+
+    let content: String = match format {
+        ConfigFormat::Toml => {
+            let mut doc: DocumentMut = content
+                .parse()
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+            let updated: String =
+                toml::to_string(&sorted).context("failed to serialize profile configuration")?;
+            edit::update_document(&mut doc, &updated)
+                .context("failed to serialize profile configuration")?;
+            doc.to_string()
+        }
+        other => other
+            .serialize(&sorted)
+            .context("failed to serialize profile configuration")?,
+    };
+    std::fs::write(path, content).with_context(|| format!("could not write {}", path.display()))?;
+    Ok(())
+}
+
+/// Write a ProfileConfig to `path` from scratch, replacing any existing file.
+pub fn write_config(config: &ProfileConfig, path: &Path, format: ConfigFormat) -> Result<()> {
+    let content: String = serialize_config(config, format)?;
+    std::fs::write(path, content).with_context(|| format!("could not write {}", path.display()))?;
+    Ok(())
+}
+
+fn serialize_config(config: &ProfileConfig, format: ConfigFormat) -> Result<String> {
+    let mut config: ProfileConfig = config.clone();
+    config
+        .meta
+        .schema_version
+        .get_or_insert(migrate::CURRENT_SCHEMA_VERSION);
+    format
+        .serialize(&config)
+        .context("failed to serialize profile configuration")
+}
+
+/// Generate a starter config in the given format.
+///
+/// TOML gets the fully commented template. YAML and JSON have no equivalent
+/// for commented-out tables, so they get a small filled-in example instead.
+pub fn generate_starter(format: ConfigFormat) -> Result<String> {
+    match format {
+        ConfigFormat::Toml => Ok(generate_starter_toml()),
+        ConfigFormat::Yaml => {
+            let body: String = format
+                .serialize(&starter_config())
+                .context("failed to serialize starter configuration")?;
+            Ok(format!(
+                "# gh-profile-gen profile configuration\n# Edit the example values and delete the sections you don't want.\n\n{}",
+                body
+            ))
+        }
+        ConfigFormat::Json => format
+            .serialize(&starter_config())
+            .context("failed to serialize starter configuration"),
+    }
+}
+
+/// The example profile behind the YAML and JSON starters.
+fn starter_config() -> ProfileConfig {
+    ProfileConfig {
+        meta: Meta {
+            username: "your-github-username".to_string(),
+            name: Some("Your Display Name".to_string()),
+            kind: None,
+            schema_version: Some(migrate::CURRENT_SCHEMA_VERSION),
+        },
+        header: Some(Header {
+            style: Some(HeaderStyle::TypingSvg),
+            typing_lines: Some(vec!["Hello".to_string(), "World".to_string()]),
+            tagline: Some("Your tagline here".to_string()),
+            ..Header::default()
+        }),
+        about: Some(About {
+            role: Some("Your Role".to_string()),
+            learning: Some("Something new".to_string()),
+            location: Some("City, Country".to_string()),
+            ..About::default()
+        }),
+        social: Some(Social {
+            github: Some("https://github.com/username".into()),
+            website: Some("https://yoursite.com".into()),
+            ..Social::default()
+        }),
+        skills: Some(Skills {
+            languages: Some(vec!["Rust".into(), "Python".into()]),
+            tools: Some(vec!["Docker".into(), "Git".into()]),
+            ..Skills::default()
+        }),
+        stats: Some(Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            theme: Some("tokyonight".to_string()),
+            ..Stats::default()
+        }),
+        projects: Some(Projects {
+            featured: Some(vec![FeaturedProject {
+                repo: "username/repo1".to_string(),
+                description: Some("What this project does".to_string()),
+            }]),
+            display: Some(ProjectDisplay::PinCards),
+        }),
+        layout: Some(Layout {
+            template: Some(Template::Full),
+            dark_mode: Some(true),
+            centered: Some(false),
+        }),
+        ..ProfileConfig::default()
+    }
+}
+
+/// Generate a starter TOML string with every field shown as a commented-out example.
+pub fn generate_starter_toml() -> String {
+    starter::commented_starter()
 }
 
 #[cfg(test)]
@@ -148,6 +417,8 @@ mod tests {
             meta: Meta {
                 username: "alice".to_string(),
                 name: Some("Alice".to_string()),
+                kind: None,
+                schema_version: Some(migrate::CURRENT_SCHEMA_VERSION),
             },
             ..ProfileConfig::default()
         };
@@ -194,5 +465,356 @@ mod tests {
         let config: ProfileConfig =
             toml::from_str(&uncommented).expect("starter TOML should parse");
         assert_eq!(config.meta.username, "your-github-username");
+        assert_eq!(
+            config.meta.schema_version,
+            Some(migrate::CURRENT_SCHEMA_VERSION)
+        );
+    }
+
+    #[test]
+    fn test_load_legacy_config_reports_migration() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profile.toml");
+        std::fs::write(
+            &path,
+            "[meta]\nusername = \"alice\"\n\n[projects]\nrepos = [\"alice/cool-cli\"]\n",
+        )
+        .unwrap();
+
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert!(loaded.migration.migrated());
+        let warnings: Vec<String> = loaded.warnings.clone();
+        let featured = loaded.config.projects.unwrap().featured.unwrap();
+        assert_eq!(featured[0].repo, "alice/cool-cli");
+        assert!(
+            warnings
+                .iter()
+                .any(|w: &String| w.contains("projects.repos"))
+        );
+        assert!(warnings.iter().any(|w: &String| w.contains("migrate")));
+    }
+
+    #[test]
+    fn test_load_unversioned_config_without_deprecated_fields_is_quiet() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profile.toml");
+        std::fs::write(
+            &path,
+            "[meta]\nusername = \"alice\"\n\n[about]\nrole = \"Engineer\"\n",
+        )
+        .unwrap();
+
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert!(loaded.warnings.is_empty(), "{:?}", loaded.warnings);
+    }
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path: PathBuf = dir.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_organization_warns_about_person_only_settings() {
+        let dir = TempDir::new().unwrap();
+        let path: PathBuf = write(
+            dir.path(),
+            "org.toml",
+            "[meta]\nusername = \"acme\"\nkind = \"organization\"\nschema_version = 2\n\n[about]\nfun_fact = \"x\"\n\n[stats]\nstats_card = true\nstreak = true\n",
+        );
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert_eq!(
+            loaded.warnings,
+            vec![
+                "about is ignored for organization profiles".to_string(),
+                "stats.streak is ignored for organization profiles".to_string(),
+            ]
+        );
+
+        let path: PathBuf = write(
+            dir.path(),
+            "person.toml",
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[organization]\nmission = \"x\"\n",
+        );
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(loaded.warnings[0].contains("[organization] is ignored"));
+    }
+
+    #[test]
+    fn test_load_config_extends_merges_bases() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "team/base.toml",
+            r#"
+[stats]
+stats_card = true
+theme = "tokyonight"
+
+[skills]
+languages = ["Go"]
+tools = ["Git"]
+
+[sponsors]
+github_sponsors = true
+"#,
+        );
+        let path = write(
+            dir.path(),
+            "people/alice.toml",
+            r#"
+extends = ["../team/base.toml"]
+
+[meta]
+username = "alice"
+schema_version = 2
+
+[stats]
+theme = "radical"
+
+[skills]
+languages = ["Rust"]
+tools = ["Vim"]
+
+[merge]
+append = ["skills.languages"]
+"#,
+        );
+
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert!(loaded.warnings.is_empty());
+        let config = loaded.config;
+        assert_eq!(config.meta.username, "alice");
+        assert!(config.extends.is_none());
+        assert!(config.merge.is_none());
+
+        let stats = config.stats.unwrap();
+        assert_eq!(stats.stats_card, Some(true));
+        assert_eq!(stats.theme.as_deref(), Some("radical"));
+
+        let skills = config.skills.unwrap();
+        assert_eq!(skills.languages, Some(vec!["Go".into(), "Rust".into()]));
+        assert_eq!(skills.tools, Some(vec!["Vim".into()]));
+        assert_eq!(config.sponsors.unwrap().github_sponsors, Some(true));
+    }
+
+    #[test]
+    fn test_load_config_extends_later_bases_win() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "a.toml",
+            "[stats]\ntheme = \"a\"\nstreak = true\n",
+        );
+        write(
+            dir.path(),
+            "b.toml",
+            "extends = [\"a.toml\"]\n[stats]\ntheme = \"b\"\n",
+        );
+        write(dir.path(), "c.toml", "[stats]\ntheme = \"c\"\n");
+        let path = write(
+            dir.path(),
+            "profile.toml",
+            "extends = [\"b.toml\", \"c.toml\"]\n[meta]\nusername = \"alice\"\n",
+        );
+
+        let stats = load_config(&path).unwrap().stats.unwrap();
+        assert_eq!(stats.theme.as_deref(), Some("c"));
+        assert_eq!(stats.streak, Some(true));
+    }
+
+    #[test]
+    fn test_load_config_extends_cycle() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "a.toml", "extends = [\"b.toml\"]\n");
+        write(dir.path(), "b.toml", "extends = [\"a.toml\"]\n");
+        let path = write(
+            dir.path(),
+            "profile.toml",
+            "extends = [\"a.toml\"]\n[meta]\nusername = \"alice\"\n",
+        );
+
+        let err_msg = format!("{:#}", load_config(&path).unwrap_err());
+        assert!(err_msg.contains("circular extends"));
+    }
+
+    #[test]
+    fn test_load_config_extends_missing_base() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            dir.path(),
+            "profile.toml",
+            "extends = [\"nope.toml\"]\n[meta]\nusername = \"alice\"\n",
+        );
+
+        let err_msg = format!("{:#}", load_config(&path).unwrap_err());
+        assert!(err_msg.contains("could not open"));
+        assert!(err_msg.contains("nope.toml"));
+    }
+
+    #[test]
+    fn test_load_config_file_include_relative_to_config() {
+        let dir = TempDir::new().unwrap();
+        write(dir.path(), "people/bio.md", "Builds *fast* things.\n");
+        let path = write(
+            dir.path(),
+            "people/alice.toml",
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[about]\nfun_fact = { file = \"bio.md\" }\n",
+        );
+
+        let about = load_config(&path).unwrap().about.unwrap();
+        assert_eq!(about.fun_fact.as_deref(), Some("Builds *fast* things."));
+    }
+
+    #[test]
+    fn test_load_old_config_with_dollar_braces_in_text() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            dir.path(),
+            "profile.toml",
+            "[meta]\nusername = \"alice\"\n\n[header]\ntagline = \"Writes shell like ${HOME_PRICE} all day\"\n\n[about]\nbio = \"Templating with ${name}\"\nfun_fact = \"I type ${ a lot\"\n",
+        );
+
+        let config = load_config(&path).unwrap();
+        assert_eq!(
+            config.header.unwrap().tagline.as_deref(),
+            Some("Writes shell like ${HOME_PRICE} all day")
+        );
+        assert_eq!(
+            config.about.unwrap().fun_fact.as_deref(),
+            Some("I type ${ a lot")
+        );
+    }
+
+    #[test]
+    fn test_migrate_config_file_rewrites_in_place() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profile.toml");
+        std::fs::write(
+            &path,
+            "# keep me\n[meta]\nusername = \"alice\"\n\n[projects]\nrepos = [\"alice/cool-cli\"]\n",
+        )
+        .unwrap();
+
+        let report = migrate_config_file(&path, None).unwrap();
+        assert!(report.migrated());
+        let content: String = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# keep me"));
+        assert!(content.contains("schema_version = 2"));
+
+        let loaded = load_config_with_warnings(&path).unwrap();
+        assert!(!loaded.migration.migrated());
+        assert!(loaded.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip_each_format() {
+        let dir = TempDir::new().unwrap();
+        let config: ProfileConfig = load_config(Path::new("tests/fixtures/full.toml")).unwrap();
+
+        for format in ConfigFormat::ALL {
+            let path: PathBuf = dir.path().join(format!("profile.{}", format));
+            save_config(&config, &path).unwrap();
+            let loaded: ProfileConfig = load_config(&path).unwrap();
+            assert_eq!(config, loaded, "round trip through {}", format);
+        }
+    }
+
+    #[test]
+    fn test_explicit_format_overrides_extension() {
+        let dir = TempDir::new().unwrap();
+        let path: PathBuf = write(
+            dir.path(),
+            "profile.conf",
+            "meta:\n  username: alice\n  schema_version: 2\n",
+        );
+
+        assert!(load_config(&path).is_err());
+        let loaded = load_config_with_format(&path, Some(ConfigFormat::Yaml)).unwrap();
+        assert_eq!(loaded.config.meta.username, "alice");
+        assert!(loaded.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_starter_in_each_format_loads() {
+        let dir = TempDir::new().unwrap();
+        for format in ConfigFormat::ALL {
+            let path: PathBuf = dir.path().join(format!("profile.{}", format));
+            std::fs::write(&path, generate_starter(format).unwrap()).unwrap();
+            let loaded = load_config_with_warnings(&path).unwrap();
+            assert_eq!(loaded.config.meta.username, "your-github-username");
+            assert!(
+                loaded.warnings.is_empty(),
+                "{}: {:?}",
+                format,
+                loaded.warnings
+            );
+        }
+        assert!(
+            generate_starter(ConfigFormat::Yaml)
+                .unwrap()
+                .starts_with('#')
+        );
+    }
+
+    #[test]
+    fn test_yaml_extends_toml_base() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "base.toml",
+            "[meta]\nusername = \"\"\nschema_version = 2\n\n[stats]\ntheme = \"dracula\"\n",
+        );
+        let path: PathBuf = write(
+            dir.path(),
+            "alice.yml",
+            "extends: [base.toml]\nmeta:\n  username: alice\n  schema_version: 2\n",
+        );
+
+        let config: ProfileConfig = load_config(&path).unwrap();
+        assert_eq!(config.meta.username, "alice");
+        assert_eq!(config.stats.unwrap().theme.as_deref(), Some("dracula"));
+    }
+
+    #[test]
+    fn test_migrate_json_file_keeps_format() {
+        let dir = TempDir::new().unwrap();
+        let path: PathBuf = write(
+            dir.path(),
+            "profile.json",
+            r#"{"meta": {"username": "alice"}, "projects": {"repos": ["alice/cool-cli"]}}"#,
+        );
+
+        let report = migrate_config_file(&path, None).unwrap();
+        assert!(report.migrated());
+        let content: String = std::fs::read_to_string(&path).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(value["meta"]["schema_version"], 2);
+        assert_eq!(value["projects"]["featured"][0]["repo"], "alice/cool-cli");
+    }
+
+    #[test]
+    fn test_save_config_keeps_comments_from_starter() {
+        let dir = TempDir::new().unwrap();
+        let path: PathBuf = write(dir.path(), "profile.toml", &generate_starter_toml());
+
+        let mut config: ProfileConfig = load_config(&path).unwrap();
+        config.meta.username = "alice".to_string();
+        config.about = Some(About {
+            role: Some("Engineer".to_string()),
+            ..About::default()
+        });
+        save_config(&config, &path).unwrap();
+
+        let content: String = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# gh-profile-gen profile configuration"));
+        assert!(content.contains("# [social]\n# github = "));
+        assert!(content.contains("username = \"alice\""));
+        assert!(content.find("[about]").unwrap() > content.find("[meta]").unwrap());
+        assert_eq!(load_config(&path).unwrap(), config);
     }
 }
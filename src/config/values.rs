@@ -0,0 +1,25 @@
+//! Shorthand for writing out configs in code, shared by the presets and the
+//! starter's example config.
+
+use crate::config::profile::{SkillEntry, SocialEntry};
+
+pub(crate) fn text(value: &str) -> Option<String> {
+    Some(value.to_string())
+}
+
+pub(crate) fn link(value: &str) -> Option<SocialEntry> {
+    Some(value.into())
+}
+
+pub(crate) fn strings(items: &[&str]) -> Option<Vec<String>> {
+    Some(items.iter().map(|s: &&str| s.to_string()).collect())
+}
+
+pub(crate) fn skills(names: &[&str]) -> Option<Vec<SkillEntry>> {
+    Some(
+        names
+            .iter()
+            .map(|name: &&str| SkillEntry::from(*name))
+            .collect(),
+    )
+}
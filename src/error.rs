@@ -8,9 +8,62 @@ pub enum ConfigError {
     #[error("unknown template: {0}")]
     UnknownTemplate(String),
 
+    #[error("unknown preset: {0} (run `init --list-presets` to see them all)")]
+    UnknownPreset(String),
+
     #[error("failed to parse config: {0}")]
     ParseError(#[from] toml::de::Error),
 
+    #[error("failed to parse JSON config: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("failed to parse YAML config: {0}")]
+    YamlError(#[from] serde_yaml_ng::Error),
+
+    #[error("unknown config format: {0} (expected toml, yaml or json)")]
+    UnknownFormat(String),
+
+    #[error("failed to parse config document: {0}")]
+    DocumentError(#[from] toml_edit::TomlError),
+
+    #[error(
+        "config schema version {found} is newer than this build supports (version {supported})"
+    )]
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
+
+    #[error("invalid meta.schema_version: expected a positive integer")]
+    InvalidSchemaVersion,
+
+    #[error(
+        "environment variable {name} is not set (used in {key}); set it or write ${{{name}:-default}}"
+    )]
+    MissingEnvVar { name: String, key: String },
+
+    #[error("invalid interpolation in {key}: {message}")]
+    InvalidInterpolation { key: String, message: String },
+
+    #[error("could not read {path} (included by {key}): {source}")]
+    Include {
+        path: std::path::PathBuf,
+        key: String,
+        source: std::io::Error,
+    },
+
+    #[error("invalid field path: {0}")]
+    InvalidPath(String),
+
+    #[error("unknown config field: {0}")]
+    UnknownField(String),
+
+    #[error("invalid value for {path}: {message}")]
+    InvalidValue { path: String, message: String },
+
+    #[error("nothing to remove at {0}")]
+    MissingField(String),
+
+    #[error("{value} is already in {path}")]
+    DuplicateEntry { path: String, value: String },
+
     #[error("failed to serialize config: {0}")]
     SerializeError(#[from] toml::ser::Error),
 
@@ -0,0 +1,249 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::config::profile::*;
+
+/// How many repositories to feature, matching the six pins GitHub shows.
+const FEATURED_LIMIT: usize = 6;
+
+/// The fields we use from a saved `GET /users/{username}` response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GithubUser {
+    pub login: String,
+    pub name: Option<String>,
+    pub company: Option<String>,
+    pub blog: Option<String>,
+    pub location: Option<String>,
+    pub email: Option<String>,
+    pub bio: Option<String>,
+    pub twitter_username: Option<String>,
+    /// `User` or `Organization`.
+    #[serde(rename = "type")]
+    pub account_type: Option<String>,
+}
+
+/// The fields we use from one entry of a saved `GET /users/{username}/repos` response.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GithubRepo {
+    pub name: String,
+    pub full_name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub stargazers_count: u64,
+    #[serde(default)]
+    pub fork: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Read saved GitHub API responses and build a profile from them. No network access.
+pub fn load_github_json(user_path: &Path, repos_path: Option<&Path>) -> Result<ProfileConfig> {
+    let user: GithubUser = read_json(user_path)?;
+    let repos: Vec<GithubRepo> = match repos_path {
+        Some(path) => read_json(path)?,
+        None => Vec::new(),
+    };
+    Ok(profile_from_github(&user, &repos))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse GitHub API JSON in {}", path.display()))
+}
+
+/// Prefill meta, about, social and featured projects from GitHub API data.
+///
+/// Featured projects are the most-starred repositories that are neither forks
+/// nor archived.
+pub fn profile_from_github(user: &GithubUser, repos: &[GithubRepo]) -> ProfileConfig {
+    let kind: Option<ProfileKind> = match user.account_type.as_deref() {
+        Some("Organization") => Some(ProfileKind::Organization),
+        _ => None,
+    };
+
+    let company: Option<String> =
+        non_empty(&user.company).map(|c: &str| c.trim_start_matches('@').to_string());
+    let location: Option<String> = non_empty(&user.location).map(str::to_string);
+    let about: Option<About> = if company.is_some() || location.is_some() {
+        Some(About {
+            company,
+            location,
+            ..About::default()
+        })
+    } else {
+        None
+    };
+
+    let header: Option<Header> = non_empty(&user.bio).map(|bio: &str| Header {
+        style: Some(HeaderStyle::Text),
+        tagline: Some(bio.trim().to_string()),
+        ..Header::default()
+    });
+
+    let social = Social {
+        github: Some(format!("https://github.com/{}", user.login).into()),
+        twitter: non_empty(&user.twitter_username)
+            .map(|handle: &str| format!("https://x.com/{}", handle).into()),
+        website: non_empty(&user.blog).map(|blog: &str| {
+            if blog.contains("://") {
+                blog.into()
+            } else {
+                format!("https://{}", blog).into()
+            }
+        }),
+        email: non_empty(&user.email).map(SocialEntry::from),
+        ..Social::default()
+    };
+
+    ProfileConfig {
+        meta: Meta {
+            username: user.login.clone(),
+            name: non_empty(&user.name).map(str::to_string),
+            kind,
+            schema_version: None,
+        },
+        header,
+        about,
+        social: Some(social),
+        projects: featured_projects(repos),
+        ..ProfileConfig::default()
+    }
+}
+
+fn featured_projects(repos: &[GithubRepo]) -> Option<Projects> {
+    let mut candidates: Vec<&GithubRepo> = repos
+        .iter()
+        .filter(|r: &&GithubRepo| !r.fork && !r.archived)
+        .collect();
+    candidates.sort_by(|a: &&GithubRepo, b: &&GithubRepo| {
+        b.stargazers_count
+            .cmp(&a.stargazers_count)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let featured: Vec<FeaturedProject> = candidates
+        .into_iter()
+        .take(FEATURED_LIMIT)
+        .map(|repo: &GithubRepo| FeaturedProject {
+            repo: repo.full_name.clone().unwrap_or_else(|| repo.name.clone()),
+            description: non_empty(&repo.description).map(str::to_string),
+        })
+        .collect();
+
+    if featured.is_empty() {
+        return None;
+    }
+    Some(Projects {
+        featured: Some(featured),
+        display: None,
+    })
+}
+
+/// The API returns `""` or `null` for unset profile fields.
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v: &&str| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: &str = r#"{
+        "login": "octocat",
+        "id": 583231,
+        "type": "User",
+        "name": "The Octocat",
+        "company": "@github",
+        "blog": "octocat.dev",
+        "location": "San Francisco",
+        "email": null,
+        "bio": "Mascot and occasional coder",
+        "twitter_username": "octocat",
+        "public_repos": 8
+    }"#;
+
+    fn repo(name: &str, stars: u64, fork: bool) -> GithubRepo {
+        GithubRepo {
+            name: name.to_string(),
+            full_name: Some(format!("octocat/{}", name)),
+            description: Some(format!("{} description", name)),
+            stargazers_count: stars,
+            fork,
+            archived: false,
+        }
+    }
+
+    #[test]
+    fn test_profile_from_github_user() {
+        let user: GithubUser = serde_json::from_str(USER).unwrap();
+        let config: ProfileConfig = profile_from_github(&user, &[]);
+
+        assert_eq!(config.meta.username, "octocat");
+        assert_eq!(config.meta.name.as_deref(), Some("The Octocat"));
+        assert!(!config.meta.is_organization());
+        let about: About = config.about.unwrap();
+        assert_eq!(about.company.as_deref(), Some("github"));
+        assert_eq!(about.location.as_deref(), Some("San Francisco"));
+        assert_eq!(
+            config.header.unwrap().tagline.as_deref(),
+            Some("Mascot and occasional coder")
+        );
+        let social: Social = config.social.unwrap();
+        assert_eq!(
+            social.website.as_ref().map(SocialEntry::url),
+            Some("https://octocat.dev")
+        );
+        assert_eq!(
+            social.twitter.as_ref().map(SocialEntry::url),
+            Some("https://x.com/octocat")
+        );
+        assert!(social.email.is_none());
+        assert!(config.projects.is_none());
+    }
+
+    #[test]
+    fn test_featured_projects_by_stars() {
+        let repos: Vec<GithubRepo> = vec![
+            repo("small", 3, false),
+            repo("forked", 500, true),
+            repo("big", 120, false),
+            repo("medium", 40, false),
+            repo("a", 1, false),
+            repo("b", 1, false),
+            repo("c", 1, false),
+            repo("d", 1, false),
+        ];
+        let projects: Projects = featured_projects(&repos).unwrap();
+        let names: Vec<String> = projects
+            .featured
+            .unwrap()
+            .into_iter()
+            .map(|p: FeaturedProject| p.repo)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "octocat/big",
+                "octocat/medium",
+                "octocat/small",
+                "octocat/a",
+                "octocat/b",
+                "octocat/c",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_organization_account() {
+        let user: GithubUser =
+            serde_json::from_str(r#"{"login": "acme", "type": "Organization", "blog": ""}"#)
+                .unwrap();
+        let config: ProfileConfig = profile_from_github(&user, &[]);
+        assert!(config.meta.is_organization());
+        assert!(config.social.unwrap().website.is_none());
+    }
+}
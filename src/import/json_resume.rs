@@ -0,0 +1,586 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::profile::*;
+use crate::render::sections::{self, PlatformLink};
+use crate::services::urls;
+
+/// Skill categories as exported, paired with the `[skills]` list they map to.
+const SKILL_CATEGORIES: &[(&str, &str)] = &[
+    ("Languages", "languages"),
+    ("Frameworks", "frameworks"),
+    ("Tools", "tools"),
+    ("Databases", "databases"),
+    ("Cloud/Infra", "cloud"),
+];
+
+/// The subset of the JSON Resume schema (<https://jsonresume.org/schema>) we map.
+///
+/// Sections we do not use, such as `education`, are ignored on import.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonResume {
+    pub basics: Basics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<Work>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<Skill>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<Certificate>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<Publication>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Basics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<NetworkProfile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkProfile {
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Work {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Skill {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Certificate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Publication {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Read a `resume.json` file and map it to a profile.
+pub fn load_json_resume(path: &Path) -> Result<ProfileConfig> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    let resume: JsonResume = serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse JSON Resume in {}", path.display()))?;
+    Ok(profile_from_resume(&resume))
+}
+
+/// Map basics, profiles, skills, certificates and publications into a profile.
+///
+/// The GitHub entry in `basics.profiles` provides `meta.username`.
+pub fn profile_from_resume(resume: &JsonResume) -> ProfileConfig {
+    let basics: &Basics = &resume.basics;
+    let mut config = ProfileConfig {
+        meta: Meta {
+            name: basics.name.clone(),
+            ..Meta::default()
+        },
+        ..ProfileConfig::default()
+    };
+
+    let current_job: Option<&Work> = resume
+        .work
+        .iter()
+        .find(|w: &&Work| w.end_date.is_none() && w.name.is_some());
+    let about = About {
+        role: basics
+            .label
+            .clone()
+            .or_else(|| current_job.and_then(|w| w.position.clone())),
+        company: current_job.and_then(|w| w.name.clone()),
+        location: basics.location.as_ref().and_then(format_location),
+        ..About::default()
+    };
+    if about != About::default() {
+        config.about = Some(about);
+    }
+
+    if let Some(summary) = &basics.summary {
+        config.header = Some(Header {
+            style: Some(HeaderStyle::Text),
+            tagline: Some(summary.clone()),
+            ..Header::default()
+        });
+    }
+
+    let mut social = Social {
+        email: basics.email.clone().map(SocialEntry::from),
+        website: basics.url.clone().map(SocialEntry::from),
+        ..Social::default()
+    };
+    for profile in &basics.profiles {
+        let network: String = profile.network.to_lowercase();
+        if network == "github"
+            && let Some(username) = &profile.username
+        {
+            config.meta.username = username.clone();
+        }
+        let url: Option<String> = profile.url.clone().or_else(|| {
+            profile
+                .username
+                .as_ref()
+                .filter(|_| network == "github")
+                .map(|u: &String| format!("https://github.com/{}", u))
+        });
+        let Some(url) = url else {
+            continue;
+        };
+        if let Some(slot) = social_slot(&mut social, &network) {
+            *slot = Some(url.into());
+        } else if !matches!(network.as_str(), "website" | "email" | "rss") {
+            social
+                .custom
+                .get_or_insert_with(Vec::new)
+                .push(CustomSocial {
+                    label: profile.network.clone(),
+                    url,
+                    logo: None,
+                    color: None,
+                    verify: None,
+                });
+        }
+    }
+    if config.meta.username.is_empty()
+        && let Some(account) = social
+            .github
+            .as_ref()
+            .map(SocialEntry::url)
+            .and_then(|url: &str| url.split("github.com/").nth(1))
+    {
+        config.meta.username = account.trim_end_matches('/').to_string();
+    }
+    if social != Social::default() {
+        config.social = Some(social);
+    }
+
+    let skills: Skills = skills_from_resume(&resume.skills);
+    if skills != Skills::default() {
+        config.skills = Some(skills);
+    }
+
+    let certifications: Vec<String> = resume
+        .certificates
+        .iter()
+        .map(|c: &Certificate| match &c.issuer {
+            Some(issuer) => format!("{} ({})", c.name, issuer),
+            None => c.name.clone(),
+        })
+        .collect();
+    if !certifications.is_empty() {
+        config.extras = Some(Extras {
+            certifications: Some(certifications),
+            ..Extras::default()
+        });
+    }
+
+    let articles: Vec<Article> = resume
+        .publications
+        .iter()
+        .filter_map(|p: &Publication| {
+            Some(Article {
+                title: p.name.clone(),
+                url: p.url.clone()?,
+            })
+        })
+        .collect();
+    if !articles.is_empty() {
+        config.blog = Some(Blog {
+            articles: Some(articles),
+            ..Blog::default()
+        });
+    }
+
+    config
+}
+
+/// Exported skill groups keep their category; other groups are sorted by keyword.
+fn skills_from_resume(groups: &[Skill]) -> Skills {
+    let mut skills = Skills::default();
+
+    for group in groups {
+        let names: Vec<&String> = if group.keywords.is_empty() {
+            vec![&group.name]
+        } else {
+            group.keywords.iter().collect()
+        };
+        let group_category: Option<&str> = SKILL_CATEGORIES
+            .iter()
+            .find(|(label, _)| label.eq_ignore_ascii_case(&group.name))
+            .map(|(_, key)| *key);
+
+        for name in names {
+            let category: &str = group_category
+                .or_else(|| urls::skill_category(name))
+                .unwrap_or("tools");
+            let list: &mut Vec<SkillEntry> =
+                skill_list(&mut skills, category).get_or_insert_with(Vec::new);
+            if !list.iter().any(|entry: &SkillEntry| entry.name() == name) {
+                list.push(SkillEntry::from(name.clone()));
+            }
+        }
+    }
+
+    skills
+}
+
+fn skill_list<'a>(skills: &'a mut Skills, category: &str) -> &'a mut Option<Vec<SkillEntry>> {
+    match category {
+        "languages" => &mut skills.languages,
+        "frameworks" => &mut skills.frameworks,
+        "databases" => &mut skills.databases,
+        "cloud" => &mut skills.cloud,
+        _ => &mut skills.tools,
+    }
+}
+
+fn format_location(location: &Location) -> Option<String> {
+    let parts: Vec<&str> = [&location.city, &location.region, &location.country_code]
+        .into_iter()
+        .filter_map(|part: &Option<String>| part.as_deref())
+        .filter(|part: &&str| !part.is_empty())
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
+fn social_slot<'a>(social: &'a mut Social, network: &str) -> Option<&'a mut Option<SocialEntry>> {
+    let key: &str = match network {
+        "x" => "twitter",
+        "dev.to" => "devto",
+        "stack overflow" => "stackoverflow",
+        "ko-fi" => "kofi",
+        "hugging face" => "huggingface",
+        // Basics carries these; a profile entry for them is not a network.
+        "website" | "email" | "rss" => return None,
+        other => other,
+    };
+    social.platform_mut(key)
+}
+
+/// Build a JSON Resume document from a profile.
+pub fn resume_from_profile(config: &ProfileConfig) -> JsonResume {
+    let about: About = config.about.clone().unwrap_or_default();
+    let social: Social = config.social.clone().unwrap_or_default();
+
+    let profiles: Vec<NetworkProfile> = sections::social_links(&social)
+        .iter()
+        .filter(|link: &&PlatformLink| !matches!(link.platform, "website" | "email" | "rss"))
+        .map(|link: &PlatformLink| NetworkProfile {
+            network: link.label.to_string(),
+            username: (link.platform == "github")
+                .then(|| config.meta.username.clone())
+                .or_else(|| url_username(&link.url)),
+            url: Some(link.url.clone()),
+        })
+        .collect();
+
+    let basics = Basics {
+        name: Some(
+            config
+                .meta
+                .name
+                .clone()
+                .unwrap_or_else(|| config.meta.username.clone()),
+        ),
+        label: about.role.clone(),
+        email: social
+            .email
+            .as_ref()
+            .map(|e: &SocialEntry| e.url().trim_start_matches("mailto:").to_string()),
+        url: social
+            .website
+            .as_ref()
+            .map(|w: &SocialEntry| w.url().to_string()),
+        summary: config.header.as_ref().and_then(|h| h.tagline.clone()),
+        location: about.location.as_deref().map(parse_location),
+        profiles,
+    };
+
+    let work: Vec<Work> = about
+        .company
+        .iter()
+        .map(|company: &String| Work {
+            name: Some(company.clone()),
+            position: about.role.clone(),
+            end_date: None,
+        })
+        .collect();
+
+    let skills: Vec<Skill> = config
+        .skills
+        .as_ref()
+        .map(|s: &Skills| {
+            let mut s: Skills = s.clone();
+            SKILL_CATEGORIES
+                .iter()
+                .filter_map(|(label, key)| {
+                    let keywords: Vec<String> = skill_list(&mut s, key)
+                        .take()?
+                        .iter()
+                        .map(|entry: &SkillEntry| entry.name().to_string())
+                        .collect();
+                    Some(Skill {
+                        name: label.to_string(),
+                        keywords,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let certificates: Vec<Certificate> = config
+        .extras
+        .as_ref()
+        .and_then(|e| e.certifications.as_ref())
+        .map(|certs: &Vec<String>| {
+            certs
+                .iter()
+                .map(|c: &String| parse_certificate(c))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let publications: Vec<Publication> = config
+        .blog
+        .as_ref()
+        .and_then(|b| b.articles.as_ref())
+        .map(|articles: &Vec<Article>| {
+            articles
+                .iter()
+                .map(|a: &Article| Publication {
+                    name: a.title.clone(),
+                    url: Some(a.url.clone()),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    JsonResume {
+        basics,
+        work,
+        skills,
+        certificates,
+        publications,
+    }
+}
+
+/// Serialize a profile as pretty-printed JSON Resume.
+pub fn export_json_resume(config: &ProfileConfig) -> Result<String> {
+    serde_json::to_string_pretty(&resume_from_profile(config))
+        .context("failed to serialize JSON Resume")
+}
+
+/// The last path segment of a profile URL, e.g. `alice` in `https://twitter.com/alice`.
+fn url_username(url: &str) -> Option<String> {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|segment: &&str| !segment.is_empty() && !segment.contains(':'))
+        .map(|segment: &str| segment.trim_start_matches('@').to_string())
+}
+
+/// Inverse of `format_location`: "City, Region" or a single free-form value.
+fn parse_location(location: &str) -> Location {
+    let mut parts = location.splitn(2, ", ");
+    Location {
+        city: parts.next().map(str::to_string),
+        region: parts.next().map(str::to_string),
+        country_code: None,
+    }
+}
+
+/// Inverse of the certification format written on import: "Name (Issuer)".
+fn parse_certificate(text: &str) -> Certificate {
+    match text
+        .strip_suffix(')')
+        .and_then(|rest: &str| rest.rsplit_once(" ("))
+    {
+        Some((name, issuer)) => Certificate {
+            name: name.to_string(),
+            issuer: Some(issuer.to_string()),
+        },
+        None => Certificate {
+            name: text.to_string(),
+            issuer: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESUME: &str = r#"{
+        "basics": {
+            "name": "Richard Hendricks",
+            "label": "Programmer",
+            "email": "richard@piedpiper.example",
+            "url": "https://richardhendricks.example",
+            "summary": "Compression algorithms and middle-out thinking.",
+            "location": { "city": "Palo Alto", "region": "California", "countryCode": "US" },
+            "profiles": [
+                { "network": "GitHub", "username": "rhendricks" },
+                { "network": "Twitter", "username": "richard", "url": "https://x.com/richard" },
+                { "network": "SoundCloud", "url": "https://soundcloud.example/richard" }
+            ]
+        },
+        "work": [
+            { "name": "Hooli", "position": "Engineer", "endDate": "2014-01-01" },
+            { "name": "Pied Piper", "position": "CEO" }
+        ],
+        "education": [{ "institution": "Stanford" }],
+        "skills": [
+            { "name": "Web Development", "level": "Master", "keywords": ["Rust", "React", "Docker", "Middle-out"] },
+            { "name": "Compression" }
+        ],
+        "certificates": [{ "name": "AWS Solutions Architect", "issuer": "Amazon" }],
+        "publications": [
+            { "name": "Middle-out compression", "url": "https://piedpiper.example/paper" },
+            { "name": "Unpublished notes" }
+        ]
+    }"#;
+
+    #[test]
+    fn test_profile_from_resume() {
+        let resume: JsonResume = serde_json::from_str(RESUME).unwrap();
+        let config: ProfileConfig = profile_from_resume(&resume);
+
+        assert_eq!(config.meta.username, "rhendricks");
+        assert_eq!(config.meta.name.as_deref(), Some("Richard Hendricks"));
+        let about: About = config.about.unwrap();
+        assert_eq!(about.role.as_deref(), Some("Programmer"));
+        assert_eq!(about.company.as_deref(), Some("Pied Piper"));
+        assert_eq!(about.location.as_deref(), Some("Palo Alto, California, US"));
+        let social: Social = config.social.unwrap();
+        assert_eq!(
+            social.github.as_ref().map(SocialEntry::url),
+            Some("https://github.com/rhendricks")
+        );
+        assert_eq!(
+            social.twitter.as_ref().map(SocialEntry::url),
+            Some("https://x.com/richard")
+        );
+        assert_eq!(
+            social.email.as_ref().map(SocialEntry::url),
+            Some("richard@piedpiper.example")
+        );
+        let custom: Vec<CustomSocial> = social.custom.unwrap();
+        assert_eq!(custom[0].label, "SoundCloud");
+        assert_eq!(custom[0].url, "https://soundcloud.example/richard");
+
+        let skills: Skills = config.skills.unwrap();
+        assert_eq!(skills.languages, Some(vec!["Rust".into()]));
+        assert_eq!(skills.frameworks, Some(vec!["React".into()]));
+        assert_eq!(
+            skills.tools,
+            Some(vec![
+                "Docker".into(),
+                "Middle-out".into(),
+                "Compression".into()
+            ])
+        );
+
+        assert_eq!(
+            config.extras.unwrap().certifications,
+            Some(vec!["AWS Solutions Architect (Amazon)".to_string()])
+        );
+        let articles: Vec<Article> = config.blog.unwrap().articles.unwrap();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].title, "Middle-out compression");
+    }
+
+    #[test]
+    fn test_export_then_import_round_trip() {
+        let resume: JsonResume = serde_json::from_str(RESUME).unwrap();
+        let config: ProfileConfig = profile_from_resume(&resume);
+
+        let exported: String = export_json_resume(&config).unwrap();
+        let reimported: JsonResume = serde_json::from_str(&exported).unwrap();
+        assert_eq!(profile_from_resume(&reimported), config);
+
+        assert_eq!(reimported.basics.profiles[0].network, "GitHub");
+        assert_eq!(
+            reimported.basics.profiles[1].username.as_deref(),
+            Some("richard")
+        );
+        assert_eq!(reimported.skills[0].name, "Languages");
+        assert_eq!(
+            reimported.certificates[0],
+            Certificate {
+                name: "AWS Solutions Architect".to_string(),
+                issuer: Some("Amazon".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_export_minimal_profile() {
+        let config = ProfileConfig {
+            meta: Meta {
+                username: "alice".to_string(),
+                ..Meta::default()
+            },
+            ..ProfileConfig::default()
+        };
+        let exported: String = export_json_resume(&config).unwrap();
+        assert_eq!(
+            exported,
+            "{\n  \"basics\": {\n    \"name\": \"alice\"\n  }\n}"
+        );
+    }
+}
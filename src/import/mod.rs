@@ -0,0 +1,3 @@
+pub mod github;
+pub mod json_resume;
+pub mod readme;
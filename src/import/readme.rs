@@ -0,0 +1,1124 @@
+use crate::config::profile::*;
+use crate::services::urls;
+
+/// Headings emitted by the renderer; they carry no configuration of their own.
+const SECTION_HEADINGS: &[&str] = &[
+    "connect with me",
+    "tech stack",
+    "github stats",
+    "featured projects",
+    "latest blog posts",
+    "support",
+    "spotify",
+    "wakatime",
+    "recent activity",
+    "stackoverflow",
+    "our mission",
+    "contributing",
+    "community",
+    "organization stats",
+    "members",
+];
+
+/// A profile recovered from a handwritten README.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedProfile {
+    pub config: ProfileConfig,
+    /// Blocks that could not be mapped; each is also kept in `extras.custom_blocks`.
+    pub unmapped: Vec<String>,
+    /// Follow-ups for the user, such as a username that could not be found.
+    pub notes: Vec<String>,
+}
+
+/// Parse a profile README into a `ProfileConfig`.
+///
+/// Recognizes the badges, cards and markers that `services::urls` and the
+/// section renderers produce. Anything else is kept verbatim in
+/// `extras.custom_blocks` and listed in `unmapped`.
+pub fn import_readme(markdown: &str) -> ImportedProfile {
+    let mut importer = Importer::default();
+    let markdown: String = strip_generated(markdown);
+
+    for block in split_blocks(&markdown) {
+        importer.block(&block);
+    }
+    importer.flush_heading();
+
+    let mut notes: Vec<String> = importer.notes;
+    if importer.config.meta.username.is_empty() {
+        notes.push("could not determine the GitHub username; set meta.username".to_string());
+    }
+    notes.dedup();
+
+    ImportedProfile {
+        config: importer.config,
+        unmapped: importer.unmapped,
+        notes,
+    }
+}
+
+#[derive(Default)]
+struct Importer {
+    config: ProfileConfig,
+    unmapped: Vec<String>,
+    notes: Vec<String>,
+    /// A heading whose content has not been seen yet.
+    pending_heading: Option<String>,
+    /// Lowercased text of the most recent heading.
+    section: String,
+    expect_tagline: bool,
+    blocks_seen: usize,
+}
+
+impl Importer {
+    fn block(&mut self, block: &str) {
+        let trimmed: &str = block.trim();
+        if trimmed.is_empty() || trimmed == "---" || trimmed == "***" {
+            return;
+        }
+        self.blocks_seen += 1;
+
+        let (first, rest): (&str, &str) = trimmed.split_once('\n').unwrap_or((trimmed, ""));
+        if first.starts_with('#') {
+            self.heading(first);
+            if !rest.trim().is_empty() {
+                self.content(rest.trim());
+            }
+            return;
+        }
+
+        self.content(trimmed);
+    }
+
+    fn heading(&mut self, line: &str) {
+        self.flush_heading();
+        self.expect_tagline = false;
+        let text: &str = line.trim_start_matches('#').trim();
+
+        if let Some(name) = greeting_name(text) {
+            let header: &mut Header = self.config.header.get_or_insert_with(Header::default);
+            header.style = Some(if line.starts_with("## ") {
+                HeaderStyle::Text
+            } else {
+                HeaderStyle::Wave
+            });
+            if name != self.config.meta.username {
+                self.config.meta.name = Some(name.to_string());
+            }
+            self.expect_tagline = true;
+            return;
+        }
+
+        self.section = text.to_lowercase();
+        self.pending_heading = Some(line.to_string());
+    }
+
+    /// Keep a heading that never got any recognized content below it.
+    fn flush_heading(&mut self) {
+        if let Some(heading) = self.pending_heading.take() {
+            let text: String = heading.trim_start_matches('#').trim().to_lowercase();
+            if !SECTION_HEADINGS.contains(&text.as_str()) {
+                self.keep(heading);
+            }
+        }
+    }
+
+    fn content(&mut self, text: &str) {
+        let expect_tagline: bool = std::mem::take(&mut self.expect_tagline);
+        if expect_tagline && is_plain_text(text) {
+            self.config
+                .header
+                .get_or_insert_with(Header::default)
+                .tagline = Some(text.to_string());
+            return;
+        }
+
+        let mut draft: ProfileConfig = self.config.clone();
+        let mut notes: Vec<String> = Vec::new();
+        let first_block: bool = self.blocks_seen == 1;
+        if apply_block(&mut draft, &mut notes, text, &self.section, first_block) {
+            self.config = draft;
+            self.notes.extend(notes);
+            self.pending_heading = None;
+        } else {
+            let block: String = match self.pending_heading.take() {
+                Some(heading) => format!("{}\n\n{}", heading, text),
+                None => text.to_string(),
+            };
+            self.keep(block);
+        }
+    }
+
+    fn keep(&mut self, block: String) {
+        self.unmapped.push(summarize(&block));
+        self.config
+            .extras
+            .get_or_insert_with(Extras::default)
+            .custom_blocks
+            .get_or_insert_with(Vec::new)
+            .push(block);
+    }
+}
+
+/// "Hey! I'm Alice", "Hi there, I'm Alice 👋" or "Welcome to Acme".
+fn greeting_name(text: &str) -> Option<&str> {
+    let name: &str = text
+        .strip_prefix("Hey! I'm ")
+        .or_else(|| text.strip_prefix("Hi there, I'm "))
+        .or_else(|| text.strip_prefix("Welcome to "))?;
+    Some(name.trim_end_matches('\u{1f44b}').trim())
+}
+
+fn is_plain_text(text: &str) -> bool {
+    !text.contains('<')
+        && !text.contains("](")
+        && !text.starts_with("- ")
+        && !text.starts_with('|')
+        && !text.starts_with('#')
+}
+
+/// First line of a block, shortened for the import report.
+fn summarize(block: &str) -> String {
+    let line: &str = block.lines().next().unwrap_or_default().trim();
+    if line.chars().count() > 60 {
+        format!("{}...", line.chars().take(57).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
+
+/// Map one block onto `draft`. Returns false if any part of it is unrecognized.
+fn apply_block(
+    draft: &mut ProfileConfig,
+    notes: &mut Vec<String>,
+    text: &str,
+    section: &str,
+    first_block: bool,
+) -> bool {
+    if text.starts_with("<details") {
+        return apply_details(draft, text);
+    }
+    let lines: Vec<&str> = text.lines().map(str::trim).collect();
+    if lines.iter().all(|l: &&str| l.starts_with('|')) {
+        return apply_project_table(draft, &lines);
+    }
+    if lines.iter().all(|l: &&str| l.starts_with("- ")) {
+        return apply_about(draft, &lines);
+    }
+    if lines.iter().all(|l: &&str| starts_numbered(l)) {
+        return apply_articles(draft, &lines);
+    }
+    if let Some(rest) = text.strip_prefix("**YouTube**: ") {
+        return apply_blog_link(
+            rest,
+            &mut draft.blog.get_or_insert_with(Blog::default).youtube,
+        );
+    }
+    if let Some(rest) = text.strip_prefix("**Newsletter**: ") {
+        return apply_blog_link(
+            rest,
+            &mut draft.blog.get_or_insert_with(Blog::default).newsletter,
+        );
+    }
+
+    let (tokens, leftover) = tokenize(text);
+    if !leftover.replace("&nbsp;", "").trim().is_empty() {
+        return false;
+    }
+
+    // A lone unrecognized image at the very top is treated as a banner.
+    if first_block
+        && draft.header.is_none()
+        && let [Token::Image { src, link: None }] = tokens.as_slice()
+        && !is_known_image(src)
+    {
+        draft.header = Some(Header {
+            style: Some(HeaderStyle::Banner),
+            banner_url: Some(src.clone()),
+            ..Header::default()
+        });
+        return true;
+    }
+
+    tokens
+        .iter()
+        .all(|token: &Token| apply_token(draft, notes, token, section))
+}
+
+fn apply_token(
+    draft: &mut ProfileConfig,
+    notes: &mut Vec<String>,
+    token: &Token,
+    section: &str,
+) -> bool {
+    match token {
+        Token::Comment(marker) => apply_marker(draft, notes, marker),
+        Token::Image { src, link, .. } => apply_image(draft, src, link.as_deref(), section),
+        Token::Link => false,
+    }
+}
+
+fn apply_marker(draft: &mut ProfileConfig, notes: &mut Vec<String>, marker: &str) -> bool {
+    match marker {
+        "BLOG-POST-LIST:START" => {
+            notes.push(
+                "found a blog post list marker; add your feed URLs to blog.rss_urls".to_string(),
+            );
+            true
+        }
+        "WAKATIME:START" | "START_SECTION:waka" => {
+            draft.dynamic.get_or_insert_with(Dynamic::default).wakatime = Some(true);
+            true
+        }
+        "START_SECTION:activity" => {
+            draft
+                .dynamic
+                .get_or_insert_with(Dynamic::default)
+                .github_activity = Some(true);
+            true
+        }
+        "BLOG-POST-LIST:END" | "WAKATIME:END" | "END_SECTION:waka" | "END_SECTION:activity" => true,
+        _ => false,
+    }
+}
+
+fn is_known_image(src: &str) -> bool {
+    let mut probe: ProfileConfig = ProfileConfig::default();
+    apply_image(&mut probe, src, None, "")
+}
+
+/// Recognize a card or badge image by its URL.
+fn apply_image(draft: &mut ProfileConfig, src: &str, link: Option<&str>, section: &str) -> bool {
+    let (path, params) = split_url(src);
+    let param = |key: &str| -> Option<String> {
+        params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    };
+
+    if let Some(rest) = path.strip_prefix("github-readme-stats.vercel.app/api") {
+        let Some(username) = param("username") else {
+            return false;
+        };
+        claim_username(draft, &username);
+        match rest.trim_matches('/') {
+            "" => {
+                let stats: &mut Stats = card_stats(draft, &param);
+                stats.stats_card = Some(true);
+            }
+            "top-langs" => {
+                let stats: &mut Stats = card_stats(draft, &param);
+                stats.top_langs = Some(true);
+                stats.top_langs_layout = param("layout");
+                stats.top_langs_count = param("langs_count").and_then(|c| c.parse().ok());
+            }
+            "pin" => {
+                let Some(repo) = param("repo") else {
+                    return false;
+                };
+                let projects: &mut Projects = draft.projects.get_or_insert_with(Projects::default);
+                projects.display = Some(ProjectDisplay::PinCards);
+                projects
+                    .featured
+                    .get_or_insert_with(Vec::new)
+                    .push(FeaturedProject {
+                        repo: format!("{}/{}", username, repo),
+                        description: None,
+                    });
+            }
+            _ => return false,
+        }
+        return true;
+    }
+
+    if path.starts_with("streak-stats.demolab.com")
+        || path.starts_with("github-readme-streak-stats.herokuapp.com")
+    {
+        let Some(user) = param("user") else {
+            return false;
+        };
+        claim_username(draft, &user);
+        card_stats(draft, &param).streak = Some(true);
+        return true;
+    }
+
+    if path.starts_with("github-profile-trophy.vercel.app") {
+        let Some(username) = param("username") else {
+            return false;
+        };
+        claim_username(draft, &username);
+        card_stats(draft, &param).trophies = Some(true);
+        return true;
+    }
+
+    if path.starts_with("github-contributor-stats.vercel.app") {
+        let Some(username) = param("username") else {
+            return false;
+        };
+        claim_username(draft, &username);
+        card_stats(draft, &param).contributor_stats = Some(true);
+        return true;
+    }
+
+    if path.starts_with("komarev.com/ghpvc") {
+        let Some(username) = param("username") else {
+            return false;
+        };
+        claim_username(draft, &username);
+        draft.stats.get_or_insert_with(Stats::default).profile_views = Some(true);
+        return true;
+    }
+
+    if path.starts_with("readme-typing-svg.demolab.com")
+        || path.starts_with("readme-typing-svg.herokuapp.com")
+    {
+        let header: &mut Header = draft.header.get_or_insert_with(Header::default);
+        header.style = Some(HeaderStyle::TypingSvg);
+        header.typing_lines = param("lines").map(|l: String| {
+            l.split(';')
+                .map(|line: &str| line.to_string())
+                .collect::<Vec<String>>()
+        });
+        header.typing_font = param("font");
+        header.typing_color = param("color");
+        return true;
+    }
+
+    if path.starts_with("spotify-github-profile.kittinan.vercel.app") {
+        let Some(uid) = param("uid") else {
+            return false;
+        };
+        draft
+            .dynamic
+            .get_or_insert_with(Dynamic::default)
+            .spotify_uid = Some(uid);
+        return true;
+    }
+
+    if let Some(file) = path.strip_prefix("stackoverflow.com/users/flair/") {
+        let uid: &str = file.trim_end_matches(".png");
+        draft
+            .dynamic
+            .get_or_insert_with(Dynamic::default)
+            .stackoverflow_uid = Some(uid.to_string());
+        return true;
+    }
+
+    if let Some(spec) = path.strip_prefix("img.shields.io/badge/") {
+        return apply_shields_badge(draft, spec, param("logo"), link, section);
+    }
+
+    false
+}
+
+/// Stats settings shared by all card URLs.
+fn card_stats<'a>(
+    draft: &'a mut ProfileConfig,
+    param: &dyn Fn(&str) -> Option<String>,
+) -> &'a mut Stats {
+    let stats: &mut Stats = draft.stats.get_or_insert_with(Stats::default);
+    if let Some(theme) = param("theme").filter(|t: &String| t != "default") {
+        stats.theme = Some(theme);
+    }
+    if param("hide_border").as_deref() == Some("true") {
+        stats.hide_border = Some(true);
+    }
+    stats
+}
+
+fn apply_shields_badge(
+    draft: &mut ProfileConfig,
+    spec: &str,
+    logo: Option<String>,
+    link: Option<&str>,
+    section: &str,
+) -> bool {
+    let parts: Vec<String> = split_badge_spec(spec);
+
+    if let [label, message, _color] = parts.as_slice() {
+        if label == "PGP" {
+            draft
+                .extras
+                .get_or_insert_with(Extras::default)
+                .pgp_fingerprint = Some(message.clone());
+            return true;
+        }
+        return false;
+    }
+    let [label, _color] = parts.as_slice() else {
+        return false;
+    };
+
+    let Some(href) = link else {
+        // An unlinked badge is a skill.
+        let category: &str = urls::skill_category(label).unwrap_or("tools");
+        let skills: &mut Skills = draft.skills.get_or_insert_with(Skills::default);
+        let list: &mut Option<Vec<SkillEntry>> = match category {
+            "languages" => &mut skills.languages,
+            "frameworks" => &mut skills.frameworks,
+            "databases" => &mut skills.databases,
+            "cloud" => &mut skills.cloud,
+            _ => &mut skills.tools,
+        };
+        list.get_or_insert_with(Vec::new)
+            .push(SkillEntry::from(label.clone()));
+        return true;
+    };
+
+    let sponsors_section: bool = section == "support";
+    match label.as_str() {
+        "Sponsor" if logo.as_deref() == Some("githubsponsors") => {
+            if let Some(account) = href.split("github.com/sponsors/").nth(1) {
+                claim_username(draft, account.trim_end_matches('/'));
+            }
+            draft
+                .sponsors
+                .get_or_insert_with(Sponsors::default)
+                .github_sponsors = Some(true);
+            return true;
+        }
+        "Buy Me A Coffee" => {
+            draft
+                .sponsors
+                .get_or_insert_with(Sponsors::default)
+                .buy_me_a_coffee = Some(href.to_string());
+            return true;
+        }
+        "Ko-fi" if sponsors_section => {
+            draft.sponsors.get_or_insert_with(Sponsors::default).kofi = Some(href.to_string());
+            return true;
+        }
+        _ => {}
+    }
+
+    let platform: Option<&str> = Social::default()
+        .platforms()
+        .into_iter()
+        .map(|(key, _)| key)
+        .find(|p: &&str| {
+            urls::social_platform_info(p)
+                .is_some_and(|(platform_label, _, _)| platform_label == label)
+        });
+    let Some(platform) = platform else {
+        return false;
+    };
+    if platform == "github"
+        && let Some(account) = href.split("github.com/").nth(1)
+    {
+        claim_username(draft, account.trim_end_matches('/'));
+    }
+    let social: &mut Social = draft.social.get_or_insert_with(Social::default);
+    if let Some(slot) = social.platform_mut(platform) {
+        *slot = Some(href.into());
+    }
+    true
+}
+
+fn claim_username(draft: &mut ProfileConfig, username: &str) {
+    if draft.meta.username.is_empty() && !username.is_empty() {
+        draft.meta.username = username.to_string();
+    }
+}
+
+/// `<details><summary>...</summary> ... </details>` → a collapsible section.
+fn apply_details(draft: &mut ProfileConfig, text: &str) -> bool {
+    let Some(summary) = between(text, "<summary>", "</summary>") else {
+        return false;
+    };
+    let Some(body) = text
+        .split_once("</summary>")
+        .and_then(|(_, rest)| rest.rsplit_once("</details>"))
+        .map(|(body, _)| body.trim())
+    else {
+        return false;
+    };
+
+    draft
+        .extras
+        .get_or_insert_with(Extras::default)
+        .collapsible
+        .get_or_insert_with(Vec::new)
+        .push(CollapsibleSection {
+            summary: summary.trim().to_string(),
+            content: body.to_string(),
+        });
+    true
+}
+
+/// `| [repo](https://github.com/owner/repo) | description |` rows.
+fn apply_project_table(draft: &mut ProfileConfig, lines: &[&str]) -> bool {
+    let mut featured: Vec<FeaturedProject> = Vec::new();
+
+    for line in lines {
+        let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+        let first: &str = cells.first().copied().unwrap_or_default();
+        if first == "Project" || first.chars().all(|c: char| c == '-' || c == ':') {
+            continue;
+        }
+        let Some((_, href)) = parse_link(first).map(|(link, _)| link) else {
+            return false;
+        };
+        let Some(repo) = href.strip_prefix("https://github.com/") else {
+            return false;
+        };
+        let description: Option<String> = cells
+            .get(1)
+            .filter(|d: &&&str| !d.is_empty())
+            .map(|d: &&str| d.to_string());
+        featured.push(FeaturedProject {
+            repo: repo.trim_end_matches('/').to_string(),
+            description,
+        });
+    }
+
+    if featured.is_empty() {
+        return false;
+    }
+    let projects: &mut Projects = draft.projects.get_or_insert_with(Projects::default);
+    projects.display = Some(ProjectDisplay::MarkdownTable);
+    projects
+        .featured
+        .get_or_insert_with(Vec::new)
+        .extend(featured);
+    true
+}
+
+/// The About Me list written by `render_about`.
+fn apply_about(draft: &mut ProfileConfig, lines: &[&str]) -> bool {
+    let mut about: About = draft.about.clone().unwrap_or_default();
+
+    for line in lines {
+        let item: &str = line.trim_start_matches("- ").trim();
+        if let Some(rest) = item.strip_prefix("\u{1f4bc} ") {
+            match rest.split_once(" at **") {
+                Some((role, company)) => {
+                    about.role = Some(role.to_string());
+                    about.company = Some(company.trim_end_matches("**").to_string());
+                }
+                None => about.role = Some(rest.to_string()),
+            }
+        } else if let Some(rest) = item.strip_prefix("\u{1f52d} Currently working on ") {
+            about.current_work = Some(unbold(rest));
+        } else if let Some(rest) = item.strip_prefix("\u{1f331} Learning ") {
+            about.learning = Some(unbold(rest));
+        } else if let Some(rest) = item.strip_prefix("\u{1f4ac} Ask me about ") {
+            about.reach_me = Some(unbold(rest));
+        } else if let Some(rest) = item.strip_prefix("\u{26a1} Fun fact: ") {
+            about.fun_fact = Some(rest.to_string());
+        } else if let Some(rest) = item.strip_prefix("\u{1f600} Pronouns: ") {
+            about.pronouns = Some(rest.to_string());
+        } else if let Some(rest) = item.strip_prefix("\u{1f4cd} ") {
+            about.location = Some(rest.to_string());
+        } else {
+            return false;
+        }
+    }
+
+    draft.about = Some(about);
+    true
+}
+
+fn unbold(text: &str) -> String {
+    text.trim_start_matches("**")
+        .trim_end_matches("**")
+        .to_string()
+}
+
+fn starts_numbered(line: &str) -> bool {
+    line.split_once(". ")
+        .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c: char| c.is_ascii_digit()))
+}
+
+/// `1. [Title](url)` lines written by `render_blog`.
+fn apply_articles(draft: &mut ProfileConfig, lines: &[&str]) -> bool {
+    let mut articles: Vec<Article> = Vec::new();
+    for line in lines {
+        let item: &str = line.split_once(". ").map(|(_, rest)| rest).unwrap_or(line);
+        match parse_link(item) {
+            Some(((title, url), "")) => articles.push(Article {
+                title: title.to_string(),
+                url: url.to_string(),
+            }),
+            _ => return false,
+        }
+    }
+    draft
+        .blog
+        .get_or_insert_with(Blog::default)
+        .articles
+        .get_or_insert_with(Vec::new)
+        .extend(articles);
+    true
+}
+
+fn apply_blog_link(text: &str, slot: &mut Option<String>) -> bool {
+    match parse_link(text.trim()) {
+        Some(((_, url), "")) => {
+            *slot = Some(url.to_string());
+            true
+        }
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Image {
+        src: String,
+        link: Option<String>,
+    },
+    /// A plain text link; never mapped on its own.
+    Link,
+    Comment(String),
+}
+
+/// Split a block into images, links and comments, plus any text left over.
+///
+/// Layout tags such as `<p>`, `<div>` and `<br />` are dropped; `<a>` and
+/// `<img>` become tokens.
+fn tokenize(block: &str) -> (Vec<Token>, String) {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut leftover = String::new();
+    let mut link: Option<String> = None;
+    let mut rest: &str = block;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end: usize = after.find("-->").unwrap_or(after.len());
+            tokens.push(Token::Comment(after[..end].trim().to_string()));
+            rest = after.get(end + 3..).unwrap_or_default();
+            continue;
+        }
+        if let Some(((src, href), remaining)) = parse_linked_image(rest) {
+            tokens.push(Token::Image {
+                src: src.to_string(),
+                link: Some(href.to_string()),
+            });
+            rest = remaining;
+            continue;
+        }
+        if let Some(((_, src), remaining)) = parse_image(rest) {
+            tokens.push(Token::Image {
+                src: src.to_string(),
+                link: link.clone(),
+            });
+            rest = remaining;
+            continue;
+        }
+        if let Some((_, remaining)) = parse_link(rest) {
+            tokens.push(Token::Link);
+            rest = remaining;
+            continue;
+        }
+        if c == '<'
+            && let Some(end) = rest.find('>')
+        {
+            let tag: &str = &rest[1..end];
+            let name: String = tag
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches('/')
+                .to_lowercase();
+            if name
+                .chars()
+                .all(|c: char| c.is_ascii_alphanumeric() || c == '/')
+                && !name.is_empty()
+            {
+                match name.as_str() {
+                    "a" => link = attribute(tag, "href"),
+                    "/a" => link = None,
+                    "img" => tokens.push(Token::Image {
+                        src: attribute(tag, "src").unwrap_or_default(),
+                        link: link.clone(),
+                    }),
+                    _ => {}
+                }
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+
+        leftover.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    (tokens, leftover)
+}
+
+/// `[![alt](src)](href)` at the start of `text`, returning `(src, href)`.
+fn parse_linked_image(text: &str) -> Option<((&str, &str), &str)> {
+    let after: &str = text.strip_prefix('[')?;
+    let ((_, src), rest) = parse_image(after)?;
+    let (href, rest) = rest.strip_prefix("](")?.split_once(')')?;
+    Some(((src, href), rest))
+}
+
+/// `![alt](src)` at the start of `text`.
+fn parse_image(text: &str) -> Option<((&str, &str), &str)> {
+    let after: &str = text.strip_prefix('!')?;
+    parse_link(after)
+}
+
+/// `[text](href)` at the start of `text`, returning the rest.
+fn parse_link(text: &str) -> Option<((&str, &str), &str)> {
+    let after: &str = text.strip_prefix('[')?;
+    let (label, rest) = after.split_once("](")?;
+    if label.contains('[') || label.contains('\n') {
+        return None;
+    }
+    let (href, rest) = rest.split_once(')')?;
+    if href.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(((label, href), rest))
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    for quote in ['"', '\''] {
+        let needle: String = format!(" {}={}", name, quote);
+        if let Some(start) = tag.find(&needle) {
+            let value: &str = &tag[start + needle.len()..];
+            return value.split(quote).next().map(str::to_string);
+        }
+    }
+    None
+}
+
+fn between<'a>(text: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let start: usize = text.find(open)? + open.len();
+    let end: usize = text[start..].find(close)? + start;
+    Some(&text[start..end])
+}
+
+/// Split on blank lines, keeping `<details>` elements and code fences whole.
+fn split_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut details_depth: usize = 0;
+    let mut in_fence: bool = false;
+
+    for line in markdown.lines() {
+        let trimmed: &str = line.trim();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        }
+        details_depth += trimmed.matches("<details").count();
+        details_depth = details_depth.saturating_sub(trimmed.matches("</details>").count());
+
+        if trimmed.is_empty() && !in_fence && details_depth == 0 {
+            if !current.is_empty() {
+                blocks.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current.join("\n"));
+    }
+    blocks
+}
+
+/// Drop content that GitHub Actions inserted between START/END markers.
+fn strip_generated(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    let mut rest: &str = markdown;
+
+    while let Some(start) = rest.find("<!--") {
+        let Some(close) = rest[start + 4..].find("-->") else {
+            break;
+        };
+        let close: usize = start + 4 + close;
+        let comment_end: usize = close + 3;
+        let marker: &str = rest[start + 4..close].trim();
+        out.push_str(&rest[..comment_end]);
+        rest = &rest[comment_end..];
+
+        let end_marker: Option<String> = if let Some(name) = marker.strip_suffix(":START") {
+            Some(format!("{}:END", name))
+        } else {
+            marker
+                .strip_prefix("START_SECTION:")
+                .map(|name: &str| format!("END_SECTION:{}", name))
+        };
+        if let Some(end_marker) = end_marker
+            && let Some(found) = find_comment(rest, &end_marker)
+        {
+            out.push('\n');
+            rest = &rest[found..];
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Byte offset of the comment whose trimmed text is `marker`.
+fn find_comment(text: &str, marker: &str) -> Option<usize> {
+    let mut offset: usize = 0;
+    while let Some(start) = text[offset..].find("<!--") {
+        let start: usize = offset + start;
+        let close: usize = text[start + 4..].find("-->")? + start + 4;
+        if text[start + 4..close].trim() == marker {
+            return Some(start);
+        }
+        offset = close + 3;
+    }
+    None
+}
+
+/// Split a URL into `host/path` (without scheme) and decoded query parameters.
+fn split_url(url: &str) -> (String, Vec<(String, String)>) {
+    let without_scheme: &str = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let (path, query) = without_scheme
+        .split_once('?')
+        .unwrap_or((without_scheme, ""));
+    let params: Vec<(String, String)> = query
+        .split('&')
+        .filter(|pair: &&str| !pair.is_empty())
+        .map(|pair: &str| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect();
+    (path.to_string(), params)
+}
+
+/// Split a shields.io static badge path into its dash-separated parts.
+///
+/// `--` is a literal dash and `_` a space, as written by `shields_badge_url`.
+fn split_badge_spec(spec: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut chars = spec.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '-' if chars.peek() == Some(&'-') => {
+                chars.next();
+                current.push('-');
+            }
+            '-' => parts.push(std::mem::take(&mut current)),
+            '_' if chars.peek() == Some(&'_') => {
+                chars.next();
+                current.push('_');
+            }
+            '_' => current.push(' '),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+        .iter()
+        .map(|part: &String| percent_decode(part))
+        .collect()
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes: &[u8] = input.as_bytes();
+    let mut out: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i: usize = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = input.get(i + 1..i + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            out.push(byte);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::markdown;
+
+    #[test]
+    fn test_import_rendered_profile_round_trip() {
+        let original = ProfileConfig {
+            meta: Meta {
+                username: "alice".to_string(),
+                name: Some("Alice".to_string()),
+                ..Meta::default()
+            },
+            header: Some(Header {
+                style: Some(HeaderStyle::Text),
+                tagline: Some("Rust developer".to_string()),
+                ..Header::default()
+            }),
+            about: Some(About {
+                role: Some("Backend Engineer".to_string()),
+                company: Some("Acme".to_string()),
+                learning: Some("Zig".to_string()),
+                ..About::default()
+            }),
+            social: Some(Social {
+                github: Some("https://github.com/alice".into()),
+                twitter: Some("https://x.com/alice".into()),
+                ..Social::default()
+            }),
+            skills: Some(Skills {
+                languages: Some(vec!["Rust".into(), "C++".into()]),
+                databases: Some(vec!["PostgreSQL".into()]),
+                ..Skills::default()
+            }),
+            stats: Some(Stats {
+                stats_card: Some(true),
+                streak: Some(true),
+                theme: Some("tokyonight".to_string()),
+                ..Stats::default()
+            }),
+            projects: Some(Projects {
+                featured: Some(vec![FeaturedProject {
+                    repo: "alice/cool-cli".to_string(),
+                    description: None,
+                }]),
+                display: Some(ProjectDisplay::PinCards),
+            }),
+            sponsors: Some(Sponsors {
+                github_sponsors: Some(true),
+                kofi: Some("https://ko-fi.com/alice".to_string()),
+                buy_me_a_coffee: None,
+            }),
+            ..ProfileConfig::default()
+        };
+
+        let imported: ImportedProfile = import_readme(&markdown::render(&original));
+        assert!(imported.unmapped.is_empty(), "{:?}", imported.unmapped);
+        assert!(imported.notes.is_empty(), "{:?}", imported.notes);
+        assert_eq!(imported.config, original);
+    }
+
+    #[test]
+    fn test_import_typing_header_and_dynamic_markers() {
+        let readme = r#"<p align="center">
+  <a href="https://readme-typing-svg.demolab.com/">
+    <img src="https://readme-typing-svg.demolab.com/?lines=Hello;Rust%20fan&font=Fira%20Code&color=f75c7e&center=true" />
+  </a>
+</p>
+
+### WakaTime
+
+<!-- WAKATIME:START -->
+generated stats that change every day
+<!-- WAKATIME:END -->
+
+<!--START_SECTION:activity-->
+1. pushed to alice/repo
+<!--END_SECTION:activity-->
+"#;
+        let imported: ImportedProfile = import_readme(readme);
+        let header: Header = imported.config.header.unwrap();
+        assert_eq!(header.style, Some(HeaderStyle::TypingSvg));
+        assert_eq!(
+            header.typing_lines,
+            Some(vec!["Hello".to_string(), "Rust fan".to_string()])
+        );
+        assert_eq!(header.typing_font.as_deref(), Some("Fira Code"));
+        let dynamic: Dynamic = imported.config.dynamic.unwrap();
+        assert_eq!(dynamic.wakatime, Some(true));
+        assert_eq!(dynamic.github_activity, Some(true));
+        assert!(imported.unmapped.is_empty());
+    }
+
+    #[test]
+    fn test_import_keeps_unrecognized_blocks() {
+        let readme = "## About this page\n\nI write about compilers.\n\n![stats](https://github-readme-stats.vercel.app/api?username=bob&theme=dark)\n\n![cat](https://example.com/cat.gif) and some text\n";
+        let imported: ImportedProfile = import_readme(readme);
+
+        assert_eq!(imported.config.meta.username, "bob");
+        assert_eq!(
+            imported.config.stats.unwrap().theme.as_deref(),
+            Some("dark")
+        );
+        assert_eq!(
+            imported.config.extras.unwrap().custom_blocks.unwrap(),
+            vec![
+                "## About this page\n\nI write about compilers.".to_string(),
+                "![cat](https://example.com/cat.gif) and some text".to_string(),
+            ]
+        );
+        assert_eq!(imported.unmapped.len(), 2);
+        assert_eq!(imported.unmapped[0], "## About this page");
+    }
+
+    #[test]
+    fn test_import_reports_missing_username() {
+        let imported: ImportedProfile = import_readme("# Hi there, I'm Dana \u{1f44b}\n");
+        assert_eq!(imported.config.meta.name.as_deref(), Some("Dana"));
+        assert_eq!(
+            imported.config.header.unwrap().style,
+            Some(HeaderStyle::Wave)
+        );
+        assert!(imported.notes[0].contains("meta.username"));
+    }
+
+    #[test]
+    fn test_import_project_table_and_collapsible() {
+        let readme = "### Featured Projects\n\n| Project | Description |\n|---|---|\n| [tool](https://github.com/carol/tool) | A tool |\n\n<details>\n<summary>More</summary>\n\nHidden text\n\n</details>\n";
+        let imported: ImportedProfile = import_readme(readme);
+        let projects: Projects = imported.config.projects.unwrap();
+        assert_eq!(projects.display, Some(ProjectDisplay::MarkdownTable));
+        assert_eq!(
+            projects.featured.unwrap(),
+            vec![FeaturedProject {
+                repo: "carol/tool".to_string(),
+                description: Some("A tool".to_string()),
+            }]
+        );
+        let collapsible: Vec<CollapsibleSection> =
+            imported.config.extras.unwrap().collapsible.unwrap();
+        assert_eq!(collapsible[0].summary, "More");
+        assert_eq!(collapsible[0].content, "Hidden text");
+    }
+
+    #[test]
+    fn test_strip_generated_short_comments() {
+        assert_eq!(strip_generated("a <!--> b"), "a <!--> b");
+        assert_eq!(strip_generated("a <!---> b"), "a <!---> b");
+        assert_eq!(
+            strip_generated("<!-->x-->\n<!-- A:START -->\nold\n<!-- A:END -->"),
+            "<!-->x-->\n<!-- A:START -->\n<!-- A:END -->"
+        );
+        assert_eq!(find_comment("<!-->", "B"), None);
+        assert_eq!(find_comment("<!--->", "B"), None);
+    }
+
+    #[test]
+    fn test_split_badge_spec() {
+        assert_eq!(
+            split_badge_spec("Buy_Me_A_Coffee-FFDD00"),
+            vec!["Buy Me A Coffee", "FFDD00"]
+        );
+        assert_eq!(split_badge_spec("Ko--fi-FF5E5B"), vec!["Ko-fi", "FF5E5B"]);
+        assert_eq!(
+            split_badge_spec("PGP-ABCD%201234-333333"),
+            vec!["PGP", "ABCD 1234", "333333"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_leftover_text() {
+        let (tokens, leftover) =
+            tokenize("<p align=\"center\">[![X](https://a/b.svg)](https://c) hi</p>");
+        assert_eq!(
+            tokens,
+            vec![Token::Image {
+                src: "https://a/b.svg".to_string(),
+                link: Some("https://c".to_string()),
+            }]
+        );
+        assert_eq!(leftover.trim(), "hi");
+    }
+}
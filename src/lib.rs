@@ -1,5 +1,6 @@
 pub mod config;
 pub mod error;
+pub mod import;
 pub mod render;
 pub mod services;
 pub mod ui;
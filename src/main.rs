@@ -1,11 +1,24 @@
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 
+use gh_profile_gen::config::edit;
+use gh_profile_gen::config::format::ConfigFormat;
+use gh_profile_gen::config::presets;
+use gh_profile_gen::config::profile::ProfileConfig;
+use gh_profile_gen::config::team as config_team;
 use gh_profile_gen::config::toml_io;
 use gh_profile_gen::error::ConfigError;
+use gh_profile_gen::import::{self, readme::ImportedProfile};
+use gh_profile_gen::render::batch::{self, BatchOutcome, BatchStatus};
 use gh_profile_gen::render::markdown;
+use gh_profile_gen::render::team as render_team;
+use gh_profile_gen::render::warnings::ConfigWarnings;
+use gh_profile_gen::services::assets::{self, LocalAssets};
+use gh_profile_gen::services::providers::{Providers, Registry};
+use gh_profile_gen::services::{skill_icons, urls};
 
 #[derive(Parser)]
 #[command(name = "gh-profile-gen", version = "0.1.0")]
@@ -13,6 +26,10 @@ use gh_profile_gen::render::markdown;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Config file format: toml, yaml or json (default: from the file extension)
+    #[arg(long, global = true)]
+    format: Option<ConfigFormat>,
 }
 
 #[derive(Subcommand)]
@@ -26,12 +43,147 @@ enum Commands {
         /// Overwrite existing file
         #[arg(long)]
         force: bool,
+
+        /// Prefill from a saved GitHub `/users/{user}` API response
+        #[arg(long, value_name = "FILE")]
+        from_github_json: Option<PathBuf>,
+
+        /// Saved `/users/{user}/repos` response; the most-starred repos become featured projects
+        #[arg(long, value_name = "FILE", requires = "from_github_json")]
+        repos: Option<PathBuf>,
+
+        /// Prefill from a JSON Resume (resume.json)
+        #[arg(long, value_name = "FILE", conflicts_with = "from_github_json")]
+        from_json_resume: Option<PathBuf>,
+
+        /// Start from a curated preset instead of the commented template
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["from_github_json", "from_json_resume"])]
+        preset: Option<String>,
+
+        /// List the available presets and what they contain
+        #[arg(long, exclusive = true)]
+        list_presets: bool,
+    },
+    /// Create a profile.toml from an existing profile README
+    Import {
+        /// Path to the README.md to import
+        readme: PathBuf,
+
+        /// Output path (default: profile.toml)
+        #[arg(short, long, default_value = "profile.toml")]
+        output: PathBuf,
+
+        /// Overwrite existing file
+        #[arg(long)]
+        force: bool,
     },
     /// Render a profile.toml into README.md
     Render {
-        /// Path to the TOML configuration file
+        /// Path to the configuration file (TOML, YAML or JSON)
+        #[arg(required_unless_present = "batch")]
+        file: Option<PathBuf>,
+
+        /// Output path (default: README.md, or .github/profile/README.md for organizations)
+        #[arg(short, long, conflicts_with = "batch")]
+        output: Option<PathBuf>,
+
+        /// Print to stdout instead of writing to file
+        #[arg(long, conflicts_with = "batch")]
+        stdout: bool,
+
+        /// Render every TOML, YAML or JSON config under this directory
+        #[arg(long, value_name = "DIR", conflicts_with = "file")]
+        batch: Option<PathBuf>,
+
+        /// Output directory for --batch; each profile goes to <DIR>/<username>/README.md
+        #[arg(long, value_name = "DIR", default_value = "out", requires = "batch")]
+        out_dir: PathBuf,
+
+        /// Write badges and the typing header as SVG files here and link them
+        /// by relative path instead of loading them from third-party services
+        #[arg(long, value_name = "DIR", conflicts_with = "batch")]
+        assets_dir: Option<PathBuf>,
+
+        /// simple-icons SVGs to draw local badge logos from, e.g.
+        /// node_modules/simple-icons/icons
+        #[arg(long, value_name = "DIR", requires = "assets_dir")]
+        logos_dir: Option<PathBuf>,
+    },
+    /// Preview the rendered README in the terminal
+    Preview {
+        /// Path to the configuration file (TOML, YAML or JSON)
+        file: PathBuf,
+    },
+    /// Export a profile.toml to another format
+    Export {
+        /// Path to the configuration file (TOML, YAML or JSON)
         file: PathBuf,
 
+        /// Export as JSON Resume (https://jsonresume.org)
+        #[arg(long, required = true)]
+        json_resume: bool,
+
+        /// Output path (default: resume.json)
+        #[arg(short, long, default_value = "resume.json")]
+        output: PathBuf,
+
+        /// Print to stdout instead of writing to file
+        #[arg(long)]
+        stdout: bool,
+    },
+    /// Set a config field, e.g. `set about.role "Staff Engineer"`
+    Set {
+        /// Dotted field path, e.g. `about.role` or `projects.featured[0].description`
+        path: String,
+
+        /// New value; numbers, booleans and arrays are written as TOML literals
+        value: String,
+
+        /// Path to the configuration file (TOML, YAML or JSON)
+        #[arg(short, long, default_value = "profile.toml")]
+        file: PathBuf,
+    },
+    /// Add a skill, featured project or article
+    Add {
+        #[command(subcommand)]
+        item: AddItem,
+
+        /// Path to the configuration file (TOML, YAML or JSON)
+        #[arg(short, long, default_value = "profile.toml", global = true)]
+        file: PathBuf,
+    },
+    /// Remove a config field, or one entry from a list
+    Remove {
+        /// Dotted field path, e.g. `social.twitter` or `skills.languages`
+        path: String,
+
+        /// Remove only the list entry matching this value
+        value: Option<String>,
+
+        /// Path to the configuration file (TOML, YAML or JSON)
+        #[arg(short, long, default_value = "profile.toml")]
+        file: PathBuf,
+    },
+    /// Check a profile for problems such as misspelled or unrecognized skills
+    Validate {
+        /// Path to the configuration file (TOML, YAML or JSON)
+        #[arg(short, long, default_value = "profile.toml")]
+        file: PathBuf,
+
+        /// Rewrite skills to their catalog spelling, including clear typos
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Upgrade a profile.toml to the current schema version, keeping comments
+    Migrate {
+        /// Path to the configuration file (TOML, YAML or JSON)
+        file: PathBuf,
+    },
+    /// Render a team directory page from a manifest of member profiles
+    Team {
+        /// Path to the team manifest (TOML)
+        manifest: PathBuf,
+
         /// Output path (default: README.md)
         #[arg(short, long, default_value = "README.md")]
         output: PathBuf,
@@ -40,36 +192,156 @@ enum Commands {
         #[arg(long)]
         stdout: bool,
     },
-    /// Preview the rendered README in the terminal
-    Preview {
-        /// Path to the TOML configuration file
+    /// Inspect a profile configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum AddItem {
+    /// Add a skill, e.g. `add skill Rust --category languages`
+    Skill {
+        name: String,
+
+        /// One of languages, frameworks, tools, databases, cloud (guessed for well-known skills)
+        #[arg(long)]
+        category: Option<String>,
+    },
+    /// Add a featured project
+    Project {
+        /// Repository as owner/name
+        repo: String,
+
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Add a blog article
+    Article {
+        #[arg(long)]
+        title: String,
+
+        #[arg(long)]
+        url: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print a profile configuration
+    Show {
+        /// Path to the configuration file (TOML, YAML or JSON)
         file: PathBuf,
+
+        /// Print the effective config with all `extends` bases merged in
+        #[arg(long)]
+        resolved: bool,
     },
 }
 
 fn main() -> Result<()> {
     let cli: Cli = Cli::parse();
+    let format: Option<ConfigFormat> = cli.format;
 
     match cli.command {
-        Some(Commands::Init { output, force }) => cmd_init(&output, force),
+        Some(Commands::Init {
+            output,
+            force,
+            from_github_json,
+            repos,
+            from_json_resume,
+            preset,
+            list_presets,
+        }) => match (from_json_resume, preset) {
+            _ if list_presets => cmd_list_presets(),
+            (Some(resume), _) => cmd_init_from_json_resume(&output, force, &resume, format),
+            (None, Some(preset)) => cmd_init_from_preset(&output, force, &preset, format),
+            (None, None) => cmd_init(
+                &output,
+                force,
+                from_github_json.as_deref(),
+                repos.as_deref(),
+                format,
+            ),
+        },
+        Some(Commands::Import {
+            readme,
+            output,
+            force,
+        }) => cmd_import(&readme, &output, force, format),
         Some(Commands::Render {
+            batch: Some(dir),
+            out_dir,
+            ..
+        }) => cmd_render_batch(&dir, &out_dir),
+        Some(Commands::Render {
+            file: Some(file),
+            output,
+            stdout,
+            assets_dir,
+            logos_dir,
+            ..
+        }) => cmd_render(
+            &file,
+            output.as_deref(),
+            stdout,
+            assets_dir.as_deref(),
+            logos_dir.as_deref(),
+            format,
+        ),
+        Some(Commands::Render { file: None, .. }) => {
+            unreachable!("clap requires a file unless --batch is given")
+        }
+        Some(Commands::Preview { file }) => cmd_preview(&file, format),
+        Some(Commands::Export {
             file,
+            json_resume: _,
             output,
             stdout,
-        }) => cmd_render(&file, &output, stdout),
-        Some(Commands::Preview { file }) => cmd_preview(&file),
+        }) => cmd_export_json_resume(&file, &output, stdout, format),
+        Some(Commands::Set { path, value, file }) => cmd_set(&file, &path, &value, format),
+        Some(Commands::Add { item, file }) => cmd_add(&file, item, format),
+        Some(Commands::Remove { path, value, file }) => {
+            cmd_remove(&file, &path, value.as_deref(), format)
+        }
+        Some(Commands::Validate { file, fix }) => cmd_validate(&file, fix, format),
+        Some(Commands::Migrate { file }) => cmd_migrate(&file, format),
+        Some(Commands::Team {
+            manifest,
+            output,
+            stdout,
+        }) => cmd_team(&manifest, &output, stdout),
+        Some(Commands::Config {
+            action: ConfigAction::Show { file, resolved },
+        }) => cmd_config_show(&file, resolved, format),
         None => {
             println!("gh-profile-gen v0.1.0");
             println!("Use --help for usage information, or run a subcommand:");
-            println!("  init     Generate a starter profile.toml");
+            println!("  init     Generate a starter profile.toml (or .yaml/.json)");
+            println!("  import   Create a profile.toml from an existing README.md");
             println!("  render   Render profile.toml to README.md");
             println!("  preview  Preview rendered README in terminal");
+            println!("  export   Export profile.toml as JSON Resume");
+            println!("  set      Set a field, e.g. `set about.role \"Staff Engineer\"`");
+            println!("  add      Add a skill, project or article");
+            println!("  remove   Remove a field or list entry");
+            println!("  validate Check profile.toml for unrecognized skills (--fix to correct)");
+            println!("  migrate  Upgrade profile.toml to the current schema");
+            println!("  team     Render a team directory page from many profiles");
+            println!("  config   Inspect a profile.toml (e.g. `config show --resolved`)");
             Ok(())
         }
     }
 }
 
-fn cmd_init(output: &Path, force: bool) -> Result<()> {
+fn cmd_init(
+    output: &Path,
+    force: bool,
+    github_user: Option<&Path>,
+    github_repos: Option<&Path>,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
     if output.exists() && !force {
         anyhow::bail!(
             "{} already exists. Use --force to overwrite.",
@@ -77,25 +349,222 @@ fn cmd_init(output: &Path, force: bool) -> Result<()> {
         );
     }
 
-    let starter: String = toml_io::generate_starter_toml();
+    let format: ConfigFormat = ConfigFormat::resolve(output, format);
+    if let Some(user) = github_user {
+        let config: ProfileConfig = import::github::load_github_json(user, github_repos)?;
+        toml_io::write_config(&config, output, format)?;
+        println!("Created {} from {}", output.display(), user.display());
+        return Ok(());
+    }
+
+    let starter: String = toml_io::generate_starter(format)?;
     std::fs::write(output, starter)
         .with_context(|| format!("could not write {}", output.display()))?;
     println!("Created {}", output.display());
     Ok(())
 }
 
-fn cmd_render(file: &Path, output: &Path, stdout: bool) -> Result<()> {
-    let config = toml_io::load_config(file)?;
+fn cmd_init_from_preset(
+    output: &Path,
+    force: bool,
+    name: &str,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    let preset =
+        presets::find_preset(name).ok_or_else(|| ConfigError::UnknownPreset(name.to_string()))?;
+    if output.exists() && !force {
+        anyhow::bail!(
+            "{} already exists. Use --force to overwrite.",
+            output.display()
+        );
+    }
+
+    toml_io::write_config(
+        &preset.config(),
+        output,
+        ConfigFormat::resolve(output, format),
+    )?;
+    println!(
+        "Created {} from the {} preset",
+        output.display(),
+        preset.name
+    );
+    Ok(())
+}
+
+fn cmd_list_presets() -> Result<()> {
+    let width: usize = presets::PRESETS
+        .iter()
+        .map(|p: &presets::Preset| p.name.len())
+        .max()
+        .unwrap_or(0);
+    for preset in presets::PRESETS {
+        println!(
+            "{:<width$}  {}",
+            preset.name,
+            preset.description,
+            width = width
+        );
+        println!("{:<width$}  {}", "", preset.summary(), width = width);
+    }
+    Ok(())
+}
+
+fn cmd_init_from_json_resume(
+    output: &Path,
+    force: bool,
+    resume: &Path,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    if output.exists() && !force {
+        anyhow::bail!(
+            "{} already exists. Use --force to overwrite.",
+            output.display()
+        );
+    }
+
+    let config: ProfileConfig = import::json_resume::load_json_resume(resume)?;
+    toml_io::write_config(&config, output, ConfigFormat::resolve(output, format))?;
+    println!("Created {} from {}", output.display(), resume.display());
+    if config.meta.username.is_empty() {
+        eprintln!("note: resume has no GitHub profile; set meta.username");
+    }
+    Ok(())
+}
+
+fn cmd_export_json_resume(
+    file: &Path,
+    output: &Path,
+    stdout: bool,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    let config = load_with_warnings(file, format)?;
+    let resume: String = import::json_resume::export_json_resume(&config)?;
+
+    if stdout {
+        println!("{}", resume);
+    } else {
+        std::fs::write(output, format!("{}\n", resume))
+            .with_context(|| format!("could not write {}", output.display()))?;
+        println!("Wrote {}", output.display());
+    }
+    Ok(())
+}
+
+fn cmd_import(
+    readme: &Path,
+    output: &Path,
+    force: bool,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    if output.exists() && !force {
+        anyhow::bail!(
+            "{} already exists. Use --force to overwrite.",
+            output.display()
+        );
+    }
+
+    let markdown: String = std::fs::read_to_string(readme)
+        .with_context(|| format!("could not open {}", readme.display()))?;
+    let imported: ImportedProfile = import::readme::import_readme(&markdown);
+    toml_io::write_config(
+        &imported.config,
+        output,
+        ConfigFormat::resolve(output, format),
+    )?;
+    println!("Created {} from {}", output.display(), readme.display());
+
+    for note in &imported.notes {
+        eprintln!("note: {}", note);
+    }
+    for block in &imported.unmapped {
+        eprintln!("unmapped: {}", block);
+    }
+    if !imported.unmapped.is_empty() {
+        eprintln!(
+            "{} block(s) could not be mapped and were kept in extras.custom_blocks",
+            imported.unmapped.len()
+        );
+    }
+    Ok(())
+}
+
+fn cmd_render(
+    file: &Path,
+    output: Option<&Path>,
+    stdout: bool,
+    assets_dir: Option<&Path>,
+    logos_dir: Option<&Path>,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    let config = load_with_warnings(file, format)?;
 
     if config.meta.username.is_empty() {
         return Err(ConfigError::MissingUsername.into());
     }
+    let registry = Registry::default();
+    warn_config(file, &config, &registry);
+    let providers = Providers::with_registry(config.services.as_ref(), &registry);
 
-    let readme: String = markdown::render(&config);
+    let output: &Path = output.unwrap_or(if config.meta.is_organization() {
+        Path::new(".github/profile/README.md")
+    } else {
+        Path::new("README.md")
+    });
+    let readme: String = match assets_dir {
+        Some(dir) => {
+            // Paths in the README are relative to where it is read from.
+            let readme_dir: &Path = if stdout {
+                Path::new("")
+            } else {
+                output.parent().unwrap_or(Path::new(""))
+            };
+            let mut assets = LocalAssets::new(&relative_path(readme_dir, dir)?);
+            if let Some(logos) = logos_dir {
+                assets = assets.with_logos(logos);
+            }
+            let assets = Arc::new(assets);
+            let services = providers.with_assets(assets.clone());
+            let readme: String = markdown::render_with(&config, &services);
+            let written: usize = assets.write_to(dir)?;
+            if stdout {
+                eprintln!("Wrote {} asset(s) to {}", written, dir.display());
+            } else {
+                println!("Wrote {} asset(s) to {}", written, dir.display());
+            }
+            let missing: Vec<String> = assets.missing_logos();
+            if let Some(logos) = logos_dir
+                && !missing.is_empty()
+            {
+                eprintln!(
+                    "warning: {}: no logo for {} in {}; those badges are drawn without one",
+                    file.display(),
+                    missing.join(", "),
+                    logos.display()
+                );
+            }
+            let hosts: Vec<String> = assets::external_image_hosts(&readme);
+            if !hosts.is_empty() {
+                eprintln!(
+                    "warning: {}: images still load from {}; only badges and the typing header have local versions",
+                    file.display(),
+                    hosts.join(", ")
+                );
+            }
+            readme
+        }
+        None => markdown::render_with(&config, &providers),
+    };
 
     if stdout {
         print!("{}", readme);
     } else {
+        if let Some(parent) = output.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("could not create {}", parent.display()))?;
+        }
         std::fs::write(output, &readme)
             .with_context(|| format!("could not write {}", output.display()))?;
         println!("Wrote {}", output.display());
@@ -104,8 +573,66 @@ fn cmd_render(file: &Path, output: &Path, stdout: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_preview(file: &Path) -> Result<()> {
-    let config = toml_io::load_config(file)?;
+/// `to` as a forward-slash path relative to the directory `from`, e.g.
+/// `../../assets` from `.github/profile` to `assets`.
+fn relative_path(from: &Path, to: &Path) -> Result<String> {
+    let cwd: PathBuf = std::env::current_dir().context("could not read the current directory")?;
+    let (from, to): (PathBuf, PathBuf) = (cwd.join(from), cwd.join(to));
+    let (from, to): (Vec<Component>, Vec<Component>) = (normalize(&from), normalize(&to));
+    let common: usize = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let parts: Vec<String> = std::iter::repeat_n("..".to_string(), from.len() - common)
+        .chain(
+            to[common..]
+                .iter()
+                .map(|c: &Component| c.as_os_str().to_string_lossy().into_owned()),
+        )
+        .collect();
+    Ok(parts.join("/"))
+}
+
+/// Path components with `.` dropped and `..` applied.
+fn normalize(path: &Path) -> Vec<Component<'_>> {
+    let mut components: Vec<Component> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    components
+}
+
+fn cmd_render_batch(dir: &Path, out_dir: &Path) -> Result<()> {
+    let outcomes: Vec<BatchOutcome> = batch::render_batch(dir, out_dir, &Registry::default())?;
+    if outcomes.is_empty() {
+        anyhow::bail!("no profile configs found under {}", dir.display());
+    }
+
+    print!("{}", batch::summary_table(&outcomes, dir));
+    for outcome in &outcomes {
+        for warning in &outcome.warnings {
+            eprintln!("warning: {}: {}", outcome.source.display(), warning);
+        }
+        if let Some(error) = &outcome.error {
+            eprintln!("error: {}: {}", outcome.source.display(), error);
+        }
+    }
+
+    let failed: usize = outcomes
+        .iter()
+        .filter(|o: &&BatchOutcome| o.status() == BatchStatus::Failed)
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} profiles failed to render", failed, outcomes.len());
+    }
+    Ok(())
+}
+
+fn cmd_preview(file: &Path, format: Option<ConfigFormat>) -> Result<()> {
+    let config = load_with_warnings(file, format)?;
 
     if config.meta.username.is_empty() {
         return Err(ConfigError::MissingUsername.into());
@@ -115,3 +642,186 @@ fn cmd_preview(file: &Path) -> Result<()> {
     print!("{}", readme);
     Ok(())
 }
+
+fn cmd_set(file: &Path, path: &str, value: &str, format: Option<ConfigFormat>) -> Result<()> {
+    toml_io::edit_config_file(file, format, |table: &mut toml::Table| {
+        edit::set_field(table, path, value)
+    })?;
+    println!("Set {} in {}", path, file.display());
+    Ok(())
+}
+
+fn cmd_add(file: &Path, item: AddItem, format: Option<ConfigFormat>) -> Result<()> {
+    let (path, entry): (String, toml::Value) = match item {
+        AddItem::Skill { name, category } => {
+            let category: String = match category {
+                Some(category) => category,
+                None => urls::skill_category(&name)
+                    .map(str::to_string)
+                    .with_context(|| {
+                        format!("cannot guess a category for {}; pass --category", name)
+                    })?,
+            };
+            (format!("skills.{}", category), toml::Value::from(name))
+        }
+        AddItem::Project { repo, description } => {
+            if repo
+                .split('/')
+                .filter(|part: &&str| !part.is_empty())
+                .count()
+                != 2
+            {
+                anyhow::bail!("project must be given as owner/name, got {}", repo);
+            }
+            let mut project = toml::Table::new();
+            project.insert("repo".to_string(), toml::Value::from(repo));
+            if let Some(description) = description {
+                project.insert("description".to_string(), toml::Value::from(description));
+            }
+            ("projects.featured".to_string(), toml::Value::Table(project))
+        }
+        AddItem::Article { title, url } => {
+            let mut article = toml::Table::new();
+            article.insert("title".to_string(), toml::Value::from(title));
+            article.insert("url".to_string(), toml::Value::from(url));
+            ("blog.articles".to_string(), toml::Value::Table(article))
+        }
+    };
+
+    toml_io::edit_config_file(file, format, |table: &mut toml::Table| {
+        edit::append_entry(table, &path, entry)
+    })?;
+    println!("Added to {} in {}", path, file.display());
+    Ok(())
+}
+
+fn cmd_remove(
+    file: &Path,
+    path: &str,
+    value: Option<&str>,
+    format: Option<ConfigFormat>,
+) -> Result<()> {
+    toml_io::edit_config_file(file, format, |table: &mut toml::Table| match value {
+        Some(value) => edit::remove_entry(table, path, value),
+        None => edit::remove_field(table, path),
+    })?;
+    match value {
+        Some(value) => println!("Removed {} from {} in {}", value, path, file.display()),
+        None => println!("Removed {} from {}", path, file.display()),
+    }
+    Ok(())
+}
+
+fn cmd_validate(file: &Path, fix: bool, format: Option<ConfigFormat>) -> Result<()> {
+    let mut config = load_with_warnings(file, format)?;
+    if config.meta.username.is_empty() {
+        return Err(ConfigError::MissingUsername.into());
+    }
+
+    if fix {
+        let custom_icons = config
+            .skills
+            .as_ref()
+            .and_then(|skills| skills.custom_icons.clone());
+        let mut fixes: Vec<skill_icons::SpellingFix> = Vec::new();
+        toml_io::edit_config_file(file, format, |table: &mut toml::Table| {
+            fixes = skill_icons::fix_spellings(table, custom_icons.as_ref());
+            Ok(())
+        })?;
+        for fixed in &fixes {
+            println!("Fixed {}", fixed);
+        }
+        config = toml_io::load_config_with_format(file, format)?.config;
+    }
+
+    let warnings: ConfigWarnings = warn_config(file, &config, &Registry::default());
+    if !warnings.skills.is_empty() {
+        anyhow::bail!(
+            "{} unrecognized skill(s) in {}; correct the spelling or add them to [skills.custom_icons]",
+            warnings.skills.len(),
+            file.display()
+        );
+    }
+    if !warnings.providers.is_empty() {
+        anyhow::bail!(
+            "{} unknown provider(s) in [services] of {}",
+            warnings.providers.len(),
+            file.display()
+        );
+    }
+    if !warnings.social.is_empty() {
+        anyhow::bail!(
+            "{} problem(s) with [social] links in {}",
+            warnings.social.len(),
+            file.display()
+        );
+    }
+    println!("{} is valid", file.display());
+    Ok(())
+}
+
+fn cmd_migrate(file: &Path, format: Option<ConfigFormat>) -> Result<()> {
+    let report = toml_io::migrate_config_file(file, format)?;
+
+    for warning in &report.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    if report.migrated() {
+        println!(
+            "Migrated {} from schema version {} to {}",
+            file.display(),
+            report.from_version,
+            report.to_version
+        );
+    } else {
+        println!(
+            "{} is already at schema version {}",
+            file.display(),
+            report.to_version
+        );
+    }
+    Ok(())
+}
+
+fn cmd_team(manifest: &Path, output: &Path, stdout: bool) -> Result<()> {
+    let team = config_team::load_team(manifest)?;
+    let page: String = render_team::render_team(&team);
+
+    if stdout {
+        print!("{}", page);
+    } else {
+        std::fs::write(output, &page)
+            .with_context(|| format!("could not write {}", output.display()))?;
+        println!("Wrote {}", output.display());
+    }
+    Ok(())
+}
+
+fn cmd_config_show(file: &Path, resolved: bool, format: Option<ConfigFormat>) -> Result<()> {
+    let content: String = if resolved {
+        toml_io::resolved_config(file, format)?
+    } else {
+        std::fs::read_to_string(file)
+            .with_context(|| format!("could not open {}", file.display()))?
+    };
+    print!("{}", content);
+    Ok(())
+}
+
+/// Print every [`ConfigWarnings`] warning about `config` and return them.
+fn warn_config(file: &Path, config: &ProfileConfig, registry: &Registry) -> ConfigWarnings {
+    let warnings = ConfigWarnings::check(config, registry);
+    for warning in warnings.all() {
+        eprintln!("warning: {}: {}", file.display(), warning);
+    }
+    warnings
+}
+
+/// Load a config and print any deprecation warnings to stderr.
+fn load_with_warnings(file: &Path, format: Option<ConfigFormat>) -> Result<ProfileConfig> {
+    let loaded = toml_io::load_config_with_format(file, format)?;
+    for warning in &loaded.warnings {
+        eprintln!("warning: {}: {}", file.display(), warning);
+    }
+    Ok(loaded.config)
+}
@@ -0,0 +1,505 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Context, Result};
+
+use crate::config::format::ConfigFormat;
+use crate::config::toml_io::{self, LoadedConfig};
+use crate::error::ConfigError;
+use crate::render::markdown;
+use crate::render::warnings::ConfigWarnings;
+use crate::services::providers::{Providers, Registry};
+
+/// Result of rendering one profile in a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOutcome {
+    pub source: PathBuf,
+    pub username: Option<String>,
+    pub output: Option<PathBuf>,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl BatchOutcome {
+    pub fn status(&self) -> BatchStatus {
+        if self.error.is_some() {
+            BatchStatus::Failed
+        } else if !self.warnings.is_empty() {
+            BatchStatus::Warning
+        } else {
+            BatchStatus::Ok
+        }
+    }
+
+    fn failed(source: &Path, username: Option<String>, error: String) -> Self {
+        Self {
+            source: source.to_path_buf(),
+            username,
+            output: None,
+            warnings: Vec::new(),
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchStatus {
+    Ok,
+    Warning,
+    Failed,
+}
+
+impl BatchStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BatchStatus::Ok => "ok",
+            BatchStatus::Warning => "warn",
+            BatchStatus::Failed => "FAILED",
+        }
+    }
+}
+
+/// Find every config file (`.toml`, `.yaml`/`.yml`, `.json`) under `dir`,
+/// recursively, in sorted order.
+///
+/// Files that another config in `dir` names in `extends` are shared bases
+/// rather than profiles, so they are left out. Symlinked directories are
+/// followed, but each directory is read only once.
+pub fn find_configs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut found: Vec<PathBuf> = Vec::new();
+    let mut pending: Vec<PathBuf> = vec![dir.to_path_buf()];
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+
+    while let Some(current) = pending.pop() {
+        let canonical: PathBuf = current
+            .canonicalize()
+            .with_context(|| format!("could not read directory {}", current.display()))?;
+        if !visited.insert(canonical) {
+            continue;
+        }
+        let entries = std::fs::read_dir(&current)
+            .with_context(|| format!("could not read directory {}", current.display()))?;
+        for entry in entries {
+            let path: PathBuf = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if ConfigFormat::from_path(&path).is_some() {
+                found.push(path);
+            }
+        }
+    }
+
+    let bases: HashSet<PathBuf> = found.iter().flat_map(|path| extended_bases(path)).collect();
+    found.retain(|path: &PathBuf| {
+        path.canonicalize()
+            .map_or(true, |canonical: PathBuf| !bases.contains(&canonical))
+    });
+    found.sort();
+    Ok(found)
+}
+
+/// Canonical paths of the bases `path` names in `extends`. Files that cannot
+/// be read or parsed yield nothing here and fail later, when rendered.
+fn extended_bases(path: &Path) -> Vec<PathBuf> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let Ok(table) = ConfigFormat::resolve(path, None).parse_table(&content) else {
+        return Vec::new();
+    };
+    let Some(toml::Value::Array(extends)) = table.get("extends") else {
+        return Vec::new();
+    };
+    let dir: &Path = path.parent().unwrap_or(Path::new(""));
+    extends
+        .iter()
+        .filter_map(toml::Value::as_str)
+        .filter_map(|base: &str| dir.join(base).canonicalize().ok())
+        .collect()
+}
+
+/// GitHub logins are 1-39 ASCII letters, digits or hyphens, not starting
+/// with a hyphen. Anything else could escape the output directory.
+fn is_github_login(name: &str) -> bool {
+    (1..=39).contains(&name.len())
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c: char| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Render every profile under `dir` to `out_dir/<username>/README.md`, with
+/// `[services]` providers looked up in `registry`.
+///
+/// Profiles are loaded and rendered in parallel; files are written afterwards
+/// so that two profiles claiming the same username are reported instead of
+/// overwriting each other.
+pub fn render_batch(dir: &Path, out_dir: &Path, registry: &Registry) -> Result<Vec<BatchOutcome>> {
+    let sources: Vec<PathBuf> = find_configs(dir)?;
+    let rendered: Vec<Result<(LoadedConfig, String), String>> =
+        parallel_map(&sources, |source: &PathBuf| render_one(source, registry));
+
+    let mut claimed: HashMap<String, PathBuf> = HashMap::new();
+    let mut outcomes: Vec<BatchOutcome> = Vec::with_capacity(sources.len());
+
+    for (source, result) in sources.iter().zip(rendered) {
+        let (loaded, readme) = match result {
+            Ok(ok) => ok,
+            Err(error) => {
+                outcomes.push(BatchOutcome::failed(source, None, error));
+                continue;
+            }
+        };
+
+        let username: String = loaded.config.meta.username.clone();
+        if !is_github_login(&username) {
+            outcomes.push(BatchOutcome::failed(
+                source,
+                Some(username.clone()),
+                format!("username {:?} is not a valid GitHub login", username),
+            ));
+            continue;
+        }
+        if let Some(first) = claimed.get(&username) {
+            outcomes.push(BatchOutcome::failed(
+                source,
+                Some(username.clone()),
+                format!(
+                    "duplicate username {} (also in {})",
+                    username,
+                    first.display()
+                ),
+            ));
+            continue;
+        }
+        claimed.insert(username.clone(), source.clone());
+
+        let output: PathBuf = out_dir.join(&username).join("README.md");
+        let written: Result<()> = output
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&output, &readme))
+            .with_context(|| format!("could not write {}", output.display()));
+
+        outcomes.push(match written {
+            Ok(()) => BatchOutcome {
+                source: source.clone(),
+                username: Some(username),
+                output: Some(output),
+                warnings: loaded.warnings,
+                error: None,
+            },
+            Err(e) => BatchOutcome::failed(source, Some(username), format!("{:#}", e)),
+        });
+    }
+
+    Ok(outcomes)
+}
+
+fn render_one(source: &Path, registry: &Registry) -> Result<(LoadedConfig, String), String> {
+    let mut loaded: LoadedConfig =
+        toml_io::load_config_with_warnings(source).map_err(|e| format!("{:#}", e))?;
+    if loaded.config.meta.username.is_empty() {
+        return Err(ConfigError::MissingUsername.to_string());
+    }
+    let warnings = ConfigWarnings::check(&loaded.config, registry);
+    loaded.warnings.extend(warnings.all().cloned());
+    let providers = Providers::with_registry(loaded.config.services.as_ref(), registry);
+    let readme: String = markdown::render_with(&loaded.config, &providers);
+    Ok((loaded, readme))
+}
+
+/// Apply `f` to every item on a small pool of scoped threads, keeping input order.
+fn parallel_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let workers: usize = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(items.len())
+        .max(1);
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new((0..items.len()).map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let i: usize = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    let result: R = f(item);
+                    results.lock().expect("batch results lock poisoned")[i] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("batch results lock poisoned")
+        .into_iter()
+        .map(|r: Option<R>| r.expect("every batch item is processed"))
+        .collect()
+}
+
+/// Format a plain-text summary table of batch outcomes.
+///
+/// Only the first line of each error is shown; callers print the full text.
+pub fn summary_table(outcomes: &[BatchOutcome], base: &Path) -> String {
+    let rows: Vec<(&str, String, String)> = outcomes
+        .iter()
+        .map(|o: &BatchOutcome| {
+            let source: String = o
+                .source
+                .strip_prefix(base)
+                .unwrap_or(&o.source)
+                .display()
+                .to_string();
+            let detail: String = match (&o.error, &o.output) {
+                (Some(error), _) => error.lines().next().unwrap_or_default().to_string(),
+                (None, Some(output)) if o.warnings.is_empty() => output.display().to_string(),
+                (None, Some(output)) => format!(
+                    "{} ({} warning{})",
+                    output.display(),
+                    o.warnings.len(),
+                    if o.warnings.len() == 1 { "" } else { "s" }
+                ),
+                (None, None) => String::new(),
+            };
+            (o.status().label(), source, detail)
+        })
+        .collect();
+
+    let source_width: usize = rows
+        .iter()
+        .map(|(_, source, _)| source.len())
+        .chain(std::iter::once("PROFILE".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut out: String = format!("{:<6}  {:<source_width$}  OUTPUT\n", "STATUS", "PROFILE");
+    for (status, source, detail) in &rows {
+        out.push_str(&format!(
+            "{:<6}  {:<source_width$}  {}\n",
+            status, source, detail
+        ));
+    }
+
+    let count = |status: BatchStatus| -> usize {
+        outcomes
+            .iter()
+            .filter(|o: &&BatchOutcome| o.status() == status)
+            .count()
+    };
+    out.push_str(&format!(
+        "\n{} profiles: {} ok, {} with warnings, {} failed\n",
+        outcomes.len(),
+        count(BatchStatus::Ok),
+        count(BatchStatus::Warning),
+        count(BatchStatus::Failed)
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        let path: PathBuf = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn profiles() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "alice.toml",
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[header]\nstyle = \"text\"\n",
+        );
+        write(
+            dir.path(),
+            "nested/bob.toml",
+            "[meta]\nusername = \"bob\"\n\n[projects]\nrepos = [\"bob/tool\"]\n",
+        );
+        write(dir.path(), "broken.toml", "[meta\n");
+        write(dir.path(), "notes.md", "not a profile");
+        dir
+    }
+
+    #[test]
+    fn test_find_configs_recursive_sorted() {
+        let dir = profiles();
+        let found: Vec<PathBuf> = find_configs(dir.path()).unwrap();
+        let names: Vec<String> = found
+            .iter()
+            .map(|p: &PathBuf| p.strip_prefix(dir.path()).unwrap().display().to_string())
+            .collect();
+        assert_eq!(names, vec!["alice.toml", "broken.toml", "nested/bob.toml"]);
+    }
+
+    #[test]
+    fn test_render_batch_every_format() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "alice.yml",
+            "meta:\n  username: alice\n  schema_version: 2\n",
+        );
+        write(
+            dir.path(),
+            "bob.json",
+            r#"{"meta": {"username": "bob", "schema_version": 2}}"#,
+        );
+        let out = TempDir::new().unwrap();
+
+        let outcomes: Vec<BatchOutcome> =
+            render_batch(dir.path(), out.path(), &Registry::default()).unwrap();
+        let statuses: Vec<BatchStatus> = outcomes.iter().map(BatchOutcome::status).collect();
+        assert_eq!(statuses, vec![BatchStatus::Ok, BatchStatus::Ok]);
+        assert!(out.path().join("alice").join("README.md").exists());
+        assert!(out.path().join("bob").join("README.md").exists());
+    }
+
+    #[test]
+    fn test_render_batch_outcomes() {
+        let dir = profiles();
+        let out = TempDir::new().unwrap();
+        let outcomes: Vec<BatchOutcome> =
+            render_batch(dir.path(), out.path(), &Registry::default()).unwrap();
+
+        let statuses: Vec<BatchStatus> = outcomes.iter().map(BatchOutcome::status).collect();
+        assert_eq!(
+            statuses,
+            vec![BatchStatus::Ok, BatchStatus::Failed, BatchStatus::Warning]
+        );
+
+        let alice: String =
+            std::fs::read_to_string(out.path().join("alice").join("README.md")).unwrap();
+        assert!(alice.contains("Hey! I'm alice"));
+        let bob: String =
+            std::fs::read_to_string(out.path().join("bob").join("README.md")).unwrap();
+        assert!(bob.contains("bob/tool"));
+        assert!(
+            outcomes[1]
+                .error
+                .as_ref()
+                .unwrap()
+                .contains("failed to parse")
+        );
+    }
+
+    #[test]
+    fn test_render_batch_duplicate_username() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "a.toml",
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n",
+        );
+        write(
+            dir.path(),
+            "b.toml",
+            "[meta]\nusername = \"alice\"\nschema_version = 2\n",
+        );
+        let out = TempDir::new().unwrap();
+
+        let outcomes: Vec<BatchOutcome> =
+            render_batch(dir.path(), out.path(), &Registry::default()).unwrap();
+        assert_eq!(outcomes[0].status(), BatchStatus::Ok);
+        assert_eq!(outcomes[1].status(), BatchStatus::Failed);
+        assert!(
+            outcomes[1]
+                .error
+                .as_ref()
+                .unwrap()
+                .contains("duplicate username alice")
+        );
+    }
+
+    #[test]
+    fn test_render_batch_rejects_path_like_username() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "evil.toml",
+            "[meta]\nusername = \"../../pwned\"\nschema_version = 2\n",
+        );
+        let out = TempDir::new().unwrap();
+
+        let outcomes: Vec<BatchOutcome> =
+            render_batch(dir.path(), out.path(), &Registry::default()).unwrap();
+        assert_eq!(outcomes[0].status(), BatchStatus::Failed);
+        assert!(
+            outcomes[0]
+                .error
+                .as_ref()
+                .unwrap()
+                .contains("not a valid GitHub login")
+        );
+        assert!(std::fs::read_dir(out.path()).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn test_is_github_login() {
+        assert!(is_github_login("alice"));
+        assert!(is_github_login("Bob-42"));
+        assert!(!is_github_login(""));
+        assert!(!is_github_login("-alice"));
+        assert!(!is_github_login(".."));
+        assert!(!is_github_login("a/b"));
+        assert!(!is_github_login(&"a".repeat(40)));
+    }
+
+    #[test]
+    fn test_find_configs_skips_extended_bases() {
+        let dir = TempDir::new().unwrap();
+        write(
+            dir.path(),
+            "shared/base.toml",
+            "[stats]\ntheme = \"dark\"\n",
+        );
+        write(
+            dir.path(),
+            "alice.toml",
+            "extends = [\"shared/base.toml\"]\n[meta]\nusername = \"alice\"\n",
+        );
+        let found: Vec<PathBuf> = find_configs(dir.path()).unwrap();
+        assert_eq!(found, vec![dir.path().join("alice.toml")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_configs_survives_symlink_loop() {
+        let dir = profiles();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("nested/loop")).unwrap();
+        let found: Vec<PathBuf> = find_configs(dir.path()).unwrap();
+        assert_eq!(found.len(), 3);
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let dir = profiles();
+        let out = TempDir::new().unwrap();
+        let outcomes: Vec<BatchOutcome> =
+            render_batch(dir.path(), out.path(), &Registry::default()).unwrap();
+        let table: String = summary_table(&outcomes, dir.path());
+
+        assert!(table.starts_with("STATUS  PROFILE"));
+        assert!(table.contains("ok      alice.toml"));
+        assert!(table.contains("FAILED  broken.toml"));
+        assert!(table.contains("(2 warnings)"));
+        assert!(table.contains("3 profiles: 1 ok, 1 with warnings, 1 failed"));
+    }
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u32> = (0..50).collect();
+        let doubled: Vec<u32> = parallel_map(&items, |n: &u32| n * 2);
+        assert_eq!(doubled, (0..50).map(|n| n * 2).collect::<Vec<u32>>());
+    }
+}
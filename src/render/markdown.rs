@@ -1,11 +1,17 @@
-use crate::config::profile::{Layout, ProfileConfig, Template};
+use crate::config::profile::{CardColors, Layout, ProfileConfig, Template};
 use crate::render::sections;
 use crate::render::templates::{
     self, Section, is_centered, is_multi_column, wrap_centered, wrap_multi_column,
 };
+use crate::services::providers::Providers;
 
 /// Render a complete README.md from a ProfileConfig.
 pub fn render(config: &ProfileConfig) -> String {
+    render_with(config, &Providers::new(config.services.as_ref()))
+}
+
+/// Like [`render`], drawing cards and badges with `services`.
+pub fn render_with(config: &ProfileConfig, services: &Providers) -> String {
     let layout: &Layout = config.layout.as_ref().unwrap_or(&Layout {
         template: Some(Template::Full),
         dark_mode: Some(false),
@@ -13,7 +19,11 @@ pub fn render(config: &ProfileConfig) -> String {
     });
     let template: &Template = layout.template.as_ref().unwrap_or(&Template::Full);
     let centered: bool = layout.centered.unwrap_or(false) || is_centered(template);
-    let ordered_sections: Vec<Section> = templates::sections_for_template(template);
+    let ordered_sections: Vec<Section> = if config.meta.is_organization() {
+        templates::sections_for_organization(template)
+    } else {
+        templates::sections_for_template(template)
+    };
 
     let theme: &str = config
         .stats
@@ -29,7 +39,7 @@ pub fn render(config: &ProfileConfig) -> String {
     let rendered: Vec<String> = ordered_sections
         .iter()
         .filter_map(|section: &Section| {
-            let content: String = render_section(section, config, theme, hide_border);
+            let content: String = render_section(section, config, theme, hide_border, services);
             if content.is_empty() {
                 None
             } else if centered {
@@ -55,12 +65,13 @@ fn render_section(
     config: &ProfileConfig,
     theme: &str,
     hide_border: bool,
+    services: &Providers,
 ) -> String {
     match section {
         Section::Header => config
             .header
             .as_ref()
-            .map(|h| sections::render_header(h, &config.meta))
+            .map(|h| sections::render_header(h, &config.meta, services))
             .unwrap_or_default(),
         Section::About => config
             .about
@@ -70,22 +81,34 @@ fn render_section(
         Section::Social => config
             .social
             .as_ref()
-            .map(sections::render_social)
+            .map(|s| sections::render_social(s, services))
             .unwrap_or_default(),
         Section::Skills => config
             .skills
             .as_ref()
-            .map(sections::render_skills)
+            .map(|s| sections::render_skills(s, services))
+            .unwrap_or_default(),
+        Section::Stats if config.meta.is_organization() => config
+            .stats
+            .as_ref()
+            .map(|s| sections::render_org_stats(s, &config.meta, services))
             .unwrap_or_default(),
         Section::Stats => config
             .stats
             .as_ref()
-            .map(|s| sections::render_stats(s, &config.meta))
+            .map(|s| sections::render_stats(s, &config.meta, services))
             .unwrap_or_default(),
         Section::Projects => config
             .projects
             .as_ref()
-            .map(|p| sections::render_projects(p, &config.meta, theme, hide_border))
+            .map(|p| {
+                let colors: CardColors = config
+                    .stats
+                    .as_ref()
+                    .and_then(|s| s.colors.clone())
+                    .unwrap_or_default();
+                sections::render_projects(p, &config.meta, theme, hide_border, &colors, services)
+            })
             .unwrap_or_default(),
         Section::Blog => config
             .blog
@@ -95,17 +118,37 @@ fn render_section(
         Section::Dynamic => config
             .dynamic
             .as_ref()
-            .map(sections::render_dynamic)
+            .map(|d| sections::render_dynamic(d, services))
             .unwrap_or_default(),
         Section::Sponsors => config
             .sponsors
             .as_ref()
-            .map(|s| sections::render_sponsors(s, &config.meta))
+            .map(|s| sections::render_sponsors(s, &config.meta, services))
             .unwrap_or_default(),
         Section::Extras => config
             .extras
             .as_ref()
-            .map(sections::render_extras)
+            .map(|e| sections::render_extras(e, services))
+            .unwrap_or_default(),
+        Section::Mission => config
+            .organization
+            .as_ref()
+            .map(sections::render_mission)
+            .unwrap_or_default(),
+        Section::Contributing => config
+            .organization
+            .as_ref()
+            .map(sections::render_contributing)
+            .unwrap_or_default(),
+        Section::Community => sections::render_community(
+            config.organization.as_ref(),
+            config.social.as_ref(),
+            services,
+        ),
+        Section::Members => config
+            .organization
+            .as_ref()
+            .map(sections::render_members)
             .unwrap_or_default(),
     }
 }
@@ -120,6 +163,8 @@ mod tests {
             meta: Meta {
                 username: "alice".to_string(),
                 name: Some("Alice".to_string()),
+                kind: None,
+                schema_version: None,
             },
             header: Some(Header {
                 style: Some(HeaderStyle::Text),
@@ -141,7 +186,7 @@ mod tests {
                 timezone: None,
             }),
             social: Some(Social {
-                twitter: Some("https://twitter.com/alice".to_string()),
+                twitter: Some("https://twitter.com/alice".into()),
                 ..Social::default()
             }),
             layout: Some(Layout {
@@ -158,6 +203,8 @@ mod tests {
             meta: Meta {
                 username: "alice".to_string(),
                 name: Some("Alice".to_string()),
+                kind: None,
+                schema_version: None,
             },
             header: Some(Header {
                 style: Some(HeaderStyle::TypingSvg),
@@ -179,13 +226,13 @@ mod tests {
                 timezone: None,
             }),
             social: Some(Social {
-                twitter: Some("https://twitter.com/alice".to_string()),
-                linkedin: Some("https://linkedin.com/in/alice".to_string()),
+                twitter: Some("https://twitter.com/alice".into()),
+                linkedin: Some("https://linkedin.com/in/alice".into()),
                 ..Social::default()
             }),
             skills: Some(Skills {
-                languages: Some(vec!["Rust".to_string(), "Python".to_string()]),
-                tools: Some(vec!["Docker".to_string()]),
+                languages: Some(vec!["Rust".into(), "Python".into()]),
+                tools: Some(vec!["Docker".into()]),
                 ..Skills::default()
             }),
             stats: Some(Stats {
@@ -200,9 +247,15 @@ mod tests {
                 hide_border: Some(false),
                 top_langs_layout: None,
                 top_langs_count: None,
+                card: None,
+                langs: None,
+                colors: None,
             }),
             projects: Some(Projects {
-                repos: Some(vec!["alice/cool-cli".to_string()]),
+                featured: Some(vec![FeaturedProject {
+                    repo: "alice/cool-cli".to_string(),
+                    description: None,
+                }]),
                 display: Some(ProjectDisplay::PinCards),
             }),
             blog: Some(Blog {
@@ -230,6 +283,7 @@ mod tests {
                 pgp_fingerprint: Some("ABCD1234".to_string()),
                 ..Extras::default()
             }),
+            ..ProfileConfig::default()
         }
     }
 
@@ -310,6 +364,8 @@ mod tests {
             meta: Meta {
                 username: "bob".to_string(),
                 name: None,
+                kind: None,
+                schema_version: None,
             },
             ..ProfileConfig::default()
         };
@@ -318,6 +374,68 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_render_organization() {
+        let config = ProfileConfig {
+            meta: Meta {
+                username: "acme".to_string(),
+                name: Some("Acme".to_string()),
+                kind: Some(ProfileKind::Organization),
+                schema_version: None,
+            },
+            header: Some(Header {
+                style: Some(HeaderStyle::Text),
+                banner_url: None,
+                typing_lines: None,
+                typing_font: None,
+                typing_color: None,
+                tagline: None,
+            }),
+            about: Some(About {
+                role: None,
+                company: None,
+                current_work: None,
+                learning: None,
+                reach_me: None,
+                fun_fact: Some("ignored".to_string()),
+                pronouns: None,
+                location: None,
+                timezone: None,
+            }),
+            stats: Some(Stats {
+                stats_card: Some(true),
+                top_langs: Some(true),
+                streak: Some(true),
+                contributor_stats: None,
+                trophies: None,
+                contribution_snake: None,
+                profile_views: None,
+                theme: None,
+                hide_border: None,
+                top_langs_layout: None,
+                top_langs_count: None,
+                card: None,
+                langs: None,
+                colors: None,
+            }),
+            organization: Some(Organization {
+                mission: Some("Open tools for everyone".to_string()),
+                members: Some(vec!["alice".to_string()]),
+                ..Organization::default()
+            }),
+            ..ProfileConfig::default()
+        };
+        let result: String = render(&config);
+        assert!(result.starts_with("## Welcome to Acme"));
+        assert!(result.contains("### Our Mission\n\nOpen tools for everyone"));
+        assert!(result.contains("https://img.shields.io/github/followers/acme"));
+        assert!(result.contains("https://github.com/alice.png"));
+        // Person-only sections and user-only cards are skipped
+        assert!(!result.contains("Fun fact"));
+        assert!(!result.contains("streak-stats"));
+        assert!(!result.contains("top-langs"));
+    }
+
     #[test]
     fn test_render_sections_joined_with_separators() {
         let config: ProfileConfig = minimal_config();
@@ -326,6 +444,23 @@ mod tests {
         assert!(result.contains("---"));
     }
 
+    #[test]
+    fn test_render_colors_stats_streak_and_pin_cards() {
+        let mut config: ProfileConfig = full_config();
+        if let Some(stats) = config.stats.as_mut() {
+            stats.colors = Some(CardColors {
+                bg: Some("#1A1B27".to_string()),
+                ..CardColors::default()
+            });
+        }
+        let result: String = render(&config);
+        assert!(result.contains("show_icons=true&hide_border=false&bg_color=1A1B27"));
+        assert!(result.contains("hide_border=false&background=1A1B27"));
+        assert!(
+            result.contains("repo=cool-cli&theme=tokyonight&hide_border=false&bg_color=1A1B27")
+        );
+    }
+
     #[test]
     fn test_render_dark_light_mode() {
         // dark_mode config field is recognized (used by future image handling)
@@ -333,6 +468,8 @@ mod tests {
             meta: Meta {
                 username: "alice".to_string(),
                 name: None,
+                kind: None,
+                schema_version: None,
             },
             layout: Some(Layout {
                 template: Some(Template::Full),
@@ -344,4 +481,100 @@ mod tests {
         let _result: String = render(&config);
         // Just verify it doesn't panic with dark_mode enabled
     }
+
+    #[test]
+    fn test_render_uses_service_overrides_everywhere() {
+        const HOSTS: [(&str, &str); 10] = [
+            ("stats", "github-readme-stats.vercel.app"),
+            ("streak", "streak-stats.demolab.com"),
+            ("trophies", "github-profile-trophy.vercel.app"),
+            ("typing", "readme-typing-svg.demolab.com"),
+            ("contrib", "github-contributor-stats.vercel.app"),
+            ("shields", "img.shields.io"),
+            ("badgen", "badgen.net"),
+            ("icons", "cdn.simpleicons.org"),
+            ("views", "komarev.com"),
+            ("spotify", "kittinan.vercel.app"),
+        ];
+        let host = |name: &str| Some(format!("https://cards.example.com/{}/", name));
+        // Every section that draws from a service, with social links shown as
+        // simple-icons logos.
+        let config = |badge: &str| ProfileConfig {
+            meta: Meta {
+                username: "alice".to_string(),
+                ..Meta::default()
+            },
+            header: Some(Header {
+                style: Some(HeaderStyle::TypingSvg),
+                typing_lines: Some(vec!["Hello".to_string()]),
+                ..Header::default()
+            }),
+            social: Some(Social {
+                github: Some("alice".into()),
+                display: Some(SocialDisplay::Icons),
+                ..Social::default()
+            }),
+            skills: Some(Skills {
+                languages: Some(vec!["Rust".into()]),
+                ..Skills::default()
+            }),
+            stats: Some(Stats {
+                stats_card: Some(true),
+                top_langs: Some(true),
+                streak: Some(true),
+                contributor_stats: Some(true),
+                trophies: Some(true),
+                profile_views: Some(true),
+                ..Stats::default()
+            }),
+            projects: Some(Projects {
+                featured: Some(vec![FeaturedProject {
+                    repo: "alice/tool".to_string(),
+                    description: None,
+                }]),
+                display: Some(ProjectDisplay::PinCards),
+            }),
+            dynamic: Some(Dynamic {
+                spotify_uid: Some("alice".to_string()),
+                ..Dynamic::default()
+            }),
+            sponsors: Some(Sponsors {
+                github_sponsors: Some(true),
+                ..Sponsors::default()
+            }),
+            services: Some(Services {
+                readme_stats: host("stats"),
+                streak_stats: host("streak"),
+                trophies: host("trophies"),
+                typing_svg: host("typing"),
+                contributor_stats: host("contrib"),
+                shields: host("shields"),
+                badgen: host("badgen"),
+                simple_icons: host("icons"),
+                profile_views: host("views"),
+                spotify: host("spotify"),
+                badge: Some(badge.to_string()),
+                ..Services::default()
+            }),
+            ..ProfileConfig::default()
+        };
+
+        // Shields and badgen are alternative badge providers, so cover each in turn.
+        let result: String = [render(&config("shields")), render(&config("badgen"))].join("\n");
+        for (service, default_host) in HOSTS {
+            assert!(
+                !result.contains(default_host),
+                "{} leaked into:\n{}",
+                default_host,
+                result
+            );
+            assert!(
+                result.contains(&format!("https://cards.example.com/{}/", service)),
+                "{} override unused:\n{}",
+                service,
+                result
+            );
+        }
+        assert!(!result.contains("cards.example.com/stats//"));
+    }
 }
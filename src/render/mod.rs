@@ -1,3 +1,6 @@
+pub mod batch;
 pub mod markdown;
 pub mod sections;
+pub mod team;
 pub mod templates;
+pub mod warnings;
@@ -1,22 +1,26 @@
 use crate::config::profile::*;
-use crate::services::urls;
+use crate::services::providers::Providers;
+use crate::services::urls::{self, Badge, Card};
+use crate::services::{skill_icons, social};
+
+const MEMBER_AVATAR_SIZE: u32 = 80;
 
 /// Render the header section.
-pub fn render_header(header: &Header, meta: &Meta) -> String {
+pub fn render_header(header: &Header, meta: &Meta, services: &Providers) -> String {
     let style: &HeaderStyle = match &header.style {
         Some(s) => s,
         None => return render_header_text(header, meta),
     };
 
     match style {
-        HeaderStyle::TypingSvg => render_header_typing_svg(header),
+        HeaderStyle::TypingSvg => render_header_typing_svg(header, services),
         HeaderStyle::Text => render_header_text(header, meta),
         HeaderStyle::Banner => render_header_banner(header),
         HeaderStyle::Wave => render_header_wave(meta),
     }
 }
 
-fn render_header_typing_svg(header: &Header) -> String {
+fn render_header_typing_svg(header: &Header, services: &Providers) -> String {
     let lines: &[String] = match &header.typing_lines {
         Some(l) if !l.is_empty() => l,
         _ => return String::new(),
@@ -24,17 +28,28 @@ fn render_header_typing_svg(header: &Header) -> String {
     let font: &str = header.typing_font.as_deref().unwrap_or("Fira Code");
     let color: &str = header.typing_color.as_deref().unwrap_or("f75c7e");
     let line_strs: Vec<&str> = lines.iter().map(|s: &String| s.as_str()).collect();
-    let url: String = urls::typing_svg_url(&line_strs, font, color, true);
+    if let Some(assets) = services.assets() {
+        return format!(
+            "<p align=\"center\">\n  <img src=\"{}\" alt=\"{}\" />\n</p>",
+            assets.typing_header(&line_strs, font, color),
+            html_escape(&lines.join(" "))
+        );
+    }
+    let url: String = services.urls.typing_svg_url(&line_strs, font, color, true);
 
     format!(
-        "<p align=\"center\">\n  <a href=\"https://readme-typing-svg.demolab.com/\">\n    <img src=\"{}\" />\n  </a>\n</p>",
-        url
+        "<p align=\"center\">\n  <a href=\"{}/\">\n    <img src=\"{}\" />\n  </a>\n</p>",
+        services.urls.typing_svg, url
     )
 }
 
 fn render_header_text(header: &Header, meta: &Meta) -> String {
     let name: &str = meta.name.as_deref().unwrap_or(&meta.username);
-    let mut out = format!("## Hey! I'm {}", name);
+    let mut out = if meta.is_organization() {
+        format!("## Welcome to {}", name)
+    } else {
+        format!("## Hey! I'm {}", name)
+    };
     if let Some(tagline) = &header.tagline {
         out.push_str(&format!("\n\n{}", tagline));
     }
@@ -50,7 +65,11 @@ fn render_header_banner(header: &Header) -> String {
 
 fn render_header_wave(meta: &Meta) -> String {
     let name: &str = meta.name.as_deref().unwrap_or(&meta.username);
-    format!("# Hi there, I'm {} \u{1f44b}", name)
+    if meta.is_organization() {
+        format!("# Welcome to {} \u{1f44b}", name)
+    } else {
+        format!("# Hi there, I'm {} \u{1f44b}", name)
+    }
 }
 
 /// Render the About Me section.
@@ -95,84 +114,341 @@ pub fn render_about(about: &About) -> String {
         .join("\n")
 }
 
-/// Render the Social Links section as badges.
-pub fn render_social(social: &Social) -> String {
-    let platforms: Vec<(&str, &Option<String>)> = vec![
-        ("github", &social.github),
-        ("twitter", &social.twitter),
-        ("linkedin", &social.linkedin),
-        ("mastodon", &social.mastodon),
-        ("bluesky", &social.bluesky),
-        ("instagram", &social.instagram),
-        ("youtube", &social.youtube),
-        ("discord", &social.discord),
-        ("devto", &social.devto),
-        ("hashnode", &social.hashnode),
-        ("medium", &social.medium),
-        ("stackoverflow", &social.stackoverflow),
-        ("reddit", &social.reddit),
-        ("twitch", &social.twitch),
-        ("website", &social.website),
-        ("email", &social.email),
-        ("kofi", &social.kofi),
-        ("rss", &social.rss),
-    ];
-
-    let badges: Vec<String> = platforms
+/// A configured social link with the badge metadata needed to render it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlatformLink<'a> {
+    /// Key in `[social]`, or "custom" for `[[social.custom]]` entries.
+    pub platform: &'a str,
+    pub label: &'a str,
+    pub logo: &'a str,
+    pub color: &'a str,
+    /// The URL or handle as written in the config.
+    pub value: &'a str,
+    /// Link target, with handles expanded by [`social::profile_url`].
+    pub url: String,
+    /// User-supplied text such as "@alice", shown instead of `label`.
+    pub custom_label: Option<&'a str>,
+    /// Rendered as an HTML `<a rel="me">` anchor for profile verification.
+    pub verify: bool,
+}
+
+impl PlatformLink<'_> {
+    /// The custom label if there is one, otherwise the platform name.
+    pub fn text(&self) -> &str {
+        self.custom_label.unwrap_or(self.label)
+    }
+}
+
+/// Configured social links in display order: built-in platforms, then custom ones.
+pub fn social_links(social: &Social) -> Vec<PlatformLink<'_>> {
+    let builtin = social.platforms().into_iter().filter_map(
+        |(platform, entry): (&'static str, &Option<SocialEntry>)| {
+            let entry: &SocialEntry = entry.as_ref()?;
+            let (label, logo, color) = urls::social_platform_info(platform)?;
+            Some(PlatformLink {
+                platform,
+                label,
+                logo,
+                color,
+                value: entry.url(),
+                url: social::profile_url(platform, entry.url()),
+                custom_label: entry.label(),
+                verify: entry.verify().unwrap_or(platform == "mastodon"),
+            })
+        },
+    );
+    let custom = social
+        .custom
         .iter()
-        .filter_map(|(platform, url_opt): &(&str, &Option<String>)| {
-            let url: &String = url_opt.as_ref()?;
-            let (label, logo, color): (&str, &str, &str) = urls::social_platform_info(platform)?;
-            Some(urls::social_badge_markdown(label, color, logo, url))
-        })
-        .collect();
+        .flatten()
+        .map(|link: &CustomSocial| PlatformLink {
+            platform: "custom",
+            label: &link.label,
+            logo: link.logo.as_deref().unwrap_or_default(),
+            color: link.color.as_deref().unwrap_or("333333"),
+            value: &link.url,
+            url: link.url.clone(),
+            custom_label: None,
+            verify: link.verify == Some(true),
+        });
+    builtin.chain(custom).collect()
+}
 
-    if badges.is_empty() {
+/// Render the Social Links section in the configured display style.
+pub fn render_social(social: &Social, services: &Providers) -> String {
+    let links: String = social_links_block(social, services);
+    if links.is_empty() {
         return String::new();
     }
 
-    format!("### Connect with me\n\n{}", badges.join("\n"))
+    format!("### Connect with me\n\n{}", links)
 }
 
-/// Render the Skills / Tech Stack section as badges.
-pub fn render_skills(skills: &Skills) -> String {
-    let mut all_badges: Vec<String> = Vec::new();
+/// Social links as badges, an icon row, inline text or a table; empty if none.
+fn social_links_block(social: &Social, services: &Providers) -> String {
+    let links: Vec<PlatformLink> = social_links(social);
+    if links.is_empty() {
+        return String::new();
+    }
 
-    let categories: Vec<(&str, &Option<Vec<String>>)> = vec![
-        ("Languages", &skills.languages),
-        ("Frameworks", &skills.frameworks),
-        ("Tools", &skills.tools),
-        ("Databases", &skills.databases),
-        ("Cloud/Infra", &skills.cloud),
-    ];
+    match social.display.unwrap_or_default() {
+        SocialDisplay::Badges => links
+            .iter()
+            .map(|link: &PlatformLink| {
+                if !link.verify {
+                    return services.social_badge_markdown(
+                        link.text(),
+                        link.color,
+                        link.logo,
+                        &link.url,
+                    );
+                }
+                let badge: String = services.skill_badge_url(link.text(), link.color, link.logo);
+                rel_me_anchor(
+                    &link.url,
+                    &format!(
+                        "<img src=\"{}\" alt=\"{}\" />",
+                        badge,
+                        html_escape(link.text())
+                    ),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        SocialDisplay::Icons => links
+            .iter()
+            .map(|link: &PlatformLink| social_icon_html(link, services))
+            .collect::<Vec<String>>()
+            .join(" "),
+        SocialDisplay::Text => links
+            .iter()
+            .map(|link: &PlatformLink| text_link(link, link.text()))
+            .collect::<Vec<String>>()
+            .join(" · "),
+        SocialDisplay::Table => {
+            let rows: Vec<String> = links
+                .iter()
+                .map(|link: &PlatformLink| {
+                    let handle: String = link
+                        .custom_label
+                        .map(str::to_string)
+                        .unwrap_or_else(|| social::display_handle(link.value));
+                    format!("| {} | {} |", link.label, text_link(link, &handle))
+                })
+                .collect();
+            format!("| Platform | Handle |\n|---|---|\n{}", rows.join("\n"))
+        }
+    }
+}
 
-    for (_category_name, skill_list) in &categories {
-        if let Some(skills_vec) = skill_list {
-            for skill in skills_vec {
-                let badge: String = match urls::skill_icon_lookup(skill) {
-                    Some((logo, color)) => urls::skill_badge_url(skill, color, logo),
-                    None => urls::skill_badge_url(skill, "333333", ""),
-                };
-                all_badges.push(format!("![{}]({})", skill, badge));
-            }
+/// A markdown link, or a `rel="me"` anchor when the link verifies the profile.
+fn text_link(link: &PlatformLink, text: &str) -> String {
+    if link.verify {
+        rel_me_anchor(&link.url, &html_escape(text))
+    } else {
+        format!("[{}]({})", text, link.url)
+    }
+}
+
+/// `<a rel="me" href="...">`: GitHub keeps `rel` on HTML anchors but renders
+/// markdown links without it, so verification links are always written as HTML.
+pub fn rel_me_anchor(url: &str, inner: &str) -> String {
+    format!("{}{}</a>", rel_me_open_tag(url), inner)
+}
+
+fn rel_me_open_tag(url: &str) -> String {
+    format!("<a rel=\"me\" href=\"{}\">", html_escape(url))
+}
+
+/// Problems that would stop `rel="me"` verification links from working: a
+/// target that isn't an https profile page, or a `github` link to another
+/// account than `username`, whose profile page is where the README (and so
+/// every verification link) is shown.
+pub fn verification_warnings(social: &Social, username: &str) -> Vec<String> {
+    let links: Vec<PlatformLink> = social_links(social);
+    let mut warnings: Vec<String> = Vec::new();
+    for link in links.iter().filter(|l: &&PlatformLink| l.verify) {
+        let name: String = match link.platform {
+            "custom" => format!("social.custom \"{}\"", link.label),
+            platform => format!("social.{}", platform),
+        };
+        if !link.url.starts_with("https://") {
+            warnings.push(format!(
+                "{} is verified with rel=\"me\" but links to {}; verification needs an https profile URL",
+                name, link.url
+            ));
+        }
+    }
+
+    let verifies: bool = links.iter().any(|l: &PlatformLink| l.verify);
+    let page: String = format!("https://github.com/{}", username);
+    if verifies
+        && let Some(github) = links
+            .iter()
+            .find(|l: &&PlatformLink| l.platform == "github")
+    {
+        let url: String = github
+            .url
+            .trim_end_matches('/')
+            .replacen("://www.", "://", 1);
+        if !url.eq_ignore_ascii_case(&page) {
+            warnings.push(format!(
+                "social.github links to {} but the README is shown on {}; verified links only count from the profile that shows them",
+                github.url, page
+            ));
         }
     }
+    warnings
+}
+
+/// A sized simple-icons logo linking to the profile, or a plain link without a logo.
+fn social_icon_html(link: &PlatformLink, services: &Providers) -> String {
+    if link.logo.is_empty() {
+        return text_link(link, link.text());
+    }
+    let caption: String = link
+        .custom_label
+        .map(|text: &str| format!(" {}", html_escape(text)))
+        .unwrap_or_default();
+    let icon: String = format!(
+        "<img src=\"{}\" alt=\"{}\" title=\"{}\" width=\"28\" height=\"28\" />{}",
+        services.urls.simple_icon_url(link.logo, link.color),
+        html_escape(link.label),
+        html_escape(link.text()),
+        caption
+    );
+    if link.verify {
+        rel_me_anchor(&link.url, &icon)
+    } else {
+        format!("<a href=\"{}\">{}</a>", html_escape(&link.url), icon)
+    }
+}
+
+/// Escape text for use inside HTML attributes and elements.
+pub(crate) fn html_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
 
-    if all_badges.is_empty() {
+/// Render the Skills / Tech Stack section as badges, tiers grouped by level,
+/// or a table of progress bars. Primary skills come first.
+pub fn render_skills(skills: &Skills, services: &Providers) -> String {
+    let mut entries: Vec<&SkillEntry> = skills
+        .lists()
+        .into_iter()
+        .flat_map(|(_, list)| list.iter().flatten())
+        .collect();
+    if entries.is_empty() {
         return String::new();
     }
+    entries.sort_by_key(|entry: &&SkillEntry| !entry.is_primary());
+
+    let content: String = match skills.display.unwrap_or_default() {
+        SkillDisplay::Badges => entries
+            .iter()
+            .map(|entry: &&SkillEntry| skill_badge(entry, skills, services))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        SkillDisplay::Tiers => {
+            let tiers: [(&str, Option<SkillLevel>); 4] = [
+                ("Expert", Some(SkillLevel::Expert)),
+                ("Comfortable", Some(SkillLevel::Comfortable)),
+                ("Learning", Some(SkillLevel::Learning)),
+                ("Other", None),
+            ];
+            tiers
+                .iter()
+                .filter_map(|(title, level)| {
+                    let badges: Vec<String> = entries
+                        .iter()
+                        .filter(|entry: &&&SkillEntry| entry.level() == *level)
+                        .map(|entry: &&SkillEntry| skill_badge(entry, skills, services))
+                        .collect();
+                    (!badges.is_empty()).then(|| format!("#### {}\n\n{}", title, badges.join("\n")))
+                })
+                .collect::<Vec<String>>()
+                .join("\n\n")
+        }
+        SkillDisplay::Bars => {
+            let rows: Vec<String> = entries
+                .iter()
+                .map(|entry: &&SkillEntry| {
+                    let proficiency: String =
+                        format!("`{}` {}", skill_bar(entry), skill_details(entry));
+                    format!("| {} | {} |", entry.name(), proficiency.trim_end())
+                })
+                .collect();
+            format!("| Skill | Proficiency |\n|---|---|\n{}", rows.join("\n"))
+        }
+    };
+
+    format!("### Tech Stack\n\n{}", content)
+}
+
+/// A skill badge; entries with `years` show them as the message, e.g. "Rust | 6y".
+fn skill_badge(entry: &SkillEntry, skills: &Skills, services: &Providers) -> String {
+    let name: &str = entry.name();
+    let (logo, color): (&str, &str) =
+        skill_icons::resolve(name, skills.custom_icons.as_ref()).unwrap_or(("", "333333"));
+    let badge: String = match entry.years() {
+        Some(years) => services.skill_message_badge_url(name, &format!("{}y", years), color, logo),
+        None => services.skill_badge_url(name, color, logo),
+    };
+    format!("![{}]({})", name, badge)
+}
+
+/// Ten-cell bar filled by level, or by years (capped at ten) when no level is set.
+fn skill_bar(entry: &SkillEntry) -> String {
+    let filled: usize = match (entry.level(), entry.years()) {
+        (Some(SkillLevel::Expert), _) => 10,
+        (Some(SkillLevel::Comfortable), _) => 6,
+        (Some(SkillLevel::Learning), _) => 3,
+        (None, Some(years)) => (years as usize).min(10),
+        (None, None) => 0,
+    };
+    format!(
+        "{}{}",
+        "\u{2588}".repeat(filled),
+        "\u{2591}".repeat(10 - filled)
+    )
+}
 
-    format!("### Tech Stack\n\n{}", all_badges.join("\n"))
+/// "Expert, 6 years", or whichever of the two is set.
+fn skill_details(entry: &SkillEntry) -> String {
+    let level: Option<&str> = entry.level().map(|level: SkillLevel| match level {
+        SkillLevel::Expert => "Expert",
+        SkillLevel::Comfortable => "Comfortable",
+        SkillLevel::Learning => "Learning",
+    });
+    let years: Option<String> = entry.years().map(|years: u32| match years {
+        1 => "1 year".to_string(),
+        n => format!("{} years", n),
+    });
+    [level.map(str::to_string), years]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
 /// Render the GitHub Stats section.
-pub fn render_stats(stats: &Stats, meta: &Meta) -> String {
+pub fn render_stats(stats: &Stats, meta: &Meta, services: &Providers) -> String {
     let theme: &str = stats.theme.as_deref().unwrap_or("default");
     let hide_border: bool = stats.hide_border.unwrap_or(false);
+    let colors: CardColors = stats.colors.clone().unwrap_or_default();
+    let card = Card {
+        username: &meta.username,
+        theme,
+        hide_border,
+        colors: &colors,
+    };
     let mut cards: Vec<String> = Vec::new();
 
     if stats.stats_card.unwrap_or(false) {
-        let url: String = urls::github_stats_url(&meta.username, theme, true, hide_border);
+        let url: String = services.stats_card_url(&card, &stats.card.clone().unwrap_or_default());
         cards.push(format!(
             "![{}'s GitHub stats]({})",
             meta.name.as_deref().unwrap_or(&meta.username),
@@ -183,27 +459,34 @@ pub fn render_stats(stats: &Stats, meta: &Meta) -> String {
     if stats.top_langs.unwrap_or(false) {
         let layout: &str = stats.top_langs_layout.as_deref().unwrap_or("compact");
         let count: u32 = stats.top_langs_count.unwrap_or(8);
-        let url: String = urls::top_langs_url(&meta.username, layout, count, theme, hide_border);
+        let url: String = services.language_card_url(
+            &card,
+            layout,
+            count,
+            &stats.langs.clone().unwrap_or_default(),
+        );
         cards.push(format!("![Top Langs]({})", url));
     }
 
     if stats.streak.unwrap_or(false) {
-        let url: String = urls::streak_stats_url(&meta.username, theme, hide_border);
+        let url: String = services.streak_url(&card);
         cards.push(format!("![GitHub Streak]({})", url));
     }
 
     if stats.contributor_stats.unwrap_or(false) {
-        let url: String = urls::contributor_stats_url(&meta.username, theme, hide_border);
+        let url: String = services
+            .urls
+            .contributor_stats_url(&meta.username, theme, hide_border);
         cards.push(format!("![Contributor Stats]({})", url));
     }
 
     if stats.trophies.unwrap_or(false) {
-        let url: String = urls::trophies_url(&meta.username, theme);
+        let url: String = services.urls.trophies_url(&meta.username, theme);
         cards.push(format!("![Trophies]({})", url));
     }
 
     if stats.profile_views.unwrap_or(false) {
-        let url: String = urls::profile_views_url(&meta.username);
+        let url: String = services.views_url(&meta.username);
         cards.push(format!("![Profile Views]({})", url));
     }
 
@@ -214,10 +497,18 @@ pub fn render_stats(stats: &Stats, meta: &Meta) -> String {
     format!("### GitHub Stats\n\n{}", cards.join("\n\n"))
 }
 
-/// Render the Featured Projects section.
-pub fn render_projects(projects: &Projects, meta: &Meta, theme: &str, hide_border: bool) -> String {
-    let repos: &[String] = match &projects.repos {
-        Some(r) if !r.is_empty() => r,
+/// Render the Featured Projects section. Pin cards share the stats cards'
+/// `colors`.
+pub fn render_projects(
+    projects: &Projects,
+    meta: &Meta,
+    theme: &str,
+    hide_border: bool,
+    colors: &CardColors,
+    services: &Providers,
+) -> String {
+    let featured: &[FeaturedProject] = match &projects.featured {
+        Some(f) if !f.is_empty() => f,
         _ => return String::new(),
     };
 
@@ -226,23 +517,36 @@ pub fn render_projects(projects: &Projects, meta: &Meta, theme: &str, hide_borde
         .as_ref()
         .unwrap_or(&ProjectDisplay::PinCards);
 
-    let items: Vec<String> = repos
+    let items: Vec<String> = featured
         .iter()
-        .map(|repo_str: &String| {
-            let (owner, repo): (&str, &str) = repo_str
+        .map(|project: &FeaturedProject| {
+            let (owner, repo): (&str, &str) = project
+                .repo
                 .split_once('/')
-                .unwrap_or((&meta.username, repo_str));
+                .unwrap_or((&meta.username, &project.repo));
 
             match display {
                 ProjectDisplay::PinCards => {
-                    let url: String = urls::pin_card_url(owner, repo, theme, hide_border);
+                    let card = Card {
+                        username: owner,
+                        theme,
+                        hide_border,
+                        colors,
+                    };
+                    let url: String = services.pin_card_url(&card, repo);
                     format!(
                         "<a href=\"https://github.com/{}/{}\">\n  <img align=\"center\" src=\"{}\" />\n</a>",
                         owner, repo, url
                     )
                 }
                 ProjectDisplay::MarkdownTable => {
-                    format!("| [{}](https://github.com/{}/{}) | |", repo, owner, repo)
+                    format!(
+                        "| [{}](https://github.com/{}/{}) | {} |",
+                        repo,
+                        owner,
+                        repo,
+                        project.description.as_deref().unwrap_or_default()
+                    )
                 }
             }
         })
@@ -301,13 +605,13 @@ pub fn render_blog(blog: &Blog) -> String {
 }
 
 /// Render the Dynamic / Real-time section.
-pub fn render_dynamic(dynamic: &Dynamic) -> String {
+pub fn render_dynamic(dynamic: &Dynamic, services: &Providers) -> String {
     let mut items: Vec<String> = Vec::new();
 
     if let Some(uid) = &dynamic.spotify_uid {
         items.push(format!(
             "### Spotify\n\n{}",
-            urls::spotify_badge_markdown(uid)
+            services.urls.spotify_badge_markdown(uid)
         ));
     }
 
@@ -334,26 +638,32 @@ pub fn render_dynamic(dynamic: &Dynamic) -> String {
 }
 
 /// Render the Sponsors section.
-pub fn render_sponsors(sponsors: &Sponsors, meta: &Meta) -> String {
+pub fn render_sponsors(sponsors: &Sponsors, meta: &Meta, services: &Providers) -> String {
     let mut items: Vec<String> = Vec::new();
 
     if sponsors.github_sponsors.unwrap_or(false) {
         items.push(format!(
-            "[![Sponsor](https://img.shields.io/badge/Sponsor-EA4AAA?style=for-the-badge&logo=githubsponsors&logoColor=white)](https://github.com/sponsors/{})",
+            "[![Sponsor]({})](https://github.com/sponsors/{})",
+            services.skill_badge_url("Sponsor", "EA4AAA", "githubsponsors"),
             meta.username
         ));
     }
 
     if let Some(kofi) = &sponsors.kofi {
         items.push(format!(
-            "[![Ko-fi](https://img.shields.io/badge/Ko--fi-FF5E5B?style=for-the-badge&logo=kofi&logoColor=white)]({})",
+            "[![Ko-fi]({})]({})",
+            services.skill_badge_url("Ko-fi", "FF5E5B", "kofi"),
             kofi
         ));
     }
 
     if let Some(bmac) = &sponsors.buy_me_a_coffee {
         items.push(format!(
-            "[![Buy Me a Coffee](https://img.shields.io/badge/Buy_Me_A_Coffee-FFDD00?style=for-the-badge&logo=buymeacoffee&logoColor=black)]({})",
+            "[![Buy Me a Coffee]({})]({})",
+            services.badge_url(&Badge {
+                logo_color: "black",
+                ..Badge::new("Buy Me A Coffee", "FFDD00", "buymeacoffee")
+            }),
             bmac
         ));
     }
@@ -365,17 +675,147 @@ pub fn render_sponsors(sponsors: &Sponsors, meta: &Meta) -> String {
     format!("### Support\n\n{}", items.join("\n"))
 }
 
-/// Render the Extras section.
-pub fn render_extras(extras: &Extras) -> String {
+/// Render an organization's mission statement.
+pub fn render_mission(org: &Organization) -> String {
+    match &org.mission {
+        Some(mission) => format!("### Our Mission\n\n{}", mission),
+        None => String::new(),
+    }
+}
+
+/// Render the "how to contribute" section of an organization profile.
+pub fn render_contributing(org: &Organization) -> String {
     let mut items: Vec<String> = Vec::new();
 
-    if let Some(pgp) = &extras.pgp_fingerprint {
+    if let Some(text) = &org.contributing {
+        items.push(text.clone());
+    }
+    if let Some(url) = &org.contributing_url {
         items.push(format!(
-            "![PGP](https://img.shields.io/badge/PGP-{}-333333?style=flat-square&logo=gnuprivacyguard&logoColor=white)",
-            pgp.replace(' ', "%20")
+            "Read our [contributing guide]({}) to get started.",
+            url
+        ));
+    }
+
+    if items.is_empty() {
+        return String::new();
+    }
+
+    format!("### Contributing\n\n{}", items.join("\n\n"))
+}
+
+/// Render community links followed by the organization's social links.
+pub fn render_community(
+    org: Option<&Organization>,
+    social: Option<&Social>,
+    services: &Providers,
+) -> String {
+    let mut items: Vec<String> = Vec::new();
+
+    if let Some(links) = org.and_then(|o| o.community.as_ref()) {
+        for link in links {
+            items.push(format!("- [{}]({})", link.label, link.url));
+        }
+    }
+
+    let badges: String = social
+        .map(|s: &Social| social_links_block(s, services))
+        .unwrap_or_default();
+
+    let mut blocks: Vec<String> = Vec::new();
+    if !items.is_empty() {
+        blocks.push(items.join("\n"));
+    }
+    if !badges.is_empty() {
+        blocks.push(badges);
+    }
+
+    if blocks.is_empty() {
+        return String::new();
+    }
+
+    format!("### Community\n\n{}", blocks.join("\n\n"))
+}
+
+/// Render stats for an organization using badges that accept org accounts.
+///
+/// The github-readme-stats, streak and trophy cards only support user
+/// accounts, so `stats_card` maps to follower and star badges instead.
+pub fn render_org_stats(stats: &Stats, meta: &Meta, services: &Providers) -> String {
+    let mut cards: Vec<String> = Vec::new();
+
+    if stats.stats_card.unwrap_or(false) {
+        cards.push(format!(
+            "![Followers]({})",
+            services.urls.followers_badge_url(&meta.username)
+        ));
+        cards.push(format!(
+            "![Stars]({})",
+            services.urls.account_stars_badge_url(&meta.username)
         ));
     }
 
+    if stats.profile_views.unwrap_or(false) {
+        let url: String = services.views_url(&meta.username);
+        cards.push(format!("![Profile Views]({})", url));
+    }
+
+    if cards.is_empty() {
+        return String::new();
+    }
+
+    format!("### Organization Stats\n\n{}", cards.join("\n"))
+}
+
+/// Render the members grid of an organization profile.
+pub fn render_members(org: &Organization) -> String {
+    let members: &[String] = match &org.members {
+        Some(m) if !m.is_empty() => m,
+        _ => return String::new(),
+    };
+    let columns: usize = org.members_columns.unwrap_or(6).max(1) as usize;
+    let width: usize = 100 / columns;
+
+    let cells: Vec<String> = members
+        .iter()
+        .map(|username: &String| {
+            format!(
+                "<td align=\"center\" width=\"{}%\">\n<a href=\"https://github.com/{}\"><img src=\"{}\" width=\"{}\" alt=\"{}\" /><br /><sub><b>{}</b></sub></a>\n</td>",
+                width,
+                username,
+                urls::avatar_url(username, MEMBER_AVATAR_SIZE),
+                MEMBER_AVATAR_SIZE,
+                username,
+                username
+            )
+        })
+        .collect();
+
+    format!("### Members\n\n{}", grid(&cells, columns))
+}
+
+/// Lay out cells in an HTML table, `columns` per row.
+pub(crate) fn grid(cells: &[String], columns: usize) -> String {
+    let rows: Vec<String> = cells
+        .chunks(columns)
+        .map(|row: &[String]| format!("<tr>\n{}\n</tr>", row.join("\n")))
+        .collect();
+    format!("<table>\n{}\n</table>", rows.join("\n"))
+}
+
+/// Render the Extras section.
+pub fn render_extras(extras: &Extras, services: &Providers) -> String {
+    let mut items: Vec<String> = Vec::new();
+
+    if let Some(pgp) = &extras.pgp_fingerprint {
+        let badge: String = services.badge_url(&Badge {
+            message: Some(pgp),
+            style: "flat-square",
+            ..Badge::new("PGP", "333333", "gnuprivacyguard")
+        });
+        items.push(format!("![PGP]({})", badge));
+    }
+
     // Gaming profiles
     let mut gaming: Vec<String> = Vec::new();
     if let Some(xbox) = &extras.xbox {
@@ -426,11 +866,14 @@ pub fn render_extras(extras: &Extras) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     fn test_meta() -> Meta {
         Meta {
             username: "alice".to_string(),
             name: Some("Alice".to_string()),
+            kind: None,
+            schema_version: None,
         }
     }
 
@@ -444,7 +887,7 @@ mod tests {
             typing_color: Some("f75c7e".to_string()),
             tagline: None,
         };
-        let result: String = render_header(&header, &test_meta());
+        let result: String = render_header(&header, &test_meta(), &Providers::default());
         assert!(result.contains("<p align=\"center\">"));
         assert!(result.contains("<img src=\"https://readme-typing-svg.demolab.com/"));
         assert!(result.contains("Hello;World"));
@@ -461,7 +904,7 @@ mod tests {
             typing_color: None,
             tagline: Some("Rust developer".to_string()),
         };
-        let result: String = render_header(&header, &test_meta());
+        let result: String = render_header(&header, &test_meta(), &Providers::default());
         assert!(result.contains("## Hey! I'm Alice"));
         assert!(result.contains("Rust developer"));
     }
@@ -476,7 +919,7 @@ mod tests {
             typing_color: None,
             tagline: None,
         };
-        let result: String = render_header(&header, &test_meta());
+        let result: String = render_header(&header, &test_meta(), &Providers::default());
         assert!(result.contains("https://example.com/banner.png"));
         assert!(result.contains("<p align=\"center\">"));
     }
@@ -491,7 +934,7 @@ mod tests {
             typing_color: None,
             tagline: None,
         };
-        let result: String = render_header(&header, &test_meta());
+        let result: String = render_header(&header, &test_meta(), &Providers::default());
         assert!(result.contains("# Hi there, I'm Alice"));
     }
 
@@ -538,34 +981,173 @@ mod tests {
     #[test]
     fn test_render_social_badges() {
         let social = Social {
-            twitter: Some("https://twitter.com/alice".to_string()),
-            linkedin: Some("https://linkedin.com/in/alice".to_string()),
+            twitter: Some("https://twitter.com/alice".into()),
+            linkedin: Some("https://linkedin.com/in/alice".into()),
             ..Social::default()
         };
-        let result: String = render_social(&social);
+        let result: String = render_social(&social, &Providers::default());
         assert!(result.contains("### Connect with me"));
         assert!(result.contains("Twitter"));
-        assert!(result.contains("https://twitter.com/alice"));
+        assert!(result.contains("https://x.com/alice"));
         assert!(result.contains("LinkedIn"));
         assert!(result.contains("https://linkedin.com/in/alice"));
         assert!(result.contains("for-the-badge"));
     }
 
+    #[test]
+    fn test_render_social_new_and_custom_platforms() {
+        let social = Social {
+            github: Some("https://github.com/alice".into()),
+            codeberg: Some("https://codeberg.org/alice".into()),
+            custom: Some(vec![
+                CustomSocial {
+                    label: "Lobsters".to_string(),
+                    url: "https://lobste.rs/~alice".to_string(),
+                    logo: Some("lobsters".to_string()),
+                    color: Some("AC130D".to_string()),
+                    verify: None,
+                },
+                CustomSocial {
+                    label: "Guestbook".to_string(),
+                    url: "https://alice.dev/guestbook".to_string(),
+                    logo: None,
+                    color: None,
+                    verify: None,
+                },
+            ]),
+            ..Social::default()
+        };
+        let result: String = render_social(&social, &Providers::default());
+        assert!(result.contains("[![Codeberg](https://img.shields.io/badge/Codeberg-2185D0?style=for-the-badge&logo=codeberg&logoColor=white)](https://codeberg.org/alice)"));
+        assert!(result.contains("[![Lobsters](https://img.shields.io/badge/Lobsters-AC130D?style=for-the-badge&logo=lobsters&logoColor=white)](https://lobste.rs/~alice)"));
+        assert!(result.contains("Guestbook-333333?style=for-the-badge&logo=&"));
+        // Custom links come after every built-in platform.
+        assert!(result.find("Codeberg").unwrap() < result.find("Lobsters").unwrap());
+    }
+
+    fn labelled_social(display: SocialDisplay) -> Social {
+        Social {
+            twitter: Some(SocialEntry::Detailed(DetailedSocial {
+                url: "@alice".to_string(),
+                label: Some("@alice".to_string()),
+                verify: None,
+            })),
+            mastodon: Some("@alice@hachyderm.io".into()),
+            display: Some(display),
+            ..Social::default()
+        }
+    }
+
+    #[test]
+    fn test_render_social_badges_custom_label() {
+        let result: String = render_social(
+            &labelled_social(SocialDisplay::Badges),
+            &Providers::default(),
+        );
+        assert!(result.contains("[![@alice](https://img.shields.io/badge/@alice-000000?style=for-the-badge&logo=x&logoColor=white)](https://x.com/alice)"));
+        assert!(result.contains("<a rel=\"me\" href=\"https://hachyderm.io/@alice\"><img src=\"https://img.shields.io/badge/Mastodon-6364FF?style=for-the-badge&logo=mastodon&logoColor=white\" alt=\"Mastodon\" /></a>"));
+    }
+
+    #[test]
+    fn test_render_social_icons() {
+        let result: String = render_social(
+            &labelled_social(SocialDisplay::Icons),
+            &Providers::default(),
+        );
+        assert_eq!(
+            result,
+            "### Connect with me\n\n\
+             <a href=\"https://x.com/alice\"><img src=\"https://cdn.simpleicons.org/x/000000\" alt=\"Twitter\" title=\"@alice\" width=\"28\" height=\"28\" /> @alice</a> \
+             <a rel=\"me\" href=\"https://hachyderm.io/@alice\"><img src=\"https://cdn.simpleicons.org/mastodon/6364FF\" alt=\"Mastodon\" title=\"Mastodon\" width=\"28\" height=\"28\" /></a>"
+        );
+    }
+
+    #[test]
+    fn test_render_social_text() {
+        let result: String =
+            render_social(&labelled_social(SocialDisplay::Text), &Providers::default());
+        assert_eq!(
+            result,
+            "### Connect with me\n\n[@alice](https://x.com/alice) · <a rel=\"me\" href=\"https://hachyderm.io/@alice\">Mastodon</a>"
+        );
+    }
+
+    #[test]
+    fn test_render_social_table() {
+        let mut social: Social = labelled_social(SocialDisplay::Table);
+        social.github = Some("https://github.com/alice/".into());
+        let result: String = render_social(&social, &Providers::default());
+        assert_eq!(
+            result,
+            "### Connect with me\n\n| Platform | Handle |\n|---|---|\n\
+             | GitHub | [github.com/alice](https://github.com/alice/) |\n\
+             | Twitter | [@alice](https://x.com/alice) |\n\
+             | Mastodon | <a rel=\"me\" href=\"https://hachyderm.io/@alice\">@alice@hachyderm.io</a> |"
+        );
+    }
+
+    #[test]
+    fn test_verification_links() {
+        let mut social = Social {
+            mastodon: Some(SocialEntry::Detailed(DetailedSocial {
+                url: "https://hachyderm.io/@alice".to_string(),
+                label: None,
+                verify: Some(false),
+            })),
+            website: Some(SocialEntry::Detailed(DetailedSocial {
+                url: "alice.dev".to_string(),
+                label: None,
+                verify: Some(true),
+            })),
+            custom: Some(vec![CustomSocial {
+                label: "Pixelfed".to_string(),
+                url: "http://pixelfed.example/alice".to_string(),
+                logo: None,
+                color: None,
+                verify: Some(true),
+            }]),
+            ..Social::default()
+        };
+        let readme: String = render_social(&social, &Providers::default());
+        assert!(readme.contains("[![Mastodon]("));
+        assert!(readme.contains("<a rel=\"me\" href=\"https://alice.dev\"><img "));
+        assert_eq!(
+            verification_warnings(&social, "alice"),
+            vec![
+                "social.custom \"Pixelfed\" is verified with rel=\"me\" but links to http://pixelfed.example/alice; verification needs an https profile URL".to_string()
+            ]
+        );
+
+        social.custom = None;
+        social.github = Some("https://www.github.com/Alice/".into());
+        assert!(verification_warnings(&social, "alice").is_empty());
+        social.github = Some("bob".into());
+        assert_eq!(
+            verification_warnings(&social, "alice"),
+            vec![
+                "social.github links to https://github.com/bob but the README is shown on https://github.com/alice; verified links only count from the profile that shows them".to_string()
+            ]
+        );
+
+        social.website = None;
+        assert!(verification_warnings(&social, "alice").is_empty());
+    }
+
     #[test]
     fn test_render_social_empty() {
         let social = Social::default();
-        let result: String = render_social(&social);
+        let result: String = render_social(&social, &Providers::default());
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_render_skills_badges() {
         let skills = Skills {
-            languages: Some(vec!["Rust".to_string(), "Python".to_string()]),
-            tools: Some(vec!["Docker".to_string()]),
+            languages: Some(vec!["Rust".into(), "Python".into()]),
+            tools: Some(vec!["Docker".into()]),
             ..Skills::default()
         };
-        let result: String = render_skills(&skills);
+        let result: String = render_skills(&skills, &Providers::default());
         assert!(result.contains("### Tech Stack"));
         assert!(result.contains("![Rust]"));
         assert!(result.contains("![Python]"));
@@ -577,14 +1159,102 @@ mod tests {
     #[test]
     fn test_render_skills_unknown_tech() {
         let skills = Skills {
-            languages: Some(vec!["ObscureLang".to_string()]),
+            languages: Some(vec!["ObscureLang".into()]),
             ..Skills::default()
         };
-        let result: String = render_skills(&skills);
+        let result: String = render_skills(&skills, &Providers::default());
         assert!(result.contains("![ObscureLang]"));
         assert!(result.contains("333333")); // fallback color
     }
 
+    fn detailed(name: &str, level: Option<SkillLevel>, years: Option<u32>) -> SkillEntry {
+        SkillEntry::Detailed(DetailedSkill {
+            name: name.to_string(),
+            level,
+            years,
+            primary: None,
+        })
+    }
+
+    #[test]
+    fn test_render_skills_badge_messages_and_primary_first() {
+        let skills = Skills {
+            languages: Some(vec![
+                "Python".into(),
+                SkillEntry::Detailed(DetailedSkill {
+                    name: "Rust".to_string(),
+                    level: None,
+                    years: Some(6),
+                    primary: Some(true),
+                }),
+            ]),
+            ..Skills::default()
+        };
+        let result: String = render_skills(&skills, &Providers::default());
+        assert!(result.contains(
+            "![Rust](https://img.shields.io/badge/Rust-6y-000000?style=for-the-badge&logo=rust&logoColor=white)"
+        ));
+        assert!(result.find("![Rust]").unwrap() < result.find("![Python]").unwrap());
+    }
+
+    #[test]
+    fn test_render_skills_tiers() {
+        let skills = Skills {
+            languages: Some(vec![
+                detailed("Rust", Some(SkillLevel::Expert), None),
+                detailed("Zig", Some(SkillLevel::Learning), None),
+                "Go".into(),
+            ]),
+            display: Some(SkillDisplay::Tiers),
+            ..Skills::default()
+        };
+        let result: String = render_skills(&skills, &Providers::default());
+        assert!(result.contains("#### Expert\n\n![Rust]"));
+        assert!(result.contains("#### Learning\n\n![Zig]"));
+        assert!(result.contains("#### Other\n\n![Go]"));
+        assert!(!result.contains("#### Comfortable"));
+        assert!(result.find("Expert").unwrap() < result.find("Learning").unwrap());
+    }
+
+    #[test]
+    fn test_render_skills_bars() {
+        let skills = Skills {
+            languages: Some(vec![
+                detailed("Rust", Some(SkillLevel::Expert), Some(6)),
+                detailed("Go", None, Some(1)),
+                "C".into(),
+            ]),
+            display: Some(SkillDisplay::Bars),
+            ..Skills::default()
+        };
+        let result: String = render_skills(&skills, &Providers::default());
+        assert!(result.contains("| Skill | Proficiency |\n|---|---|\n"));
+        assert!(result.contains("| Rust | `\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}\u{2588}` Expert, 6 years |"));
+        assert!(result.contains("| Go | `\u{2588}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}` 1 year |"));
+        assert!(result.contains("| C | `\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}` |"));
+    }
+
+    #[test]
+    fn test_render_skills_custom_icons() {
+        let mut custom_icons: BTreeMap<String, CustomIcon> = BTreeMap::new();
+        custom_icons.insert(
+            "ObscureLang".to_string(),
+            CustomIcon {
+                slug: "obscure".to_string(),
+                color: Some("123456".to_string()),
+                aliases: None,
+            },
+        );
+        let skills = Skills {
+            languages: Some(vec!["ObscureLang".into(), "golang".into()]),
+            custom_icons: Some(custom_icons),
+            ..Skills::default()
+        };
+        let result: String = render_skills(&skills, &Providers::default());
+        assert!(result.contains("ObscureLang-123456?style=for-the-badge&logo=obscure"));
+        assert!(result.contains("golang-00ADD8?style=for-the-badge&logo=go&"));
+    }
+
     #[test]
     fn test_render_stats_cards() {
         let stats = Stats {
@@ -599,14 +1269,48 @@ mod tests {
             hide_border: Some(false),
             top_langs_layout: None,
             top_langs_count: None,
+            card: None,
+            langs: None,
+            colors: None,
         };
-        let result: String = render_stats(&stats, &test_meta());
+        let result: String = render_stats(&stats, &test_meta(), &Providers::default());
         assert!(result.contains("### GitHub Stats"));
         assert!(result.contains("Alice's GitHub stats"));
         assert!(result.contains("github-readme-stats.vercel.app"));
         assert!(result.contains("streak-stats.demolab.com"));
     }
 
+    #[test]
+    fn test_render_stats_card_options() {
+        let stats = Stats {
+            stats_card: Some(true),
+            top_langs: Some(true),
+            card: Some(StatsCardOptions {
+                hide: Some(vec![StatsRow::Contribs, StatsRow::Issues]),
+                show: Some(vec![StatsExtraRow::Reviews]),
+                custom_title: Some("Alice's stats".to_string()),
+                rank_icon: Some(RankIcon::Percentile),
+                cache_seconds: Some(86400),
+                ..StatsCardOptions::default()
+            }),
+            langs: Some(TopLangsOptions {
+                exclude_repo: Some(vec!["dotfiles".to_string()]),
+                hide: Some(vec!["C++".to_string(), "HTML".to_string()]),
+                size_weight: Some(0.5),
+                count_weight: Some(0.5),
+            }),
+            colors: Some(CardColors {
+                title: Some("FF0000".to_string()),
+                bg: Some("00000000".to_string()),
+                ..CardColors::default()
+            }),
+            ..Stats::default()
+        };
+        let result: String = render_stats(&stats, &test_meta(), &Providers::default());
+        assert!(result.contains("&show_icons=true&hide_border=false&hide=contribs,issues&show=reviews&custom_title=Alice's%20stats&rank_icon=percentile&cache_seconds=86400&title_color=FF0000&bg_color=00000000)"));
+        assert!(result.contains("&langs_count=8&theme=default&hide_border=false&exclude_repo=dotfiles&hide=C%2B%2B,HTML&size_weight=0.5&count_weight=0.5&title_color=FF0000&bg_color=00000000)"));
+    }
+
     #[test]
     fn test_render_stats_empty() {
         let stats = Stats {
@@ -621,21 +1325,37 @@ mod tests {
             hide_border: None,
             top_langs_layout: None,
             top_langs_count: None,
+            card: None,
+            langs: None,
+            colors: None,
         };
-        let result: String = render_stats(&stats, &test_meta());
+        let result: String = render_stats(&stats, &test_meta(), &Providers::default());
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_render_featured_projects() {
         let projects = Projects {
-            repos: Some(vec![
-                "alice/cool-cli".to_string(),
-                "alice/other-lib".to_string(),
+            featured: Some(vec![
+                FeaturedProject {
+                    repo: "alice/cool-cli".to_string(),
+                    description: None,
+                },
+                FeaturedProject {
+                    repo: "alice/other-lib".to_string(),
+                    description: None,
+                },
             ]),
             display: Some(ProjectDisplay::PinCards),
         };
-        let result: String = render_projects(&projects, &test_meta(), "tokyonight", false);
+        let result: String = render_projects(
+            &projects,
+            &test_meta(),
+            "tokyonight",
+            false,
+            &CardColors::default(),
+            &Providers::default(),
+        );
         assert!(result.contains("### Featured Projects"));
         assert!(result.contains("https://github.com/alice/cool-cli"));
         assert!(result.contains("github-readme-stats.vercel.app/api/pin/"));
@@ -644,12 +1364,22 @@ mod tests {
     #[test]
     fn test_render_featured_projects_table() {
         let projects = Projects {
-            repos: Some(vec!["alice/cool-cli".to_string()]),
+            featured: Some(vec![FeaturedProject {
+                repo: "alice/cool-cli".to_string(),
+                description: Some("A cool CLI".to_string()),
+            }]),
             display: Some(ProjectDisplay::MarkdownTable),
         };
-        let result: String = render_projects(&projects, &test_meta(), "tokyonight", false);
+        let result: String = render_projects(
+            &projects,
+            &test_meta(),
+            "tokyonight",
+            false,
+            &CardColors::default(),
+            &Providers::default(),
+        );
         assert!(result.contains("| Project | Description |"));
-        assert!(result.contains("cool-cli"));
+        assert!(result.contains("| [cool-cli](https://github.com/alice/cool-cli) | A cool CLI |"));
     }
 
     #[test]
@@ -695,7 +1425,7 @@ mod tests {
             kofi: Some("https://ko-fi.com/alice".to_string()),
             buy_me_a_coffee: None,
         };
-        let result: String = render_sponsors(&sponsors, &test_meta());
+        let result: String = render_sponsors(&sponsors, &test_meta(), &Providers::default());
         assert!(result.contains("### Support"));
         assert!(result.contains("github.com/sponsors/alice"));
         assert!(result.contains("ko-fi.com/alice"));
@@ -707,7 +1437,7 @@ mod tests {
             pgp_fingerprint: Some("ABCD1234".to_string()),
             ..Extras::default()
         };
-        let result: String = render_extras(&extras);
+        let result: String = render_extras(&extras, &Providers::default());
         assert!(result.contains("PGP"));
         assert!(result.contains("ABCD1234"));
     }
@@ -721,7 +1451,7 @@ mod tests {
             }]),
             ..Extras::default()
         };
-        let result: String = render_extras(&extras);
+        let result: String = render_extras(&extras, &Providers::default());
         assert!(result.contains("<details>"));
         assert!(result.contains("<summary>More info</summary>"));
         assert!(result.contains("Hidden details"));
@@ -734,17 +1464,17 @@ mod tests {
             custom_blocks: Some(vec!["Custom **markdown** here".to_string()]),
             ..Extras::default()
         };
-        let result: String = render_extras(&extras);
+        let result: String = render_extras(&extras, &Providers::default());
         assert!(result.contains("Custom **markdown** here"));
     }
 
     #[test]
     fn test_render_empty_sections_omitted() {
         let social = Social::default();
-        assert!(render_social(&social).is_empty());
+        assert!(render_social(&social, &Providers::default()).is_empty());
 
         let skills = Skills::default();
-        assert!(render_skills(&skills).is_empty());
+        assert!(render_skills(&skills, &Providers::default()).is_empty());
 
         let stats = Stats {
             stats_card: None,
@@ -758,17 +1488,30 @@ mod tests {
             hide_border: None,
             top_langs_layout: None,
             top_langs_count: None,
+            card: None,
+            langs: None,
+            colors: None,
         };
-        assert!(render_stats(&stats, &test_meta()).is_empty());
+        assert!(render_stats(&stats, &test_meta(), &Providers::default()).is_empty());
 
         let projects = Projects::default();
-        assert!(render_projects(&projects, &test_meta(), "default", false).is_empty());
+        assert!(
+            render_projects(
+                &projects,
+                &test_meta(),
+                "default",
+                false,
+                &CardColors::default(),
+                &Providers::default()
+            )
+            .is_empty()
+        );
 
         let blog = Blog::default();
         assert!(render_blog(&blog).is_empty());
 
         let extras = Extras::default();
-        assert!(render_extras(&extras).is_empty());
+        assert!(render_extras(&extras, &Providers::default()).is_empty());
     }
 
     #[test]
@@ -779,7 +1522,7 @@ mod tests {
             github_activity: None,
             stackoverflow_uid: None,
         };
-        let result: String = render_dynamic(&dynamic);
+        let result: String = render_dynamic(&dynamic, &Providers::default());
         assert!(result.contains("### Spotify"));
         assert!(result.contains("uid=USER"));
     }
@@ -792,7 +1535,7 @@ mod tests {
             github_activity: None,
             stackoverflow_uid: None,
         };
-        let result: String = render_dynamic(&dynamic);
+        let result: String = render_dynamic(&dynamic, &Providers::default());
         assert!(result.contains("<!-- WAKATIME:START -->"));
     }
 
@@ -804,7 +1547,80 @@ mod tests {
             github_activity: Some(true),
             stackoverflow_uid: None,
         };
-        let result: String = render_dynamic(&dynamic);
+        let result: String = render_dynamic(&dynamic, &Providers::default());
         assert!(result.contains("<!--START_SECTION:activity-->"));
     }
+
+    fn test_org() -> Organization {
+        Organization {
+            mission: Some("Build open tools".to_string()),
+            contributing: Some("We welcome pull requests.".to_string()),
+            contributing_url: Some("https://acme.dev/contributing".to_string()),
+            community: Some(vec![CommunityLink {
+                label: "Forum".to_string(),
+                url: "https://forum.acme.dev".to_string(),
+            }]),
+            members: Some(vec![
+                "alice".to_string(),
+                "bob".to_string(),
+                "carol".to_string(),
+            ]),
+            members_columns: Some(2),
+        }
+    }
+
+    #[test]
+    fn test_render_organization_sections() {
+        let org: Organization = test_org();
+        assert_eq!(render_mission(&org), "### Our Mission\n\nBuild open tools");
+
+        let contributing: String = render_contributing(&org);
+        assert!(contributing.contains("We welcome pull requests."));
+        assert!(contributing.contains("[contributing guide](https://acme.dev/contributing)"));
+
+        let social = Social {
+            discord: Some("https://discord.gg/acme".into()),
+            ..Social::default()
+        };
+        let community: String = render_community(Some(&org), Some(&social), &Providers::default());
+        assert!(community.contains("- [Forum](https://forum.acme.dev)"));
+        assert!(community.contains("https://discord.gg/acme"));
+        assert!(render_community(None, None, &Providers::default()).is_empty());
+        assert!(render_mission(&Organization::default()).is_empty());
+    }
+
+    #[test]
+    fn test_render_members_grid() {
+        let result: String = render_members(&test_org());
+        assert!(result.starts_with("### Members"));
+        assert_eq!(result.matches("<tr>").count(), 2);
+        assert!(result.contains("https://github.com/bob.png?size=80"));
+        assert!(result.contains("width=\"50%\""));
+    }
+
+    #[test]
+    fn test_render_org_header() {
+        let meta = Meta {
+            username: "acme".to_string(),
+            name: None,
+            kind: Some(ProfileKind::Organization),
+            schema_version: None,
+        };
+        let header = Header {
+            style: Some(HeaderStyle::Wave),
+            banner_url: None,
+            typing_lines: None,
+            typing_font: None,
+            typing_color: None,
+            tagline: None,
+        };
+        assert!(
+            render_header(&header, &meta, &Providers::default()).starts_with("# Welcome to acme")
+        );
+    }
+
+    #[test]
+    fn test_html_escape() {
+        assert_eq!(html_escape("R&D <team>"), "R&amp;D &lt;team&gt;");
+    }
 }
@@ -0,0 +1,239 @@
+use crate::config::profile::{ProfileConfig, SkillEntry, Skills};
+use crate::config::team::{Team, TeamSort};
+use crate::render::sections::{self, PlatformLink, grid, html_escape};
+use crate::services::providers::Providers;
+use crate::services::skill_icons;
+use crate::services::urls::{self, Badge};
+
+const AVATAR_SIZE: u32 = 100;
+
+/// Render an organization README listing every team member in a grid.
+pub fn render_team(team: &Team) -> String {
+    let manifest = &team.manifest;
+    let columns: usize = manifest.columns.unwrap_or(4).max(1) as usize;
+    let top_skills: usize = manifest.top_skills.unwrap_or(5) as usize;
+    let sort: TeamSort = manifest.sort.unwrap_or(TeamSort::Manifest);
+
+    let mut out: Vec<String> = vec![format!("# {}", manifest.title)];
+    if let Some(description) = &manifest.description {
+        out.push(description.clone());
+    }
+
+    for (name, members) in &team.groups {
+        if members.is_empty() {
+            continue;
+        }
+        let mut members: Vec<&ProfileConfig> = members.iter().collect();
+        sort_members(&mut members, sort);
+
+        let cells: Vec<String> = members
+            .iter()
+            .map(|member: &&ProfileConfig| render_member(member, columns, top_skills))
+            .collect();
+        out.push(format!("## {}\n\n{}", name, grid(&cells, columns)));
+    }
+
+    out.join("\n\n")
+}
+
+fn sort_members(members: &mut [&ProfileConfig], sort: TeamSort) {
+    let key = |config: &ProfileConfig| -> String {
+        match sort {
+            TeamSort::Manifest => String::new(),
+            TeamSort::Name => display_name(config).to_lowercase(),
+            TeamSort::Username => config.meta.username.to_lowercase(),
+            TeamSort::Role => role(config).unwrap_or_default().to_lowercase(),
+        }
+    };
+    members.sort_by_key(|config: &&ProfileConfig| key(config));
+}
+
+fn display_name(config: &ProfileConfig) -> &str {
+    config.meta.name.as_deref().unwrap_or(&config.meta.username)
+}
+
+fn role(config: &ProfileConfig) -> Option<&str> {
+    config.about.as_ref().and_then(|a| a.role.as_deref())
+}
+
+/// Skill names across every list, primary skills first.
+fn skill_names(skills: &Skills) -> Vec<&str> {
+    let mut entries: Vec<&SkillEntry> = skills
+        .lists()
+        .into_iter()
+        .flat_map(|(_, list)| list.iter().flatten())
+        .collect();
+    entries.sort_by_key(|entry: &&SkillEntry| !entry.is_primary());
+    entries.into_iter().map(SkillEntry::name).collect()
+}
+
+/// One grid cell: avatar, name, role, top skills and social badges.
+fn render_member(config: &ProfileConfig, columns: usize, top_skills: usize) -> String {
+    let services = Providers::new(config.services.as_ref());
+    let username: &str = &config.meta.username;
+    let width: usize = 100 / columns;
+    let mut lines: Vec<String> = vec![
+        format!(
+            "<a href=\"https://github.com/{}\"><img src=\"{}\" width=\"{}\" alt=\"{}\" /></a>",
+            username,
+            urls::avatar_url(username, AVATAR_SIZE),
+            AVATAR_SIZE,
+            html_escape(username)
+        ),
+        format!("<b>{}</b>", html_escape(display_name(config))),
+    ];
+
+    if let Some(role) = role(config) {
+        lines.push(format!("<sub>{}</sub>", html_escape(role)));
+    }
+
+    if let Some(skills) = &config.skills {
+        let badges: Vec<String> = skill_names(skills)
+            .into_iter()
+            .take(top_skills)
+            .map(|skill: &str| {
+                let (logo, color): (&str, &str) =
+                    skill_icons::resolve(skill, skills.custom_icons.as_ref())
+                        .unwrap_or(("", "333333"));
+                format!(
+                    "<img src=\"{}\" alt=\"{}\" />",
+                    services.badge_url(&Badge {
+                        style: "flat-square",
+                        ..Badge::new(skill, color, logo)
+                    }),
+                    html_escape(skill)
+                )
+            })
+            .collect();
+        if !badges.is_empty() {
+            lines.push(badges.join(" "));
+        }
+    }
+
+    if let Some(social) = &config.social {
+        let links: Vec<String> = sections::social_links(social)
+            .iter()
+            .map(|link: &PlatformLink| {
+                format!(
+                    "<a href=\"{}\"><img src=\"{}\" alt=\"{}\" /></a>",
+                    html_escape(&link.url),
+                    services.badge_url(&Badge {
+                        style: "flat-square",
+                        ..Badge::new(link.label, link.color, link.logo)
+                    }),
+                    html_escape(link.label)
+                )
+            })
+            .collect();
+        if !links.is_empty() {
+            lines.push(links.join(" "));
+        }
+    }
+
+    format!(
+        "<td align=\"center\" valign=\"top\" width=\"{}%\">\n{}\n</td>",
+        width,
+        lines.join("\n<br />\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::profile::{About, Meta, Social};
+    use crate::config::team::{TeamGroup, TeamManifest};
+
+    fn member(username: &str, name: &str, role: &str) -> ProfileConfig {
+        ProfileConfig {
+            meta: Meta {
+                username: username.to_string(),
+                name: Some(name.to_string()),
+                kind: None,
+                schema_version: None,
+            },
+            about: Some(About {
+                role: Some(role.to_string()),
+                company: None,
+                current_work: None,
+                learning: None,
+                reach_me: None,
+                fun_fact: None,
+                pronouns: None,
+                location: None,
+                timezone: None,
+            }),
+            ..ProfileConfig::default()
+        }
+    }
+
+    fn team(sort: Option<TeamSort>, columns: Option<u32>) -> Team {
+        let mut carol: ProfileConfig = member("carol", "Carol", "SRE");
+        carol.skills = Some(Skills {
+            languages: Some(vec!["Rust".into(), "Go".into()]),
+            tools: Some(vec!["Docker".into()]),
+            ..Skills::default()
+        });
+        carol.social = Some(Social {
+            twitter: Some("https://twitter.com/carol".into()),
+            ..Social::default()
+        });
+
+        Team {
+            manifest: TeamManifest {
+                title: "Acme Engineering".to_string(),
+                description: Some("The people behind Acme.".to_string()),
+                columns,
+                sort,
+                top_skills: Some(2),
+                groups: vec![TeamGroup {
+                    name: "Platform".to_string(),
+                    members: Vec::new(),
+                }],
+            },
+            groups: vec![
+                (
+                    "Platform".to_string(),
+                    vec![carol, member("alice", "Alice", "Backend Engineer")],
+                ),
+                ("Empty".to_string(), Vec::new()),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_render_team_page() {
+        let result: String = render_team(&team(None, None));
+        assert!(result.starts_with("# Acme Engineering\n\nThe people behind Acme."));
+        assert!(result.contains("## Platform"));
+        assert!(!result.contains("## Empty"));
+        assert!(result.contains("<img src=\"https://github.com/carol.png?size=100\""));
+        assert!(result.contains("<b>Carol</b>"));
+        assert!(result.contains("<sub>SRE</sub>"));
+        assert!(result.contains("alt=\"Rust\""));
+        assert!(result.contains("alt=\"Go\""));
+        // Only the top two skills are shown
+        assert!(!result.contains("alt=\"Docker\""));
+        assert!(result.contains("<a href=\"https://x.com/carol\">"));
+        assert!(result.contains("width=\"25%\""));
+    }
+
+    #[test]
+    fn test_render_team_sorted_by_name() {
+        let result: String = render_team(&team(Some(TeamSort::Name), None));
+        let alice: usize = result.find("<b>Alice</b>").unwrap();
+        let carol: usize = result.find("<b>Carol</b>").unwrap();
+        assert!(alice < carol);
+
+        let result: String = render_team(&team(None, None));
+        let alice: usize = result.find("<b>Alice</b>").unwrap();
+        let carol: usize = result.find("<b>Carol</b>").unwrap();
+        assert!(carol < alice);
+    }
+
+    #[test]
+    fn test_render_team_grid_rows() {
+        let result: String = render_team(&team(None, Some(1)));
+        assert_eq!(result.matches("<tr>").count(), 2);
+        assert!(result.contains("width=\"100%\""));
+    }
+}
@@ -13,6 +13,10 @@ pub enum Section {
     Dynamic,
     Sponsors,
     Extras,
+    Mission,
+    Contributing,
+    Community,
+    Members,
 }
 
 /// Returns the ordered list of sections for a given template.
@@ -54,6 +58,24 @@ pub fn sections_for_template(template: &Template) -> Vec<Section> {
     }
 }
 
+/// Returns the ordered list of sections for an organization profile.
+pub fn sections_for_organization(template: &Template) -> Vec<Section> {
+    match template {
+        Template::Minimal => vec![Section::Header, Section::Mission, Section::Community],
+        Template::Full | Template::DeveloperCard | Template::MultiColumn => vec![
+            Section::Header,
+            Section::Mission,
+            Section::Projects,
+            Section::Contributing,
+            Section::Community,
+            Section::Stats,
+            Section::Sponsors,
+            Section::Members,
+            Section::Extras,
+        ],
+    }
+}
+
 /// Whether to use centered alignment for a template.
 pub fn is_centered(template: &Template) -> bool {
     matches!(template, Template::DeveloperCard)
@@ -108,6 +130,18 @@ mod tests {
         assert!(is_centered(&Template::DeveloperCard));
     }
 
+    #[test]
+    fn test_organization_sections() {
+        let sections: Vec<Section> = sections_for_organization(&Template::Full);
+        assert_eq!(sections[1], Section::Mission);
+        assert!(sections.contains(&Section::Members));
+        assert!(!sections.contains(&Section::About));
+        assert_eq!(
+            sections_for_organization(&Template::Minimal),
+            vec![Section::Header, Section::Mission, Section::Community]
+        );
+    }
+
     #[test]
     fn test_multi_column_template() {
         assert!(is_multi_column(&Template::MultiColumn));
@@ -0,0 +1,105 @@
+//! Problems in a loaded config that do not stop it from rendering. `render`,
+//! `render --batch` and `validate` all report these, so they are collected
+//! in one place.
+
+use crate::config::profile::ProfileConfig;
+use crate::render::sections;
+use crate::services::providers::Registry;
+use crate::services::skill_icons::{self, UnknownSkill};
+use crate::services::social;
+
+/// Warnings about one config, grouped so `validate` can count each kind.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigWarnings {
+    /// Skills with no icon.
+    pub skills: Vec<String>,
+    /// `[services]` provider names that nothing is registered under.
+    pub providers: Vec<String>,
+    /// Social links on the wrong host, or verified links that will not verify.
+    pub social: Vec<String>,
+}
+
+impl ConfigWarnings {
+    /// Check `config`, looking up `[services]` providers in `registry`.
+    pub fn check(config: &ProfileConfig, registry: &Registry) -> Self {
+        let skills: Vec<String> = config
+            .skills
+            .as_ref()
+            .map(|skills| {
+                skill_icons::unknown_skills(skills)
+                    .iter()
+                    .map(|skill: &UnknownSkill| skill.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let providers: Vec<String> = config
+            .services
+            .as_ref()
+            .map(|services| registry.provider_warnings(services))
+            .unwrap_or_default();
+        let social: Vec<String> = config
+            .social
+            .as_ref()
+            .map(|links| {
+                let mut warnings: Vec<String> = social::social_warnings(links);
+                warnings.extend(sections::verification_warnings(
+                    links,
+                    &config.meta.username,
+                ));
+                warnings
+            })
+            .unwrap_or_default();
+        ConfigWarnings {
+            skills,
+            providers,
+            social,
+        }
+    }
+
+    /// Every warning, skills first.
+    pub fn all(&self) -> impl Iterator<Item = &String> {
+        self.skills
+            .iter()
+            .chain(&self.providers)
+            .chain(&self.social)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::profile::{Meta, Services, SkillEntry, Skills, Social, SocialEntry};
+
+    #[test]
+    fn test_check_collects_each_kind() {
+        let config = ProfileConfig {
+            meta: Meta {
+                username: "alice".to_string(),
+                ..Meta::default()
+            },
+            skills: Some(Skills {
+                languages: Some(vec![SkillEntry::Name("Rustlang-ish".to_string())]),
+                ..Skills::default()
+            }),
+            services: Some(Services {
+                views: Some("hits".to_string()),
+                ..Services::default()
+            }),
+            social: Some(Social {
+                twitter: Some(SocialEntry::Url("https://example.com/alice".to_string())),
+                ..Social::default()
+            }),
+            ..ProfileConfig::default()
+        };
+        let warnings = ConfigWarnings::check(&config, &Registry::default());
+        assert_eq!(warnings.skills.len(), 1);
+        assert_eq!(warnings.providers.len(), 1);
+        assert_eq!(warnings.social.len(), 1);
+        assert_eq!(warnings.all().count(), 3);
+
+        assert_eq!(
+            ConfigWarnings::check(&ProfileConfig::default(), &Registry::default()),
+            ConfigWarnings::default()
+        );
+    }
+}
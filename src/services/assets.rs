@@ -0,0 +1,546 @@
+//! Local SVG files for `render --assets-dir`, so a README can show its badges
+//! and header without loading images from third-party services.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+
+use crate::services::providers::BadgeProvider;
+use crate::services::urls::{Badge, ServiceUrls};
+
+/// SVG files collected while rendering, referenced from the README by a
+/// relative path and written out afterwards with [`LocalAssets::write_to`].
+#[derive(Debug, Default)]
+pub struct LocalAssets {
+    /// How the README refers to the assets directory, e.g. `assets`.
+    prefix: String,
+    /// File name -> SVG.
+    files: Mutex<BTreeMap<String, String>>,
+    /// simple-icons SVGs to draw badge logos from, as `<slug>.svg`.
+    logos: Option<PathBuf>,
+    /// Logo slugs with no file in `logos`.
+    missing_logos: Mutex<BTreeSet<String>>,
+}
+
+/// Lists the files the last run wrote, so the next one can remove those it
+/// no longer needs without touching anything else in the directory.
+const MANIFEST: &str = ".gh-profile-gen-assets";
+
+impl LocalAssets {
+    /// `prefix` is the assets directory relative to the README.
+    pub fn new(prefix: &str) -> Self {
+        LocalAssets {
+            prefix: prefix.trim_end_matches('/').to_string(),
+            ..LocalAssets::default()
+        }
+    }
+
+    /// Draw badge logos from the simple-icons SVGs in `dir`, e.g. the
+    /// `icons` directory of the simple-icons package.
+    pub fn with_logos(mut self, dir: &Path) -> Self {
+        self.logos = Some(dir.to_path_buf());
+        self
+    }
+
+    /// Keep `svg` as `<stem>.svg`, or `<stem>-2.svg` and so on if another
+    /// image already took that name, and return its path for the README.
+    pub fn add(&self, stem: &str, svg: String) -> String {
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        let mut name: String = format!("{}.svg", stem);
+        let mut n: usize = 2;
+        while let Some(existing) = files.get(&name) {
+            if *existing == svg {
+                break;
+            }
+            name = format!("{}-{}.svg", stem, n);
+            n += 1;
+        }
+        files.insert(name.clone(), svg);
+        if self.prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", self.prefix, name)
+        }
+    }
+
+    /// A still image of the typing header, saved as `header.svg`.
+    pub fn typing_header(&self, lines: &[&str], font: &str, color: &str) -> String {
+        self.add("header", typing_header_svg(lines, font, color))
+    }
+
+    /// File names collected so far, sorted.
+    pub fn file_names(&self) -> Vec<String> {
+        let files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        files.keys().cloned().collect()
+    }
+
+    /// Logos that badges were drawn without because the logos directory has
+    /// no file for them, sorted.
+    pub fn missing_logos(&self) -> Vec<String> {
+        let missing = self.missing_logos.lock().unwrap_or_else(|e| e.into_inner());
+        missing.iter().cloned().collect()
+    }
+
+    /// Write every collected file into `dir`, creating it if needed, and
+    /// remove the files an earlier run wrote there that are no longer used.
+    pub fn write_to(&self, dir: &Path) -> Result<usize> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("could not create {}", dir.display()))?;
+        let files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        let manifest: PathBuf = dir.join(MANIFEST);
+        let previous: String = std::fs::read_to_string(&manifest).unwrap_or_default();
+        for name in previous.lines() {
+            // Only plain file names; never follow a path out of `dir`.
+            if files.contains_key(name) || name.is_empty() || name.contains(['/', '\\']) {
+                continue;
+            }
+            let path = dir.join(name);
+            match std::fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(e).with_context(|| format!("could not remove {}", path.display()));
+                }
+            }
+        }
+        for (name, svg) in files.iter() {
+            let path = dir.join(name);
+            std::fs::write(&path, svg)
+                .with_context(|| format!("could not write {}", path.display()))?;
+        }
+        let names: String = files
+            .keys()
+            .map(|name: &String| format!("{}\n", name))
+            .collect();
+        std::fs::write(&manifest, names)
+            .with_context(|| format!("could not write {}", manifest.display()))?;
+        Ok(files.len())
+    }
+
+    /// The path data of `slug`'s simple-icons logo, if a logos directory was
+    /// given and has it.
+    fn logo_path(&self, slug: &str) -> Option<String> {
+        let dir: &Path = self.logos.as_deref()?;
+        if slug.is_empty() {
+            return None;
+        }
+        // Slugs are plain names; anything else cannot be a simple-icons file.
+        let found: Option<String> = if slug.chars().all(|c: char| c.is_ascii_alphanumeric()) {
+            std::fs::read_to_string(dir.join(format!("{}.svg", slug)))
+                .ok()
+                .and_then(|svg: String| svg_path_data(&svg))
+        } else {
+            None
+        };
+        if found.is_none() {
+            let mut missing = self.missing_logos.lock().unwrap_or_else(|e| e.into_inner());
+            missing.insert(slug.to_string());
+        }
+        found
+    }
+}
+
+impl BadgeProvider for LocalAssets {
+    fn badge_url(&self, _urls: &ServiceUrls, badge: &Badge) -> String {
+        let stem: String = match badge.message {
+            Some(message) => slug(&format!("{} {}", badge.label, message)),
+            None => slug(badge.label),
+        };
+        // Labels with no letters or digits, e.g. "***", still need a name.
+        let stem: &str = if stem.is_empty() { "badge" } else { &stem };
+        let logo: Option<String> = self.logo_path(badge.logo);
+        self.add(stem, badge_svg(badge, logo.as_deref()))
+    }
+}
+
+/// Hosts that images in `readme` are still loaded from, e.g. stats cards
+/// that need live data and have no local form.
+pub fn external_image_hosts(readme: &str) -> Vec<String> {
+    let mut hosts: Vec<String> = Vec::new();
+    let markdown_images = readme.split("![").skip(1).filter_map(|rest: &str| {
+        let (_, target) = rest.split_once("](")?;
+        target.split(')').next()
+    });
+    let html_images = readme
+        .split("<img src=\"")
+        .skip(1)
+        .filter_map(|rest: &str| rest.split('"').next());
+    for url in markdown_images.chain(html_images) {
+        let Some(rest) = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+        else {
+            continue;
+        };
+        let host: String = rest.split('/').next().unwrap_or(rest).to_string();
+        if !hosts.contains(&host) {
+            hosts.push(host);
+        }
+    }
+    hosts
+}
+
+/// A badge drawn like shields.io's: the label on grey and the message in
+/// `badge.color`, or the label alone in `badge.color`. `logo` is the path
+/// data of a 24x24 simple-icons logo to draw before the label.
+pub fn badge_svg(badge: &Badge, logo: Option<&str>) -> String {
+    let tall: bool = badge.style == "for-the-badge";
+    let (height, font_size, padding): (u32, u32, u32) =
+        if tall { (28, 10, 12) } else { (20, 11, 6) };
+    let color: String = hex_color(badge.color).unwrap_or_else(|| "007EC6".to_string());
+    let text = |s: &str| {
+        if tall {
+            s.to_uppercase()
+        } else {
+            s.to_string()
+        }
+    };
+    let width_of = |s: &str| text_width(s, font_size, tall) + 2 * padding;
+
+    let mut parts: Vec<(String, String)> = Vec::new();
+    match badge.message {
+        Some(message) => {
+            parts.push((text(badge.label), "555555".to_string()));
+            parts.push((text(message), color));
+        }
+        None => parts.push((text(badge.label), color)),
+    }
+
+    // The logo sits at the start of the first part, which widens to fit it.
+    const LOGO_SIZE: u32 = 14;
+    const LOGO_GAP: u32 = 4;
+    let logo_fill: String = match hex_color(badge.logo_color) {
+        Some(hex) => format!("#{}", hex),
+        None if !badge.logo_color.is_empty()
+            && badge
+                .logo_color
+                .chars()
+                .all(|c: char| c.is_ascii_alphabetic()) =>
+        {
+            badge.logo_color.to_string()
+        }
+        None => "white".to_string(),
+    };
+
+    let mut x: u32 = 0;
+    let mut shapes: Vec<String> = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+    for (i, (part, color)) in parts.iter().enumerate() {
+        let logo: Option<&str> = logo.filter(|_| i == 0);
+        let indent: u32 = if logo.is_some() {
+            LOGO_SIZE + LOGO_GAP
+        } else {
+            0
+        };
+        let width: u32 = width_of(part) + indent;
+        shapes.push(format!(
+            "<rect x=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{}\"/>",
+            x, width, height, color
+        ));
+        if let Some(path) = logo {
+            shapes.push(format!(
+                "<svg x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" viewBox=\"0 0 24 24\"><path fill=\"{}\" d=\"{}\"/></svg>",
+                x + padding,
+                (height - LOGO_SIZE) / 2,
+                xml_escape(&logo_fill),
+                xml_escape(path),
+                s = LOGO_SIZE,
+            ));
+        }
+        labels.push(format!(
+            "<text x=\"{}\" y=\"{}\" fill=\"#{}\">{}</text>",
+            x + indent + (width - indent) / 2,
+            height / 2 + font_size * 7 / 20,
+            text_color(color),
+            xml_escape(part)
+        ));
+        x += width;
+    }
+
+    let title: String = match badge.message {
+        Some(message) => format!("{}: {}", badge.label, message),
+        None => badge.label.to_string(),
+    };
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" role=\"img\" aria-label=\"{t}\">\
+         <title>{t}</title>{shapes}\
+         <g text-anchor=\"middle\" font-family=\"Verdana,Geneva,DejaVu Sans,sans-serif\" font-size=\"{fs}\"{weight}>{labels}</g>\
+         </svg>\n",
+        w = x,
+        h = height,
+        t = xml_escape(&title),
+        shapes = shapes.join(""),
+        fs = font_size,
+        weight = if tall {
+            " font-weight=\"bold\" letter-spacing=\"1\""
+        } else {
+            ""
+        },
+        labels = labels.join(""),
+    )
+}
+
+/// The typing header as a still image: every line, centered, with a cursor
+/// after the last one.
+pub fn typing_header_svg(lines: &[&str], font: &str, color: &str) -> String {
+    const WIDTH: u32 = 440;
+    const LINE_HEIGHT: u32 = 32;
+    let height: u32 = LINE_HEIGHT * lines.len().max(1) as u32 + 13;
+    let rows: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line): (usize, &&str)| {
+            let cursor: &str = if i + 1 == lines.len() {
+                "<tspan fill-opacity=\"0.7\">|</tspan>"
+            } else {
+                ""
+            };
+            format!(
+                "<text x=\"{}\" y=\"{}\">{}{}</text>",
+                WIDTH / 2,
+                LINE_HEIGHT * (i as u32 + 1),
+                xml_escape(line),
+                cursor
+            )
+        })
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" role=\"img\" aria-label=\"{t}\">\
+         <title>{t}</title>\
+         <g text-anchor=\"middle\" font-family=\"'{font}', monospace\" font-size=\"22\" fill=\"#{color}\">{rows}</g>\
+         </svg>\n",
+        w = WIDTH,
+        h = height,
+        t = xml_escape(&lines.join(" ")),
+        font = xml_escape(font),
+        color = hex_color(color).unwrap_or_else(|| "000000".to_string()),
+        rows = rows.join(""),
+    )
+}
+
+/// `color` as six hex digits without `#`, or `None` if it is not a 3- or
+/// 6-digit hex color.
+fn hex_color(color: &str) -> Option<String> {
+    let hex: &str = color.trim().trim_start_matches('#');
+    if !hex.chars().all(|c: char| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        6 => Some(hex.to_string()),
+        3 => Some(hex.chars().flat_map(|c: char| [c, c]).collect()),
+        _ => None,
+    }
+}
+
+/// The `d` attribute of the first `<path>` in a simple-icons SVG.
+fn svg_path_data(svg: &str) -> Option<String> {
+    let (_, path) = svg.split_once("<path")?;
+    let (_, rest) = path.split_once(" d=\"")?;
+    let (d, _) = rest.split_once('"')?;
+    Some(d.to_string()).filter(|d: &String| !d.is_empty())
+}
+
+/// Rough rendered width of `text`; Verdana averages about 0.6em per glyph.
+fn text_width(text: &str, font_size: u32, spaced: bool) -> u32 {
+    let glyphs: u32 = text.chars().count() as u32;
+    let spacing: u32 = if spaced { glyphs } else { 0 };
+    (glyphs * font_size * 6).div_ceil(10) + spacing
+}
+
+/// Dark text on light backgrounds, white text otherwise.
+fn text_color(background: &str) -> &'static str {
+    let channel = |i: usize| {
+        background
+            .get(i..i + 2)
+            .and_then(|hex: &str| u8::from_str_radix(hex, 16).ok())
+            .map_or(0.0, f64::from)
+    };
+    let luminance: f64 = 0.299 * channel(0) + 0.587 * channel(2) + 0.114 * channel(4);
+    if luminance > 160.0 {
+        "333333"
+    } else {
+        "ffffff"
+    }
+}
+
+/// `C++ 6y` -> `c-plus-plus-6y`, for file names.
+fn slug(text: &str) -> String {
+    let text: String = text
+        .to_lowercase()
+        .replace('+', "-plus-")
+        .replace('#', "-sharp-");
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part: &&str| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_badge_svg() {
+        let svg: String = badge_svg(&Badge::new("Rust", "000000", "rust"), None);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("height=\"28\""));
+        assert!(svg.contains("fill=\"#000000\""));
+        assert!(svg.contains(">RUST</text>"));
+
+        let svg: String = badge_svg(
+            &Badge {
+                message: Some("A&B"),
+                style: "flat-square",
+                ..Badge::new("PGP", "FFDD00", "gnuprivacyguard")
+            },
+            None,
+        );
+        assert!(svg.contains("height=\"20\""));
+        assert!(svg.contains("fill=\"#555555\""));
+        assert!(svg.contains("fill=\"#333333\">A&amp;B</text>"));
+        assert!(svg.contains("<title>PGP: A&amp;B</title>"));
+    }
+
+    #[test]
+    fn test_assets_are_named_and_deduplicated() {
+        let assets = LocalAssets::new("assets/");
+        let urls = ServiceUrls::default();
+        assert_eq!(
+            assets.badge_url(&urls, &Badge::new("C++", "00599C", "cplusplus")),
+            "assets/c-plus-plus.svg"
+        );
+        assert_eq!(
+            assets.badge_url(&urls, &Badge::new("C++", "00599C", "cplusplus")),
+            "assets/c-plus-plus.svg"
+        );
+        assert_eq!(
+            assets.badge_url(&urls, &Badge::new("C++", "123456", "cplusplus")),
+            "assets/c-plus-plus-2.svg"
+        );
+        assert_eq!(
+            assets.typing_header(&["Hi <there>"], "Fira Code", "f75c7e"),
+            "assets/header.svg"
+        );
+        assert_eq!(
+            assets.file_names(),
+            vec!["c-plus-plus-2.svg", "c-plus-plus.svg", "header.svg"]
+        );
+    }
+
+    #[test]
+    fn test_badge_svg_checks_colors() {
+        let svg: String = badge_svg(&Badge::new("Rust", "000\" onload=\"x", "rust"), None);
+        assert!(svg.contains("fill=\"#007EC6\""));
+        assert!(!svg.contains("onload"));
+        assert!(badge_svg(&Badge::new("Rust", "#fa0", "rust"), None).contains("fill=\"#ffaa00\""));
+
+        let svg: String = typing_header_svg(&["Hi"], "Rock'n'Roll", "red\"/><script");
+        assert!(svg.contains("font-family=\"'Rock&apos;n&apos;Roll', monospace\""));
+        assert!(svg.contains("fill=\"#000000\""));
+        assert!(!svg.contains("<script"));
+    }
+
+    #[test]
+    fn test_badge_svg_with_logo() {
+        let svg: String = badge_svg(&Badge::new("Rust", "000000", "rust"), Some("M0 0h24v24H0z"));
+        assert!(svg.contains("viewBox=\"0 0 24 24\"><path fill=\"white\" d=\"M0 0h24v24H0z\"/>"));
+        assert!(svg.len() > badge_svg(&Badge::new("Rust", "000000", "rust"), None).len());
+        let svg: String = badge_svg(
+            &Badge {
+                logo_color: "F05032",
+                ..Badge::new("Git", "000000", "git")
+            },
+            Some("M1 1"),
+        );
+        assert!(svg.contains("<path fill=\"#F05032\" d=\"M1 1\"/>"));
+    }
+
+    #[test]
+    fn test_logos_are_read_from_the_logos_dir() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("rust.svg"),
+            "<svg role=\"img\" viewBox=\"0 0 24 24\" xmlns=\"http://www.w3.org/2000/svg\"><title>Rust</title><path d=\"M23.8 11.7z\"/></svg>",
+        )
+        .unwrap();
+        let assets = LocalAssets::new("assets").with_logos(dir.path());
+        let urls = ServiceUrls::default();
+        assets.badge_url(&urls, &Badge::new("Rust", "000000", "rust"));
+        assets.badge_url(&urls, &Badge::new("Go", "00ADD8", "go"));
+        assets.badge_url(&urls, &Badge::new("Up", "000000", "../rust"));
+        let files = assets.files.lock().unwrap();
+        assert!(files["rust.svg"].contains("d=\"M23.8 11.7z\""));
+        assert!(!files["go.svg"].contains("<path"));
+        assert!(!files["up.svg"].contains("<path"));
+        drop(files);
+        assert_eq!(assets.missing_logos(), vec!["../rust", "go"]);
+    }
+
+    #[test]
+    fn test_unnamed_badges_get_a_name() {
+        let assets = LocalAssets::new("");
+        let urls = ServiceUrls::default();
+        assert_eq!(
+            assets.badge_url(&urls, &Badge::new("***", "000000", "")),
+            "badge.svg"
+        );
+        assert_eq!(
+            assets.badge_url(&urls, &Badge::new("!!!", "000000", "")),
+            "badge-2.svg"
+        );
+    }
+
+    #[test]
+    fn test_write_to_removes_stale_assets() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("logo.svg"), "mine").unwrap();
+
+        let first = LocalAssets::new("assets");
+        first.typing_header(&["Hi"], "Fira Code", "f75c7e");
+        first.badge_url(&ServiceUrls::default(), &Badge::new("Go", "00ADD8", "go"));
+        assert_eq!(first.write_to(dir.path()).unwrap(), 2);
+        assert!(dir.path().join("go.svg").exists());
+
+        let second = LocalAssets::new("assets");
+        second.typing_header(&["Hello"], "Fira Code", "f75c7e");
+        assert_eq!(second.write_to(dir.path()).unwrap(), 1);
+        assert!(!dir.path().join("go.svg").exists());
+        assert!(dir.path().join("header.svg").exists());
+        // Files the tool did not write are left alone.
+        assert!(dir.path().join("logo.svg").exists());
+    }
+
+    #[test]
+    fn test_external_image_hosts() {
+        let readme = "[![GitHub](assets/github.svg)](https://github.com/alice)\n\
+                      ![Stats](https://stats.example.com/api?username=alice)\n\
+                      <img src=\"https://stats.example.com/api/pin/\" />\n\
+                      <img src=\"https://cdn.example.com/x.svg\" />";
+        assert_eq!(
+            external_image_hosts(readme),
+            vec!["stats.example.com", "cdn.example.com"]
+        );
+        assert!(external_image_hosts("![Rust](assets/rust.svg)").is_empty());
+    }
+
+    #[test]
+    fn test_typing_header_svg() {
+        let svg: String = typing_header_svg(&["Hello", "World"], "Fira Code", "f75c7e");
+        assert!(svg.contains("font-family=\"'Fira Code', monospace\""));
+        assert!(svg.contains("fill=\"#f75c7e\""));
+        assert!(svg.contains(">Hello</text>"));
+        assert!(svg.contains(">World<tspan"));
+        assert!(!svg.contains("https://"));
+    }
+}
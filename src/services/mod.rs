@@ -1 +1,5 @@
+pub mod assets;
+pub mod providers;
+pub mod skill_icons;
+pub mod social;
 pub mod urls;
@@ -0,0 +1,498 @@
+//! Pluggable card and badge providers. Each kind of image (stats card,
+//! language card, streak, pin card, badge, views counter) is drawn by a
+//! provider picked by name in `[services]`; the public services are the
+//! built-in defaults, and [`Registry::register`] adds more.
+
+use std::sync::Arc;
+
+use crate::config::profile::{Services, StatsCardOptions, TopLangsOptions};
+use crate::services::assets::LocalAssets;
+use crate::services::urls::{Badge, Card, ServiceUrls};
+
+/// Draws the GitHub stats card.
+pub trait StatsCardProvider: Send + Sync {
+    fn stats_card_url(&self, urls: &ServiceUrls, card: &Card, options: &StatsCardOptions)
+    -> String;
+}
+
+/// Draws the top languages card.
+pub trait LanguageCardProvider: Send + Sync {
+    fn language_card_url(
+        &self,
+        urls: &ServiceUrls,
+        card: &Card,
+        layout: &str,
+        count: u32,
+        options: &TopLangsOptions,
+    ) -> String;
+}
+
+/// Draws the contribution streak card.
+pub trait StreakProvider: Send + Sync {
+    fn streak_url(&self, urls: &ServiceUrls, card: &Card) -> String;
+}
+
+/// Draws a repository card; `card.username` is the repository owner.
+pub trait PinCardProvider: Send + Sync {
+    fn pin_card_url(&self, urls: &ServiceUrls, card: &Card, repo: &str) -> String;
+}
+
+/// Draws static badges for skills, social links, sponsors and the like.
+pub trait BadgeProvider: Send + Sync {
+    fn badge_url(&self, urls: &ServiceUrls, badge: &Badge) -> String;
+}
+
+/// Draws the profile views counter.
+pub trait ViewsProvider: Send + Sync {
+    fn views_url(&self, urls: &ServiceUrls, username: &str) -> String;
+}
+
+/// A provider of one kind, as registered under a name.
+#[derive(Clone)]
+pub enum Provider {
+    StatsCard(Arc<dyn StatsCardProvider>),
+    LanguageCard(Arc<dyn LanguageCardProvider>),
+    Streak(Arc<dyn StreakProvider>),
+    PinCard(Arc<dyn PinCardProvider>),
+    Badge(Arc<dyn BadgeProvider>),
+    Views(Arc<dyn ViewsProvider>),
+}
+
+impl Provider {
+    /// The `[services]` key that selects a provider of this kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Provider::StatsCard(_) => "stats_card",
+            Provider::LanguageCard(_) => "language_card",
+            Provider::Streak(_) => "streak",
+            Provider::PinCard(_) => "pin_card",
+            Provider::Badge(_) => "badge",
+            Provider::Views(_) => "views",
+        }
+    }
+}
+
+/// github-readme-stats: stats, top languages and pin cards.
+pub struct ReadmeStats;
+
+impl StatsCardProvider for ReadmeStats {
+    fn stats_card_url(
+        &self,
+        urls: &ServiceUrls,
+        card: &Card,
+        options: &StatsCardOptions,
+    ) -> String {
+        urls.github_stats_url(card, true, options)
+    }
+}
+
+impl LanguageCardProvider for ReadmeStats {
+    fn language_card_url(
+        &self,
+        urls: &ServiceUrls,
+        card: &Card,
+        layout: &str,
+        count: u32,
+        options: &TopLangsOptions,
+    ) -> String {
+        urls.top_langs_url(card, layout, count, options)
+    }
+}
+
+impl PinCardProvider for ReadmeStats {
+    fn pin_card_url(&self, urls: &ServiceUrls, card: &Card, repo: &str) -> String {
+        urls.pin_card_url(card, repo)
+    }
+}
+
+/// github-readme-streak-stats.
+pub struct StreakStats;
+
+impl StreakProvider for StreakStats {
+    fn streak_url(&self, urls: &ServiceUrls, card: &Card) -> String {
+        urls.streak_stats_url(card)
+    }
+}
+
+/// shields.io static badges.
+pub struct Shields;
+
+impl BadgeProvider for Shields {
+    fn badge_url(&self, urls: &ServiceUrls, badge: &Badge) -> String {
+        urls.shields_badge_url(badge)
+    }
+}
+
+/// badgen.net static badges.
+pub struct Badgen;
+
+impl BadgeProvider for Badgen {
+    fn badge_url(&self, urls: &ServiceUrls, badge: &Badge) -> String {
+        urls.badgen_badge_url(badge)
+    }
+}
+
+/// komarev profile views counter.
+pub struct ProfileViews;
+
+impl ViewsProvider for ProfileViews {
+    fn views_url(&self, urls: &ServiceUrls, username: &str) -> String {
+        urls.profile_views_url(username)
+    }
+}
+
+/// Providers selectable by name in `[services]`. The default registry holds
+/// the built-in public services; [`Registry::register`] adds more.
+#[derive(Clone)]
+pub struct Registry {
+    entries: Vec<(String, Provider)>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry {
+            entries: vec![
+                (
+                    "readme_stats".to_string(),
+                    Provider::StatsCard(Arc::new(ReadmeStats)),
+                ),
+                (
+                    "readme_stats".to_string(),
+                    Provider::LanguageCard(Arc::new(ReadmeStats)),
+                ),
+                (
+                    "readme_stats".to_string(),
+                    Provider::PinCard(Arc::new(ReadmeStats)),
+                ),
+                (
+                    "streak_stats".to_string(),
+                    Provider::Streak(Arc::new(StreakStats)),
+                ),
+                ("shields".to_string(), Provider::Badge(Arc::new(Shields))),
+                ("badgen".to_string(), Provider::Badge(Arc::new(Badgen))),
+                (
+                    "profile_views".to_string(),
+                    Provider::Views(Arc::new(ProfileViews)),
+                ),
+            ],
+        }
+    }
+}
+
+impl Registry {
+    /// Make `provider` selectable as `name` in `[services]`, e.g.
+    /// `registry.register("hits", Provider::Views(Arc::new(Hits)))` for
+    /// `views = "hits"`. Replaces any provider of the same kind already
+    /// registered as `name`.
+    pub fn register(&mut self, name: &str, provider: Provider) {
+        self.entries.retain(|(existing, p): &(String, Provider)| {
+            existing != name || p.kind() != provider.kind()
+        });
+        self.entries.push((name.to_string(), provider));
+    }
+
+    /// The provider of `kind` registered as `name`.
+    fn lookup(&self, kind: &str, name: &str) -> Option<Provider> {
+        self.entries
+            .iter()
+            .find(|(existing, p): &&(String, Provider)| existing == name && p.kind() == kind)
+            .map(|(_, p): &(String, Provider)| p.clone())
+    }
+
+    /// Names registered for `kind`, in registration order.
+    fn names(&self, kind: &str) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|(_, p): &&(String, Provider)| p.kind() == kind)
+            .map(|(name, _): &(String, Provider)| name.clone())
+            .collect()
+    }
+
+    /// Provider names in `[services]` that nothing is registered under; those
+    /// kinds fall back to their default provider.
+    pub fn provider_warnings(&self, services: &Services) -> Vec<String> {
+        selections(Some(services))
+            .into_iter()
+            .filter_map(|(kind, name)| {
+                let name: &str = name?;
+                self.lookup(kind, name).is_none().then(|| {
+                    format!(
+                        "services.{} = \"{}\" is not a known provider; expected {}",
+                        kind,
+                        name,
+                        self.names(kind).join(" or ")
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// The provider name chosen in `[services]` for each kind.
+fn selections(services: Option<&Services>) -> [(&'static str, Option<&str>); 6] {
+    let pick = |f: fn(&Services) -> &Option<String>| services.and_then(|s| f(s).as_deref());
+    [
+        ("stats_card", pick(|s| &s.stats_card)),
+        ("language_card", pick(|s| &s.language_card)),
+        ("streak", pick(|s| &s.streak)),
+        ("pin_card", pick(|s| &s.pin_card)),
+        ("badge", pick(|s| &s.badge)),
+        ("views", pick(|s| &s.views)),
+    ]
+}
+
+/// The providers and base URLs a profile renders with.
+#[derive(Clone)]
+pub struct Providers {
+    pub urls: ServiceUrls,
+    stats_card: Arc<dyn StatsCardProvider>,
+    language_card: Arc<dyn LanguageCardProvider>,
+    streak: Arc<dyn StreakProvider>,
+    pin_card: Arc<dyn PinCardProvider>,
+    badge: Arc<dyn BadgeProvider>,
+    views: Arc<dyn ViewsProvider>,
+    assets: Option<Arc<LocalAssets>>,
+}
+
+impl Default for Providers {
+    fn default() -> Self {
+        Providers::new(None)
+    }
+}
+
+impl Providers {
+    /// The built-in providers selected in `[services]`, or the defaults for
+    /// kinds that are unset or name an unknown provider.
+    pub fn new(services: Option<&Services>) -> Self {
+        Providers::with_registry(services, &Registry::default())
+    }
+
+    /// Like [`Providers::new`], but picking providers from `registry`.
+    pub fn with_registry(services: Option<&Services>, registry: &Registry) -> Self {
+        let mut providers = Providers {
+            urls: ServiceUrls::new(services),
+            stats_card: Arc::new(ReadmeStats),
+            language_card: Arc::new(ReadmeStats),
+            streak: Arc::new(StreakStats),
+            pin_card: Arc::new(ReadmeStats),
+            badge: Arc::new(Shields),
+            views: Arc::new(ProfileViews),
+            assets: None,
+        };
+        for (kind, name) in selections(services) {
+            match name.and_then(|name: &str| registry.lookup(kind, name)) {
+                Some(Provider::StatsCard(p)) => providers.stats_card = p,
+                Some(Provider::LanguageCard(p)) => providers.language_card = p,
+                Some(Provider::Streak(p)) => providers.streak = p,
+                Some(Provider::PinCard(p)) => providers.pin_card = p,
+                Some(Provider::Badge(p)) => providers.badge = p,
+                Some(Provider::Views(p)) => providers.views = p,
+                None => {}
+            }
+        }
+        providers
+    }
+
+    /// Draw badges and the typing header as local SVG files in `assets`
+    /// instead of loading them from a service.
+    pub fn with_assets(mut self, assets: Arc<LocalAssets>) -> Self {
+        self.badge = assets.clone();
+        self.assets = Some(assets);
+        self
+    }
+
+    /// The local SVG files being collected, if rendering with `--assets-dir`.
+    pub fn assets(&self) -> Option<&LocalAssets> {
+        self.assets.as_deref()
+    }
+
+    pub fn stats_card_url(&self, card: &Card, options: &StatsCardOptions) -> String {
+        self.stats_card.stats_card_url(&self.urls, card, options)
+    }
+
+    pub fn language_card_url(
+        &self,
+        card: &Card,
+        layout: &str,
+        count: u32,
+        options: &TopLangsOptions,
+    ) -> String {
+        self.language_card
+            .language_card_url(&self.urls, card, layout, count, options)
+    }
+
+    pub fn streak_url(&self, card: &Card) -> String {
+        self.streak.streak_url(&self.urls, card)
+    }
+
+    pub fn pin_card_url(&self, card: &Card, repo: &str) -> String {
+        self.pin_card.pin_card_url(&self.urls, card, repo)
+    }
+
+    pub fn badge_url(&self, badge: &Badge) -> String {
+        self.badge.badge_url(&self.urls, badge)
+    }
+
+    pub fn views_url(&self, username: &str) -> String {
+        self.views.views_url(&self.urls, username)
+    }
+
+    /// A skill badge in "for-the-badge" style.
+    pub fn skill_badge_url(&self, label: &str, color: &str, logo: &str) -> String {
+        self.badge_url(&Badge::new(label, color, logo))
+    }
+
+    /// A skill badge with a message, e.g. "Rust | 6y".
+    pub fn skill_message_badge_url(
+        &self,
+        label: &str,
+        message: &str,
+        color: &str,
+        logo: &str,
+    ) -> String {
+        self.badge_url(&Badge {
+            message: Some(message),
+            ..Badge::new(label, color, logo)
+        })
+    }
+
+    /// A social link badge, linked to `url`.
+    pub fn social_badge_markdown(&self, label: &str, color: &str, logo: &str, url: &str) -> String {
+        let badge: String = self.skill_badge_url(label, color, logo);
+        format!("[![{}]({})]({})", label, badge, url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::profile::CardColors;
+
+    struct Hits;
+
+    impl ViewsProvider for Hits {
+        fn views_url(&self, _urls: &ServiceUrls, username: &str) -> String {
+            format!("https://hits.example.com/{}.svg", username)
+        }
+    }
+
+    fn card(colors: &CardColors) -> Card<'_> {
+        Card {
+            username: "alice",
+            theme: "tokyonight",
+            hide_border: false,
+            colors,
+        }
+    }
+
+    #[test]
+    fn test_default_providers() {
+        let providers = Providers::default();
+        let colors = CardColors::default();
+        assert_eq!(
+            providers.streak_url(&card(&colors)),
+            "https://streak-stats.demolab.com/?user=alice&theme=tokyonight&hide_border=false"
+        );
+        assert_eq!(
+            providers.pin_card_url(&card(&colors), "repo"),
+            "https://github-readme-stats.vercel.app/api/pin/?username=alice&repo=repo&theme=tokyonight&hide_border=false"
+        );
+        assert_eq!(
+            providers.skill_badge_url("Rust", "000000", "rust"),
+            "https://img.shields.io/badge/Rust-000000?style=for-the-badge&logo=rust&logoColor=white"
+        );
+    }
+
+    #[test]
+    fn test_skill_message_badge_url() {
+        let url: String =
+            Providers::default().skill_message_badge_url("C++", "6y", "00599C", "cplusplus");
+        assert_eq!(
+            url,
+            "https://img.shields.io/badge/C++-6y-00599C?style=for-the-badge&logo=cplusplus&logoColor=white"
+        );
+    }
+
+    #[test]
+    fn test_social_badge_markdown() {
+        let md: String = Providers::default().social_badge_markdown(
+            "GitHub",
+            "181717",
+            "github",
+            "https://github.com/alice",
+        );
+        assert!(md.starts_with("[![GitHub](https://img.shields.io/badge/GitHub-181717"));
+        assert!(md.contains("style=for-the-badge"));
+        assert!(md.ends_with("](https://github.com/alice)"));
+    }
+
+    #[test]
+    fn test_select_badgen_by_name() {
+        let services = Services {
+            badge: Some("badgen".to_string()),
+            ..Services::default()
+        };
+        let providers = Providers::new(Some(&services));
+        assert_eq!(
+            providers.skill_badge_url("Rust", "000000", "rust"),
+            "https://badgen.net/badge/icon/Rust/000000?icon=rust&label"
+        );
+        assert_eq!(
+            providers.skill_message_badge_url("Rust", "6y", "000000", "rust"),
+            "https://badgen.net/badge/Rust/6y/000000?icon=rust"
+        );
+        assert!(Registry::default().provider_warnings(&services).is_empty());
+    }
+
+    #[test]
+    fn test_register_provider() {
+        let mut registry = Registry::default();
+        registry.register("hits", Provider::Views(Arc::new(Hits)));
+        let services = Services {
+            views: Some("hits".to_string()),
+            ..Services::default()
+        };
+        assert_eq!(
+            Providers::with_registry(Some(&services), &registry).views_url("alice"),
+            "https://hits.example.com/alice.svg"
+        );
+        assert!(registry.provider_warnings(&services).is_empty());
+        // Only the registry it was added to knows it.
+        assert_eq!(Registry::default().provider_warnings(&services).len(), 1);
+        assert!(
+            Providers::new(Some(&services))
+                .views_url("alice")
+                .starts_with("https://komarev.com/")
+        );
+        // Registered for views only, so it cannot draw badges.
+        let services = Services {
+            badge: Some("hits".to_string()),
+            ..Services::default()
+        };
+        assert!(
+            Providers::with_registry(Some(&services), &registry)
+                .skill_badge_url("Rust", "000000", "rust")
+                .starts_with("https://img.shields.io/")
+        );
+    }
+
+    #[test]
+    fn test_unknown_provider_warning() {
+        let services = Services {
+            streak: Some("streaks-r-us".to_string()),
+            ..Services::default()
+        };
+        assert_eq!(
+            Registry::default().provider_warnings(&services),
+            vec![
+                "services.streak = \"streaks-r-us\" is not a known provider; expected streak_stats"
+                    .to_string()
+            ]
+        );
+        assert!(
+            Providers::new(Some(&services))
+                .streak_url(&card(&CardColors::default()))
+                .starts_with("https://streak-stats.demolab.com/")
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+use crate::config::profile::{CustomIcon, Skills};
+
+/// The `[skills]` lists, in the order the catalog groups them.
+pub const CATEGORIES: &[&str] = &["languages", "frameworks", "tools", "databases", "cloud"];
+
+/// A built-in catalog entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillIcon {
+    /// Canonical spelling of the skill.
+    pub name: String,
+    /// simple-icons slug used as the badge logo.
+    pub slug: String,
+    /// Badge background as a hex color without `#`.
+    pub color: String,
+    /// The `[skills]` list the skill belongs in.
+    pub category: &'static str,
+    pub aliases: Vec<String>,
+}
+
+/// Skill icons shipped with the binary, indexed by lowercase name and alias.
+#[derive(Debug)]
+pub struct SkillCatalog {
+    entries: Vec<SkillIcon>,
+    index: HashMap<String, usize>,
+}
+
+#[derive(Deserialize)]
+struct RawEntry {
+    slug: String,
+    color: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+static CATALOG: LazyLock<SkillCatalog> = LazyLock::new(|| {
+    SkillCatalog::parse(include_str!("skill_icons.toml"))
+        .unwrap_or_else(|e: String| panic!("built-in skill icon catalog is invalid: {}", e))
+});
+
+/// The built-in catalog, parsed on first use.
+pub fn catalog() -> &'static SkillCatalog {
+    &CATALOG
+}
+
+impl SkillCatalog {
+    /// Parse catalog TOML: one table per category, mapping names to entries.
+    /// Names and aliases must be unique ignoring case.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let groups: BTreeMap<String, BTreeMap<String, RawEntry>> =
+            toml::from_str(source).map_err(|e: toml::de::Error| e.to_string())?;
+
+        let mut catalog = SkillCatalog {
+            entries: Vec::new(),
+            index: HashMap::new(),
+        };
+        for category in CATEGORIES {
+            let Some(group) = groups.get(*category) else {
+                continue;
+            };
+            for (name, raw) in group {
+                let position: usize = catalog.entries.len();
+                for key in std::iter::once(name).chain(&raw.aliases) {
+                    if let Some(existing) = catalog.index.insert(key.to_lowercase(), position) {
+                        return Err(format!(
+                            "\"{}\" is claimed by both {} and {}",
+                            key, catalog.entries[existing].name, name
+                        ));
+                    }
+                }
+                catalog.entries.push(SkillIcon {
+                    name: name.clone(),
+                    slug: raw.slug.clone(),
+                    color: raw.color.clone(),
+                    category,
+                    aliases: raw.aliases.clone(),
+                });
+            }
+        }
+        if let Some(unknown) = groups
+            .keys()
+            .find(|k: &&String| !CATEGORIES.contains(&k.as_str()))
+        {
+            return Err(format!("unknown category [{}]", unknown));
+        }
+        Ok(catalog)
+    }
+
+    /// Find a skill by name or alias, ignoring case.
+    pub fn get(&self, skill: &str) -> Option<&SkillIcon> {
+        self.index
+            .get(&skill.to_lowercase())
+            .map(|i: &usize| &self.entries[*i])
+    }
+
+    /// Every entry, grouped by category.
+    pub fn entries(&self) -> &[SkillIcon] {
+        &self.entries
+    }
+}
+
+/// Resolve a skill to (slug, color), preferring the user's `[skills.custom_icons]`
+/// over the built-in catalog. Custom icons without a color use the catalog's
+/// color for the same name, or neutral grey.
+pub fn resolve<'a>(
+    skill: &str,
+    custom: Option<&'a BTreeMap<String, CustomIcon>>,
+) -> Option<(&'a str, &'a str)> {
+    let builtin: Option<&'static SkillIcon> = catalog().get(skill);
+    let user: Option<&CustomIcon> = custom.and_then(|icons: &BTreeMap<String, CustomIcon>| {
+        icons
+            .iter()
+            .find_map(|(name, icon): (&String, &CustomIcon)| {
+                let matches: bool = name.eq_ignore_ascii_case(skill)
+                    || icon
+                        .aliases
+                        .iter()
+                        .flatten()
+                        .any(|alias: &String| alias.eq_ignore_ascii_case(skill));
+                matches.then_some(icon)
+            })
+    });
+
+    match user {
+        Some(icon) => {
+            let color: &str = icon
+                .color
+                .as_deref()
+                .or(builtin.map(|b: &SkillIcon| b.color.as_str()))
+                .unwrap_or("333333");
+            Some((icon.slug.as_str(), color))
+        }
+        None => builtin.map(|b: &SkillIcon| (b.slug.as_str(), b.color.as_str())),
+    }
+}
+
+/// Canonical spelling for a catalog name or alias, e.g. "k8s" -> "Kubernetes".
+pub fn canonical_name(skill: &str) -> Option<&'static str> {
+    catalog().get(skill).map(|b: &SkillIcon| b.name.as_str())
+}
+
+/// A skill that matches neither the catalog nor `[skills.custom_icons]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownSkill {
+    /// The list it appears in, e.g. `skills.databases`.
+    pub path: String,
+    pub name: String,
+    /// Nearest catalog names, closest first.
+    pub suggestions: Vec<&'static str>,
+}
+
+impl std::fmt::Display for UnknownSkill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown skill \"{}\" in {}", self.name, self.path)?;
+        if !self.suggestions.is_empty() {
+            write!(f, "; did you mean {}?", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Skills that would render as a logo-less grey badge, with the nearest catalog entries.
+pub fn unknown_skills(skills: &Skills) -> Vec<UnknownSkill> {
+    let mut unknown: Vec<UnknownSkill> = Vec::new();
+    for (key, list) in skills.lists() {
+        for entry in list.iter().flatten() {
+            let name: &str = entry.name();
+            if resolve(name, skills.custom_icons.as_ref()).is_none() {
+                unknown.push(UnknownSkill {
+                    path: format!("skills.{}", key),
+                    name: name.to_string(),
+                    suggestions: suggest(name),
+                });
+            }
+        }
+    }
+    unknown
+}
+
+/// Up to three catalog names within a few edits of `skill`, closest first.
+/// Aliases count as matches for their canonical name.
+pub fn suggest(skill: &str) -> Vec<&'static str> {
+    nearest(skill)
+        .into_iter()
+        .take(3)
+        .map(|(_, name): (usize, &'static str)| name)
+        .collect()
+}
+
+/// Catalog names within a fifth of the length of `skill`, with their
+/// distance, closest first. Names under five characters get no fuzzy matches,
+/// since one edit already turns "Io" into "Go" or "Just" into "Rust".
+fn nearest(skill: &str) -> Vec<(usize, &'static str)> {
+    let wanted: String = skill.trim().to_lowercase();
+    let threshold: usize = wanted.chars().count() / 5;
+    if threshold == 0 {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(usize, &'static str)> = Vec::new();
+    for entry in catalog().entries() {
+        let distance: usize = std::iter::once(&entry.name)
+            .chain(&entry.aliases)
+            .map(|key: &String| edit_distance(&wanted, &key.to_lowercase()))
+            .min()
+            .unwrap_or(usize::MAX);
+        if distance <= threshold {
+            scored.push((distance, entry.name.as_str()));
+        }
+    }
+    scored.sort();
+    scored
+}
+
+/// The spelling `--fix` should write for `skill`, if it differs from what's there:
+/// the canonical name when `skill` is a catalog name or alias up to case and
+/// whitespace, or else the one catalog name nearer than any other, e.g.
+/// "PostgresSQL" -> "PostgreSQL". Misspellings with several equally near
+/// matches are only suggested, and custom icon names are left as written.
+pub fn canonical_spelling(
+    skill: &str,
+    custom: Option<&BTreeMap<String, CustomIcon>>,
+) -> Option<&'static str> {
+    let known: bool = custom.is_some_and(|icons: &BTreeMap<String, CustomIcon>| {
+        icons.iter().any(|(name, icon): (&String, &CustomIcon)| {
+            name.eq_ignore_ascii_case(skill)
+                || icon
+                    .aliases
+                    .iter()
+                    .flatten()
+                    .any(|alias: &String| alias.eq_ignore_ascii_case(skill))
+        })
+    });
+    if known {
+        return None;
+    }
+
+    let collapsed: String = skill.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let canonical: &'static str = match canonical_name(&collapsed) {
+        Some(name) => name,
+        None => match nearest(&collapsed).as_slice() {
+            [(_, name)] => name,
+            [(best, name), (next, _), ..] if next > best => name,
+            _ => return None,
+        },
+    };
+    (canonical != skill).then_some(canonical)
+}
+
+/// A skill rewritten to its canonical spelling by [`fix_spellings`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellingFix {
+    pub path: String,
+    pub from: String,
+    pub to: &'static str,
+}
+
+impl std::fmt::Display for SpellingFix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: \"{}\" -> \"{}\"", self.path, self.from, self.to)
+    }
+}
+
+/// Rewrite the `[skills]` lists of a raw config table to canonical spellings,
+/// including the `name` of detailed entries. A plain name that becomes a
+/// duplicate of one already in its list is dropped.
+pub fn fix_spellings(
+    table: &mut toml::Table,
+    custom: Option<&BTreeMap<String, CustomIcon>>,
+) -> Vec<SpellingFix> {
+    let mut fixes: Vec<SpellingFix> = Vec::new();
+    let Some(skills) = table.get_mut("skills").and_then(toml::Value::as_table_mut) else {
+        return fixes;
+    };
+
+    for key in CATEGORIES {
+        let Some(list) = skills.get_mut(*key).and_then(toml::Value::as_array_mut) else {
+            continue;
+        };
+        let mut seen: Vec<String> = Vec::new();
+        list.retain_mut(|item: &mut toml::Value| {
+            let detailed: bool = item.is_table();
+            let slot: &mut toml::Value = match item {
+                toml::Value::Table(entry) => match entry.get_mut("name") {
+                    Some(name) => name,
+                    None => return true,
+                },
+                plain => plain,
+            };
+            let Some(name) = slot.as_str().map(str::to_string) else {
+                return true;
+            };
+            let spelled: String = match canonical_spelling(&name, custom) {
+                Some(canonical) => {
+                    fixes.push(SpellingFix {
+                        path: format!("skills.{}", key),
+                        from: name,
+                        to: canonical,
+                    });
+                    *slot = toml::Value::from(canonical);
+                    canonical.to_string()
+                }
+                None => name,
+            };
+            // Plain duplicates carry nothing worth keeping; detailed ones do.
+            if seen.contains(&spelled) && !detailed {
+                return false;
+            }
+            seen.push(spelled);
+            true
+        });
+    }
+    fixes
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution: usize = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_catalog_parses() {
+        let catalog: &SkillCatalog = catalog();
+        assert!(catalog.entries().len() > 200);
+        for entry in catalog.entries() {
+            assert_eq!(entry.color.len(), 6, "{} color", entry.name);
+            assert!(
+                entry.color.chars().all(|c: char| c.is_ascii_hexdigit()),
+                "{} color",
+                entry.name
+            );
+            assert!(!entry.slug.is_empty(), "{} slug", entry.name);
+        }
+    }
+
+    #[test]
+    fn test_aliases_resolve_to_canonical_names() {
+        assert_eq!(canonical_name("golang"), Some("Go"));
+        assert_eq!(canonical_name("K8s"), Some("Kubernetes"));
+        assert_eq!(canonical_name("tf"), Some("Terraform"));
+        assert_eq!(canonical_name("postgres"), Some("PostgreSQL"));
+        assert_eq!(canonical_name("rust"), Some("Rust"));
+        assert_eq!(canonical_name("no-such-skill"), None);
+        assert_eq!(resolve("golang", None), Some(("go", "00ADD8")));
+    }
+
+    #[test]
+    fn test_parse_rejects_duplicates_and_unknown_categories() {
+        let duplicate: &str = r#"
+[languages]
+"Go" = { slug = "go", color = "00ADD8", aliases = ["golang"] }
+[tools]
+"Golang" = { slug = "go", color = "00ADD8" }
+"#;
+        let err: String = SkillCatalog::parse(duplicate).unwrap_err();
+        assert!(err.contains("Go and Golang"), "{}", err);
+
+        let unknown: &str = "[editors]\nVim = { slug = \"vim\", color = \"019733\" }\n";
+        assert!(
+            SkillCatalog::parse(unknown)
+                .unwrap_err()
+                .contains("[editors]")
+        );
+    }
+
+    #[test]
+    fn test_custom_icons_take_precedence() {
+        let mut custom: BTreeMap<String, CustomIcon> = BTreeMap::new();
+        custom.insert(
+            "Ratatui".to_string(),
+            CustomIcon {
+                slug: "rust".to_string(),
+                color: Some("E65100".to_string()),
+                aliases: Some(vec!["tui-rs".to_string()]),
+            },
+        );
+        custom.insert(
+            "Docker".to_string(),
+            CustomIcon {
+                slug: "podman".to_string(),
+                ..CustomIcon::default()
+            },
+        );
+
+        assert_eq!(resolve("ratatui", Some(&custom)), Some(("rust", "E65100")));
+        assert_eq!(resolve("TUI-RS", Some(&custom)), Some(("rust", "E65100")));
+        // Without a color, an override keeps the catalog color.
+        assert_eq!(resolve("Docker", Some(&custom)), Some(("podman", "2496ED")));
+        assert_eq!(resolve("Rust", Some(&custom)), Some(("rust", "000000")));
+        assert_eq!(resolve("Unlisted", Some(&custom)), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "rust"), 4);
+        assert_eq!(edit_distance("rust", "rust"), 0);
+        assert_eq!(edit_distance("postgressql", "postgresql"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_unknown_skills_come_with_suggestions() {
+        let skills = Skills {
+            languages: Some(vec!["Typescript ".into(), "Rust".into()]),
+            databases: Some(vec!["PostgresSQL".into()]),
+            tools: Some(vec!["Zzyzx".into()]),
+            ..Skills::default()
+        };
+        let unknown: Vec<UnknownSkill> = unknown_skills(&skills);
+        let names: Vec<&str> = unknown
+            .iter()
+            .map(|u: &UnknownSkill| u.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Typescript ", "Zzyzx", "PostgresSQL"]);
+        assert_eq!(unknown[0].suggestions[0], "TypeScript");
+        assert!(unknown[1].suggestions.is_empty());
+        assert_eq!(
+            unknown[2].to_string(),
+            "unknown skill \"PostgresSQL\" in skills.databases; did you mean PostgreSQL?"
+        );
+    }
+
+    #[test]
+    fn test_short_names_get_no_fuzzy_suggestions() {
+        assert!(suggest("Io").is_empty());
+        assert!(suggest("Just").is_empty());
+        assert_eq!(suggest("Pythn"), vec!["Python"]);
+    }
+
+    #[test]
+    fn test_canonical_spelling() {
+        assert_eq!(canonical_spelling("Typescript ", None), Some("TypeScript"));
+        assert_eq!(
+            canonical_spelling("react  native", None),
+            Some("React Native")
+        );
+        assert_eq!(canonical_spelling("golang", None), Some("Go"));
+        assert_eq!(canonical_spelling("PostgresSQL", None), Some("PostgreSQL"));
+        assert_eq!(canonical_spelling("Pythn", None), Some("Python"));
+        // As near to Vite as to Vitest, so only suggested.
+        assert_eq!(suggest("Vitet"), vec!["Vite", "Vitest"]);
+        assert_eq!(canonical_spelling("Vitet", None), None);
+        assert_eq!(canonical_spelling("Io", None), None);
+        assert_eq!(canonical_spelling("Rust", None), None);
+        assert_eq!(canonical_spelling("Zzyzx", None), None);
+
+        let custom: BTreeMap<String, CustomIcon> = BTreeMap::from([(
+            "Rustt".to_string(),
+            CustomIcon {
+                slug: "rust".to_string(),
+                ..CustomIcon::default()
+            },
+        )]);
+        assert_eq!(canonical_spelling("rustt", Some(&custom)), None);
+    }
+
+    #[test]
+    fn test_fix_spellings_rewrites_and_dedupes() {
+        let mut table: toml::Table = toml::from_str(
+            "[skills]\nlanguages = [\"Go\", \"golang\", { name = \"Typescript \", years = 2 }]\ndatabases = [\"PostgresSQL\", \"Zzyzx\"]\n",
+        )
+        .unwrap();
+        let fixes: Vec<SpellingFix> = fix_spellings(&mut table, None);
+        assert_eq!(fixes.len(), 3);
+        assert_eq!(
+            fixes[0].to_string(),
+            "skills.languages: \"golang\" -> \"Go\""
+        );
+        assert_eq!(table["skills"]["languages"][0].as_str(), Some("Go"));
+        assert_eq!(
+            table["skills"]["languages"][1]["name"].as_str(),
+            Some("TypeScript")
+        );
+        assert_eq!(table["skills"]["languages"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            fixes[2].to_string(),
+            "skills.databases: \"PostgresSQL\" -> \"PostgreSQL\""
+        );
+        assert_eq!(
+            table["skills"]["databases"],
+            toml::Value::from(vec!["PostgreSQL", "Zzyzx"])
+        );
+    }
+}
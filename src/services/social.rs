@@ -0,0 +1,311 @@
+//! Turning `[social]` values into link targets. Each platform accepts a full
+//! URL or a handle such as `@alice`; handles are expanded into the platform's
+//! canonical profile URL.
+
+use crate::config::profile::{Social, SocialEntry};
+
+/// How to expand a handle for one platform, and which hosts its URLs live on.
+struct PlatformRule {
+    key: &'static str,
+    /// `{}` is replaced by the handle.
+    template: &'static str,
+    /// Hosts a URL for this platform may use, without `www.`; empty means any.
+    hosts: &'static [&'static str],
+}
+
+const RULES: &[PlatformRule] = &[
+    rule("github", "https://github.com/{}", &["github.com"]),
+    rule("twitter", "https://x.com/{}", &["x.com", "twitter.com"]),
+    rule(
+        "linkedin",
+        "https://www.linkedin.com/in/{}",
+        &["linkedin.com"],
+    ),
+    rule("bluesky", "https://bsky.app/profile/{}", &["bsky.app"]),
+    rule("instagram", "https://instagram.com/{}", &["instagram.com"]),
+    rule(
+        "youtube",
+        "https://youtube.com/@{}",
+        &["youtube.com", "youtu.be"],
+    ),
+    rule(
+        "discord",
+        "https://discord.gg/{}",
+        &["discord.gg", "discord.com"],
+    ),
+    rule("devto", "https://dev.to/{}", &["dev.to"]),
+    rule(
+        "hashnode",
+        "https://hashnode.com/@{}",
+        &["hashnode.com", "hashnode.dev"],
+    ),
+    rule("medium", "https://medium.com/@{}", &["medium.com"]),
+    rule(
+        "stackoverflow",
+        "https://stackoverflow.com/users/{}",
+        &["stackoverflow.com"],
+    ),
+    rule("reddit", "https://reddit.com/user/{}", &["reddit.com"]),
+    rule("twitch", "https://twitch.tv/{}", &["twitch.tv"]),
+    rule("kofi", "https://ko-fi.com/{}", &["ko-fi.com"]),
+    rule(
+        "threads",
+        "https://www.threads.net/@{}",
+        &["threads.net", "threads.com"],
+    ),
+    rule("gitlab", "https://gitlab.com/{}", &["gitlab.com"]),
+    rule("codeberg", "https://codeberg.org/{}", &["codeberg.org"]),
+    rule("keybase", "https://keybase.io/{}", &["keybase.io"]),
+    rule("matrix", "https://matrix.to/#/{}", &["matrix.to"]),
+    rule("telegram", "https://t.me/{}", &["t.me", "telegram.me"]),
+    rule(
+        "leetcode",
+        "https://leetcode.com/u/{}",
+        &["leetcode.com", "leetcode.cn"],
+    ),
+    rule("kaggle", "https://www.kaggle.com/{}", &["kaggle.com"]),
+    rule(
+        "huggingface",
+        "https://huggingface.co/{}",
+        &["huggingface.co"],
+    ),
+    rule("orcid", "https://orcid.org/{}", &["orcid.org"]),
+    rule("polywork", "https://www.polywork.com/{}", &["polywork.com"]),
+    rule("peerlist", "https://peerlist.io/{}", &["peerlist.io"]),
+];
+
+const fn rule(
+    key: &'static str,
+    template: &'static str,
+    hosts: &'static [&'static str],
+) -> PlatformRule {
+    PlatformRule {
+        key,
+        template,
+        hosts,
+    }
+}
+
+/// The link target for a `[social]` value: URLs are kept, handles are expanded.
+pub fn profile_url(platform: &str, value: &str) -> String {
+    let value: &str = value.trim();
+    if platform == "twitter"
+        && let Some(path) = twitter_path(value)
+    {
+        return format!("https://x.com/{}", path);
+    }
+    if has_scheme(value) {
+        return value.to_string();
+    }
+
+    match platform {
+        "email" => format!("mailto:{}", value),
+        "mastodon" => match fediverse_handle(value) {
+            Some((user, instance)) => format!("https://{}/@{}", instance, user),
+            None => value.to_string(),
+        },
+        // A bare domain for sites that have no handle form.
+        "website" | "rss" => format!("https://{}", value),
+        // Matrix IDs keep their sigil: @alice:matrix.org.
+        "matrix" => format!("https://matrix.to/#/{}", value),
+        _ => {
+            let Some(rule) = RULES.iter().find(|r: &&PlatformRule| r.key == platform) else {
+                return value.to_string();
+            };
+            if looks_like_host_path(value, rule.hosts) {
+                return format!("https://{}", value);
+            }
+            let handle: &str = value.trim_start_matches('@');
+            let handle: &str = match platform {
+                "reddit" => handle
+                    .trim_start_matches('/')
+                    .trim_start_matches("u/")
+                    .trim_start_matches("user/"),
+                _ => handle,
+            };
+            rule.template.replace("{}", handle)
+        }
+    }
+}
+
+/// Problems with `[social]` values that would produce broken or misleading links.
+pub fn social_warnings(social: &Social) -> Vec<String> {
+    let mut warnings: Vec<String> = Vec::new();
+    for (platform, value) in social.platforms() {
+        let Some(value) = value.as_ref().map(|v: &SocialEntry| v.url().trim()) else {
+            continue;
+        };
+        if platform == "mastodon" && !has_scheme(value) && fediverse_handle(value).is_none() {
+            warnings.push(format!(
+                "social.mastodon \"{}\" needs an instance, e.g. @{}@mastodon.social",
+                value,
+                value.trim_start_matches('@')
+            ));
+            continue;
+        }
+        let Some(rule) = RULES.iter().find(|r: &&PlatformRule| r.key == platform) else {
+            continue;
+        };
+        if let Some(host) = url_host(value)
+            && !host_matches(host, rule.hosts)
+        {
+            warnings.push(format!(
+                "social.{} points at {}, not {}",
+                platform,
+                host,
+                rule.hosts.join(" or ")
+            ));
+        }
+    }
+    warnings
+}
+
+/// A short form of a URL or handle for display: the handle as written, or the
+/// URL without its scheme, `www.` or trailing slash.
+pub fn display_handle(value: &str) -> String {
+    let value: &str = value.trim();
+    let rest: &str = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .or_else(|| value.strip_prefix("mailto:"))
+        .unwrap_or(value);
+    rest.trim_start_matches("www.")
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// `@alice@hachyderm.io` or `alice@hachyderm.io` -> ("alice", "hachyderm.io").
+pub fn fediverse_handle(value: &str) -> Option<(&str, &str)> {
+    let (user, instance) = value.trim_start_matches('@').split_once('@')?;
+    (!user.is_empty() && instance.contains('.') && !instance.contains('/'))
+        .then_some((user, instance))
+}
+
+/// The path of a twitter.com or x.com URL, which are rewritten to one host.
+fn twitter_path(value: &str) -> Option<&str> {
+    let rest: &str = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .unwrap_or(value);
+    let (host, path) = rest.split_once('/')?;
+    let host: &str = host
+        .trim_start_matches("www.")
+        .trim_start_matches("mobile.");
+    matches!(host, "twitter.com" | "x.com").then_some(path)
+}
+
+fn has_scheme(value: &str) -> bool {
+    value.starts_with("https://") || value.starts_with("http://") || value.starts_with("mailto:")
+}
+
+/// `linkedin.com/in/alice` written without a scheme.
+fn looks_like_host_path(value: &str, hosts: &[&str]) -> bool {
+    value
+        .split_once('/')
+        .is_some_and(|(host, _)| host_matches(host, hosts))
+}
+
+/// The host of an http(s) URL, without `www.` or a port.
+fn url_host(value: &str) -> Option<&str> {
+    let rest: &str = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))?;
+    let host: &str = rest.split(['/', '?', '#']).next()?;
+    let host: &str = host.split(':').next()?;
+    Some(host.strip_prefix("www.").unwrap_or(host))
+}
+
+fn host_matches(host: &str, hosts: &[&str]) -> bool {
+    let host: String = host.to_lowercase();
+    let host: &str = host.strip_prefix("www.").unwrap_or(&host);
+    hosts.is_empty()
+        || hosts
+            .iter()
+            .any(|allowed: &&str| host == *allowed || host.ends_with(&format!(".{}", allowed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handles_expand_to_profile_urls() {
+        assert_eq!(profile_url("twitter", "@alice"), "https://x.com/alice");
+        assert_eq!(
+            profile_url("linkedin", "alice"),
+            "https://www.linkedin.com/in/alice"
+        );
+        assert_eq!(
+            profile_url("mastodon", "@alice@hachyderm.io"),
+            "https://hachyderm.io/@alice"
+        );
+        assert_eq!(
+            profile_url("mastodon", "alice@hachyderm.io"),
+            "https://hachyderm.io/@alice"
+        );
+        assert_eq!(profile_url("email", "a@b.c"), "mailto:a@b.c");
+        assert_eq!(
+            profile_url("youtube", "@alice"),
+            "https://youtube.com/@alice"
+        );
+        assert_eq!(
+            profile_url("reddit", "u/alice"),
+            "https://reddit.com/user/alice"
+        );
+        assert_eq!(
+            profile_url("matrix", "@alice:matrix.org"),
+            "https://matrix.to/#/@alice:matrix.org"
+        );
+        assert_eq!(profile_url("website", "alice.dev"), "https://alice.dev");
+        assert_eq!(
+            profile_url("linkedin", "linkedin.com/in/alice"),
+            "https://linkedin.com/in/alice"
+        );
+    }
+
+    #[test]
+    fn test_twitter_urls_use_x_com() {
+        for value in [
+            "https://twitter.com/alice",
+            "http://www.twitter.com/alice",
+            "https://mobile.twitter.com/alice",
+            "twitter.com/alice",
+            "https://x.com/alice",
+        ] {
+            assert_eq!(profile_url("twitter", value), "https://x.com/alice");
+        }
+    }
+
+    #[test]
+    fn test_urls_are_kept() {
+        assert_eq!(
+            profile_url("linkedin", "https://linkedin.com/in/alice"),
+            "https://linkedin.com/in/alice"
+        );
+        assert_eq!(profile_url("email", "mailto:a@b.c"), "mailto:a@b.c");
+        assert_eq!(
+            profile_url("mastodon", "https://hachyderm.io/@alice"),
+            "https://hachyderm.io/@alice"
+        );
+    }
+
+    #[test]
+    fn test_social_warnings() {
+        let social = Social {
+            twitter: Some("https://x.com/alice".into()),
+            github: Some("https://www.github.com/alice".into()),
+            linkedin: Some("https://twitter.com/alice".into()),
+            mastodon: Some("@alice".into()),
+            website: Some("https://anything.example".into()),
+            ..Social::default()
+        };
+        assert_eq!(
+            social_warnings(&social),
+            vec![
+                "social.linkedin points at twitter.com, not linkedin.com".to_string(),
+                "social.mastodon \"@alice\" needs an instance, e.g. @alice@mastodon.social"
+                    .to_string(),
+            ]
+        );
+    }
+}
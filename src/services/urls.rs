@@ -1,17 +1,393 @@
+use crate::config::profile::{CardColors, Services, StatsCardOptions, TopLangsOptions};
+use crate::services::skill_icons;
+
+/// Base URLs of the card and badge services, without a trailing slash. The
+/// defaults are the public deployments; `[services]` overrides them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceUrls {
+    pub readme_stats: String,
+    pub streak_stats: String,
+    pub trophies: String,
+    pub typing_svg: String,
+    pub contributor_stats: String,
+    pub shields: String,
+    pub badgen: String,
+    pub simple_icons: String,
+    pub profile_views: String,
+    pub spotify: String,
+}
+
+/// What every card is drawn for: whose data, and how it looks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Card<'a> {
+    pub username: &'a str,
+    pub theme: &'a str,
+    pub hide_border: bool,
+    pub colors: &'a CardColors,
+}
+
+/// A static badge, e.g. a skill or social link, drawn by the configured badge provider.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Badge<'a> {
+    pub label: &'a str,
+    /// Shown after the label, e.g. "6y" for years of experience.
+    pub message: Option<&'a str>,
+    /// Hex color without `#`.
+    pub color: &'a str,
+    /// simple-icons slug.
+    pub logo: &'a str,
+    pub logo_color: &'a str,
+    pub style: &'a str,
+}
+
+impl<'a> Badge<'a> {
+    /// A "for-the-badge" style badge with a white logo.
+    pub fn new(label: &'a str, color: &'a str, logo: &'a str) -> Self {
+        Badge {
+            label,
+            message: None,
+            color,
+            logo,
+            logo_color: "white",
+            style: "for-the-badge",
+        }
+    }
+}
+
+impl Default for ServiceUrls {
+    fn default() -> Self {
+        ServiceUrls {
+            readme_stats: "https://github-readme-stats.vercel.app".to_string(),
+            streak_stats: "https://streak-stats.demolab.com".to_string(),
+            trophies: "https://github-profile-trophy.vercel.app".to_string(),
+            typing_svg: "https://readme-typing-svg.demolab.com".to_string(),
+            contributor_stats: "https://github-contributor-stats.vercel.app".to_string(),
+            shields: "https://img.shields.io".to_string(),
+            badgen: "https://badgen.net".to_string(),
+            simple_icons: "https://cdn.simpleicons.org".to_string(),
+            profile_views: "https://komarev.com".to_string(),
+            spotify: "https://spotify-github-profile.kittinan.vercel.app".to_string(),
+        }
+    }
+}
+
+impl ServiceUrls {
+    /// The public deployments with any configured overrides applied.
+    pub fn new(overrides: Option<&Services>) -> Self {
+        let mut urls = ServiceUrls::default();
+        let Some(overrides) = overrides else {
+            return urls;
+        };
+        let fields: [(&mut String, &Option<String>); 10] = [
+            (&mut urls.readme_stats, &overrides.readme_stats),
+            (&mut urls.streak_stats, &overrides.streak_stats),
+            (&mut urls.trophies, &overrides.trophies),
+            (&mut urls.typing_svg, &overrides.typing_svg),
+            (&mut urls.contributor_stats, &overrides.contributor_stats),
+            (&mut urls.shields, &overrides.shields),
+            (&mut urls.badgen, &overrides.badgen),
+            (&mut urls.simple_icons, &overrides.simple_icons),
+            (&mut urls.profile_views, &overrides.profile_views),
+            (&mut urls.spotify, &overrides.spotify),
+        ];
+        for (url, custom) in fields {
+            if let Some(custom) = custom {
+                *url = custom.trim_end_matches('/').to_string();
+            }
+        }
+        urls
+    }
+
+    /// URL builder for github-readme-stats card.
+    pub fn github_stats_url(
+        &self,
+        card: &Card,
+        show_icons: bool,
+        options: &StatsCardOptions,
+    ) -> String {
+        let url: String = format!(
+            "{}/api?username={}&theme={}&show_icons={}&hide_border={}",
+            self.readme_stats, card.username, card.theme, show_icons, card.hide_border
+        );
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(hide) = &options.hide {
+            params.push(("hide", join_params(hide.iter().map(|r| r.as_str()))));
+        }
+        if let Some(show) = &options.show {
+            params.push(("show", join_params(show.iter().map(|r| r.as_str()))));
+        }
+        push_param(&mut params, "count_private", options.count_private);
+        push_param(
+            &mut params,
+            "include_all_commits",
+            options.include_all_commits,
+        );
+        push_param(
+            &mut params,
+            "custom_title",
+            options.custom_title.as_deref().map(url_encode),
+        );
+        push_param(
+            &mut params,
+            "locale",
+            options.locale.as_deref().map(url_encode),
+        );
+        push_param(
+            &mut params,
+            "rank_icon",
+            options.rank_icon.map(|icon| icon.as_str()),
+        );
+        push_param(&mut params, "card_width", options.card_width);
+        push_param(&mut params, "cache_seconds", options.cache_seconds);
+        push_param(
+            &mut params,
+            "disable_animations",
+            options.disable_animations,
+        );
+        push_color_params(&mut params, card.colors);
+        with_params(url, &params)
+    }
+
+    /// URL builder for top languages card.
+    pub fn top_langs_url(
+        &self,
+        card: &Card,
+        layout: &str,
+        langs_count: u32,
+        options: &TopLangsOptions,
+    ) -> String {
+        let url: String = format!(
+            "{}/api/top-langs/?username={}&layout={}&langs_count={}&theme={}&hide_border={}",
+            self.readme_stats, card.username, layout, langs_count, card.theme, card.hide_border
+        );
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(repos) = &options.exclude_repo {
+            params.push((
+                "exclude_repo",
+                join_params(repos.iter().map(String::as_str)),
+            ));
+        }
+        if let Some(hide) = &options.hide {
+            params.push(("hide", join_params(hide.iter().map(String::as_str))));
+        }
+        push_param(&mut params, "size_weight", options.size_weight);
+        push_param(&mut params, "count_weight", options.count_weight);
+        push_color_params(&mut params, card.colors);
+        with_params(url, &params)
+    }
+
+    /// URL builder for GitHub streak stats.
+    pub fn streak_stats_url(&self, card: &Card) -> String {
+        let url: String = format!(
+            "{}/?user={}&theme={}&hide_border={}",
+            self.streak_stats, card.username, card.theme, card.hide_border
+        );
+        let mut params: Vec<(&str, String)> = Vec::new();
+        push_streak_color_params(&mut params, card.colors);
+        with_params(url, &params)
+    }
+
+    /// URL builder for typing SVG animation.
+    pub fn typing_svg_url(&self, lines: &[&str], font: &str, color: &str, center: bool) -> String {
+        let lines_param: String = lines.join(";");
+        format!(
+            "{}/?lines={}&font={}&color={}&center={}&width=440&height=45&vCenter=true&pause=1000&size=22",
+            self.typing_svg,
+            url_encode(&lines_param),
+            url_encode(font),
+            color,
+            center
+        )
+    }
+
+    /// URL builder for a shields.io static badge.
+    pub fn shields_badge_url(&self, badge: &Badge) -> String {
+        let text: String = match badge.message {
+            Some(message) => format!("{}-{}", badge_text(badge.label), badge_text(message)),
+            None => badge_text(badge.label),
+        };
+        format!(
+            "{}/badge/{}-{}?style={}&logo={}&logoColor={}",
+            self.shields, text, badge.color, badge.style, badge.logo, badge.logo_color
+        )
+    }
+
+    /// URL builder for a badgen.net static badge. Badgen has one style and
+    /// always shows a status, so a label-only badge shows it as the status.
+    pub fn badgen_badge_url(&self, badge: &Badge) -> String {
+        match badge.message {
+            Some(message) => format!(
+                "{}/badge/{}/{}/{}?icon={}",
+                self.badgen,
+                url_encode(badge.label),
+                url_encode(message),
+                badge.color,
+                badge.logo
+            ),
+            None => format!(
+                "{}/badge/icon/{}/{}?icon={}&label",
+                self.badgen,
+                url_encode(badge.label),
+                badge.color,
+                badge.logo
+            ),
+        }
+    }
+
+    /// A simple-icons logo in the given hex color, served by the simple-icons CDN.
+    pub fn simple_icon_url(&self, slug: &str, color: &str) -> String {
+        format!("{}/{}/{}", self.simple_icons, slug, color)
+    }
+
+    /// URL builder for komarev profile views counter.
+    pub fn profile_views_url(&self, username: &str) -> String {
+        format!(
+            "{}/ghpvc/?username={}&color=blue&style=flat",
+            self.profile_views, username
+        )
+    }
+
+    /// URL builder for spotify-github-profile.
+    pub fn spotify_url(&self, uid: &str) -> String {
+        format!("{}/api/view?uid={}&cover_image=true", self.spotify, uid)
+    }
+
+    /// Spotify profile badge markdown (with redirect link).
+    pub fn spotify_badge_markdown(&self, uid: &str) -> String {
+        let img_url: String = self.spotify_url(uid);
+        let link_url: String = format!("{}/api/view?uid={}&redirect=true", self.spotify, uid);
+        format!("[![spotify-github-profile]({})]({})", img_url, link_url)
+    }
+
+    /// URL builder for github-readme-stats pin card.
+    pub fn pin_card_url(&self, card: &Card, repo: &str) -> String {
+        let url: String = format!(
+            "{}/api/pin/?username={}&repo={}&theme={}&hide_border={}",
+            self.readme_stats, card.username, repo, card.theme, card.hide_border
+        );
+        let mut params: Vec<(&str, String)> = Vec::new();
+        push_color_params(&mut params, card.colors);
+        with_params(url, &params)
+    }
+
+    /// URL builder for github-readme-streak-stats contributor card.
+    pub fn contributor_stats_url(&self, username: &str, theme: &str, hide_border: bool) -> String {
+        format!(
+            "{}/api?username={}&theme={}&hide_border={}",
+            self.contributor_stats, username, theme, hide_border
+        )
+    }
+
+    /// URL builder for github-profile-trophy.
+    pub fn trophies_url(&self, username: &str, theme: &str) -> String {
+        format!("{}/?username={}&theme={}", self.trophies, username, theme)
+    }
+
+    /// URL builder for a shields.io follower count badge; works for users and organizations.
+    pub fn followers_badge_url(&self, account: &str) -> String {
+        format!(
+            "{}/github/followers/{}?label=Followers&style=for-the-badge&logo=github",
+            self.shields, account
+        )
+    }
+
+    /// URL builder for a shields.io total stars badge across an account's repositories.
+    pub fn account_stars_badge_url(&self, account: &str) -> String {
+        format!(
+            "{}/github/stars/{}?label=Stars&style=for-the-badge&logo=github",
+            self.shields, account
+        )
+    }
+}
+
+/// Colors go in as bare hex; a leading `#` would start the URL fragment and
+/// cut off every parameter after it, so it is dropped.
+fn push_color_params(params: &mut Vec<(&str, String)>, colors: &CardColors) {
+    push_param(params, "title_color", hex_param(&colors.title));
+    push_param(params, "text_color", hex_param(&colors.text));
+    push_param(params, "icon_color", hex_param(&colors.icon));
+    push_param(params, "bg_color", hex_param(&colors.bg));
+    push_param(params, "border_color", hex_param(&colors.border));
+}
+
+/// The streak card names its colors per element: titles are the labels,
+/// text is the numbers and dates, and the icon is the fire and its ring.
+fn push_streak_color_params(params: &mut Vec<(&str, String)>, colors: &CardColors) {
+    push_param(params, "background", hex_param(&colors.bg));
+    push_param(params, "border", hex_param(&colors.border));
+    for key in ["currStreakLabel", "sideLabels"] {
+        push_param(params, key, hex_param(&colors.title));
+    }
+    for key in ["currStreakNum", "sideNums", "dates"] {
+        push_param(params, key, hex_param(&colors.text));
+    }
+    for key in ["fire", "ring"] {
+        push_param(params, key, hex_param(&colors.icon));
+    }
+}
+
+fn hex_param(color: &Option<String>) -> Option<String> {
+    color
+        .as_deref()
+        .map(|c: &str| c.trim().trim_start_matches('#').to_string())
+}
+
+fn push_param<'a>(params: &mut Vec<(&'a str, String)>, key: &'a str, value: Option<impl ToString>) {
+    if let Some(value) = value {
+        params.push((key, value.to_string()));
+    }
+}
+
+/// Comma-separated list values, each encoded for a query string.
+fn join_params<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    values.map(url_encode).collect::<Vec<String>>().join(",")
+}
+
+fn with_params(mut url: String, params: &[(&str, String)]) -> String {
+    for (key, value) in params {
+        url.push_str(&format!("&{}={}", key, value));
+    }
+    url
+}
+
+// The free functions below predate `ServiceUrls` and build URLs for the
+// public deployments. They are kept for one release so library users can
+// move to the methods; each is `ServiceUrls::default()` plus default colors
+// and card options.
+
+fn default_card<'a>(username: &'a str, theme: &'a str, hide_border: bool) -> Card<'a> {
+    static NO_COLORS: CardColors = CardColors {
+        title: None,
+        text: None,
+        icon: None,
+        bg: None,
+        border: None,
+    };
+    Card {
+        username,
+        theme,
+        hide_border,
+        colors: &NO_COLORS,
+    }
+}
+
 /// URL builder for github-readme-stats card.
+#[deprecated(note = "use `ServiceUrls::github_stats_url`")]
 pub fn github_stats_url(
     username: &str,
     theme: &str,
     show_icons: bool,
     hide_border: bool,
 ) -> String {
-    format!(
-        "https://github-readme-stats.vercel.app/api?username={}&theme={}&show_icons={}&hide_border={}",
-        username, theme, show_icons, hide_border
+    ServiceUrls::default().github_stats_url(
+        &default_card(username, theme, hide_border),
+        show_icons,
+        &StatsCardOptions::default(),
     )
 }
 
 /// URL builder for top languages card.
+#[deprecated(note = "use `ServiceUrls::top_langs_url`")]
 pub fn top_langs_url(
     username: &str,
     layout: &str,
@@ -19,100 +395,87 @@ pub fn top_langs_url(
     theme: &str,
     hide_border: bool,
 ) -> String {
-    format!(
-        "https://github-readme-stats.vercel.app/api/top-langs/?username={}&layout={}&langs_count={}&theme={}&hide_border={}",
-        username, layout, langs_count, theme, hide_border
+    ServiceUrls::default().top_langs_url(
+        &default_card(username, theme, hide_border),
+        layout,
+        langs_count,
+        &TopLangsOptions::default(),
     )
 }
 
 /// URL builder for GitHub streak stats.
+#[deprecated(note = "use `ServiceUrls::streak_stats_url`")]
 pub fn streak_stats_url(username: &str, theme: &str, hide_border: bool) -> String {
-    format!(
-        "https://streak-stats.demolab.com/?user={}&theme={}&hide_border={}",
-        username, theme, hide_border
-    )
+    ServiceUrls::default().streak_stats_url(&default_card(username, theme, hide_border))
 }
 
 /// URL builder for typing SVG animation.
+#[deprecated(note = "use `ServiceUrls::typing_svg_url`")]
 pub fn typing_svg_url(lines: &[&str], font: &str, color: &str, center: bool) -> String {
-    let lines_param: String = lines.join(";");
-    format!(
-        "https://readme-typing-svg.demolab.com/?lines={}&font={}&color={}&center={}&width=440&height=45&vCenter=true&pause=1000&size=22",
-        url_encode(&lines_param),
-        url_encode(font),
-        color,
-        center
-    )
+    ServiceUrls::default().typing_svg_url(lines, font, color, center)
 }
 
 /// URL builder for shields.io badge.
+#[deprecated(note = "use `ServiceUrls::shields_badge_url`")]
 pub fn shields_badge_url(label: &str, color: &str, logo: &str, style: &str) -> String {
-    let encoded_label: String = label.replace('-', "--").replace(' ', "_");
-    format!(
-        "https://img.shields.io/badge/{}-{}?style={}&logo={}&logoColor=white",
-        encoded_label, color, style, logo
-    )
+    ServiceUrls::default().shields_badge_url(&Badge {
+        style,
+        ..Badge::new(label, color, logo)
+    })
 }
 
 /// Convenience: shields.io badge with "for-the-badge" style.
+#[deprecated(note = "use `Providers::skill_badge_url`")]
 pub fn skill_badge_url(label: &str, color: &str, logo: &str) -> String {
-    shields_badge_url(label, color, logo, "for-the-badge")
+    ServiceUrls::default().shields_badge_url(&Badge::new(label, color, logo))
 }
 
 /// URL builder for social link badge (for-the-badge with clickable link).
+#[deprecated(note = "use `Providers::social_badge_markdown`")]
 pub fn social_badge_markdown(label: &str, color: &str, logo: &str, url: &str) -> String {
-    let badge: String = shields_badge_url(label, color, logo, "for-the-badge");
+    let badge: String = ServiceUrls::default().shields_badge_url(&Badge::new(label, color, logo));
     format!("[![{}]({})]({})", label, badge, url)
 }
 
 /// URL builder for komarev profile views counter.
+#[deprecated(note = "use `ServiceUrls::profile_views_url`")]
 pub fn profile_views_url(username: &str) -> String {
-    format!(
-        "https://komarev.com/ghpvc/?username={}&color=blue&style=flat",
-        username
-    )
+    ServiceUrls::default().profile_views_url(username)
 }
 
 /// URL builder for spotify-github-profile.
+#[deprecated(note = "use `ServiceUrls::spotify_url`")]
 pub fn spotify_url(uid: &str) -> String {
-    format!(
-        "https://spotify-github-profile.kittinan.vercel.app/api/view?uid={}&cover_image=true",
-        uid
-    )
+    ServiceUrls::default().spotify_url(uid)
 }
 
 /// Spotify profile badge markdown (with redirect link).
+#[deprecated(note = "use `ServiceUrls::spotify_badge_markdown`")]
 pub fn spotify_badge_markdown(uid: &str) -> String {
-    let img_url: String = spotify_url(uid);
-    let link_url: String = format!(
-        "https://spotify-github-profile.kittinan.vercel.app/api/view?uid={}&redirect=true",
-        uid
-    );
-    format!("[![spotify-github-profile]({})]({})", img_url, link_url)
+    ServiceUrls::default().spotify_badge_markdown(uid)
 }
 
 /// URL builder for github-readme-stats pin card.
+#[deprecated(note = "use `ServiceUrls::pin_card_url`")]
 pub fn pin_card_url(username: &str, repo: &str, theme: &str, hide_border: bool) -> String {
-    format!(
-        "https://github-readme-stats.vercel.app/api/pin/?username={}&repo={}&theme={}&hide_border={}",
-        username, repo, theme, hide_border
-    )
+    ServiceUrls::default().pin_card_url(&default_card(username, theme, hide_border), repo)
 }
 
 /// URL builder for github-readme-streak-stats contributor card.
+#[deprecated(note = "use `ServiceUrls::contributor_stats_url`")]
 pub fn contributor_stats_url(username: &str, theme: &str, hide_border: bool) -> String {
-    format!(
-        "https://github-contributor-stats.vercel.app/api?username={}&theme={}&hide_border={}",
-        username, theme, hide_border
-    )
+    ServiceUrls::default().contributor_stats_url(username, theme, hide_border)
 }
 
 /// URL builder for github-profile-trophy.
+#[deprecated(note = "use `ServiceUrls::trophies_url`")]
 pub fn trophies_url(username: &str, theme: &str) -> String {
-    format!(
-        "https://github-profile-trophy.vercel.app/?username={}&theme={}",
-        username, theme
-    )
+    ServiceUrls::default().trophies_url(username, theme)
+}
+
+/// URL for a GitHub user's or organization's avatar image.
+pub fn avatar_url(username: &str, size: u32) -> String {
+    format!("https://github.com/{}.png?size={}", username, size)
 }
 
 /// URL builder for StackOverflow flair badge.
@@ -120,6 +483,26 @@ pub fn stackoverflow_badge_url(uid: &str) -> String {
     format!("https://stackoverflow.com/users/flair/{}.png", uid)
 }
 
+/// Escape text for a shields.io badge path: dashes and underscores double,
+/// spaces become underscores, and characters that end a path segment are
+/// percent-encoded.
+fn badge_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '-' => result.push_str("--"),
+            '_' => result.push_str("__"),
+            ' ' => result.push('_'),
+            '%' => result.push_str("%25"),
+            '#' => result.push_str("%23"),
+            '?' => result.push_str("%3F"),
+            '/' => result.push_str("%2F"),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
 /// Simple percent-encoding for URL query parameters.
 fn url_encode(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
@@ -129,87 +512,27 @@ fn url_encode(input: &str) -> String {
             '+' => result.push_str("%2B"),
             '&' => result.push_str("%26"),
             '=' => result.push_str("%3D"),
+            '%' => result.push_str("%25"),
             '#' => result.push_str("%23"),
             '?' => result.push_str("%3F"),
+            '/' => result.push_str("%2F"),
             _ => result.push(ch),
         }
     }
     result
 }
 
-/// Lookup table mapping skill names to (simple-icons slug, hex color).
+/// Look up a skill (or one of its aliases) in the built-in icon catalog.
 /// Returns (slug, color) or None if not found.
 pub fn skill_icon_lookup(skill: &str) -> Option<(&'static str, &'static str)> {
-    match skill.to_lowercase().as_str() {
-        // Languages
-        "rust" => Some(("rust", "000000")),
-        "python" => Some(("python", "3776AB")),
-        "typescript" => Some(("typescript", "3178C6")),
-        "javascript" => Some(("javascript", "F7DF1E")),
-        "go" => Some(("go", "00ADD8")),
-        "c" => Some(("c", "A8B9CC")),
-        "c++" => Some(("cplusplus", "00599C")),
-        "java" => Some(("openjdk", "ED8B00")),
-        "kotlin" => Some(("kotlin", "7F52FF")),
-        "swift" => Some(("swift", "FA7343")),
-        "ruby" => Some(("ruby", "CC342D")),
-        "php" => Some(("php", "777BB4")),
-        "elixir" => Some(("elixir", "4B275F")),
-        "haskell" => Some(("haskell", "5D4F85")),
-        "scala" => Some(("scala", "DC322F")),
-        "zig" => Some(("zig", "F7A41D")),
-        "lua" => Some(("lua", "2C2D72")),
-        "dart" => Some(("dart", "0175C2")),
-        "r" => Some(("r", "276DC3")),
-        "shell" | "bash" | "shell/bash" => Some(("gnubash", "4EAA25")),
-        // Frameworks
-        "react" => Some(("react", "61DAFB")),
-        "vue" | "vue.js" => Some(("vuedotjs", "4FC08D")),
-        "svelte" => Some(("svelte", "FF3E00")),
-        "angular" => Some(("angular", "0F0F11")),
-        "next.js" | "nextjs" => Some(("nextdotjs", "000000")),
-        "actix" => Some(("actix", "000000")),
-        "axum" => Some(("rust", "000000")),
-        "rocket" => Some(("rocket", "D33847")),
-        "django" => Some(("django", "092E20")),
-        "flask" => Some(("flask", "000000")),
-        "fastapi" => Some(("fastapi", "009688")),
-        "rails" | "ruby on rails" => Some(("rubyonrails", "CC0000")),
-        "spring" => Some(("spring", "6DB33F")),
-        "express" | "express.js" => Some(("express", "000000")),
-        "gin" => Some(("go", "00ADD8")),
-        "echo" => Some(("go", "00ADD8")),
-        // Tools
-        "docker" => Some(("docker", "2496ED")),
-        "git" => Some(("git", "F05032")),
-        "neovim" => Some(("neovim", "57A143")),
-        "vs code" | "vscode" => Some(("visualstudiocode", "007ACC")),
-        "github actions" => Some(("githubactions", "2088FF")),
-        "terraform" => Some(("terraform", "844FBA")),
-        "ansible" => Some(("ansible", "EE0000")),
-        "kubernetes" => Some(("kubernetes", "326CE5")),
-        "nginx" => Some(("nginx", "009639")),
-        "linux" => Some(("linux", "FCC624")),
-        // Databases
-        "postgresql" | "postgres" => Some(("postgresql", "4169E1")),
-        "mysql" => Some(("mysql", "4479A1")),
-        "sqlite" => Some(("sqlite", "003B57")),
-        "mongodb" => Some(("mongodb", "47A248")),
-        "redis" => Some(("redis", "FF4438")),
-        "dynamodb" => Some(("amazondynamodb", "4053D6")),
-        "cockroachdb" => Some(("cockroachlabs", "6933FF")),
-        "clickhouse" => Some(("clickhouse", "FFCC01")),
-        // Cloud/Infra
-        "aws" => Some(("amazonwebservices", "232F3E")),
-        "gcp" | "google cloud" => Some(("googlecloud", "4285F4")),
-        "azure" => Some(("microsoftazure", "0078D4")),
-        "vercel" => Some(("vercel", "000000")),
-        "cloudflare" => Some(("cloudflare", "F38020")),
-        "digitalocean" => Some(("digitalocean", "0080FF")),
-        "fly.io" => Some(("flydotio", "24175B")),
-        "heroku" => Some(("heroku", "430098")),
-        _ => None,
-    }
+    skill_icons::resolve(skill, None)
+}
+
+/// Which `[skills]` list a known skill belongs in, per the built-in catalog.
+pub fn skill_category(skill: &str) -> Option<&'static str> {
+    skill_icons::catalog()
+        .get(skill)
+        .map(|icon: &skill_icons::SkillIcon| icon.category)
 }
 
 /// Social platform metadata: (display_label, logo_slug, badge_color).
@@ -233,6 +556,18 @@ pub fn social_platform_info(platform: &str) -> Option<(&'static str, &'static st
         "email" => Some(("Email", "gmail", "EA4335")),
         "kofi" | "ko-fi" => Some(("Ko-fi", "kofi", "FF5E5B")),
         "rss" => Some(("RSS", "rss", "FFA500")),
+        "threads" => Some(("Threads", "threads", "000000")),
+        "gitlab" => Some(("GitLab", "gitlab", "FC6D26")),
+        "codeberg" => Some(("Codeberg", "codeberg", "2185D0")),
+        "keybase" => Some(("Keybase", "keybase", "33A0FF")),
+        "matrix" => Some(("Matrix", "matrix", "000000")),
+        "telegram" => Some(("Telegram", "telegram", "26A5E4")),
+        "leetcode" => Some(("LeetCode", "leetcode", "FFA116")),
+        "kaggle" => Some(("Kaggle", "kaggle", "20BEFF")),
+        "huggingface" | "hugging face" => Some(("Hugging Face", "huggingface", "FFD21E")),
+        "orcid" => Some(("ORCID", "orcid", "A6CE39")),
+        "polywork" => Some(("Polywork", "polywork", "543DE0")),
+        "peerlist" => Some(("Peerlist", "peerlist", "00AA45")),
         _ => None,
     }
 }
@@ -241,9 +576,40 @@ pub fn social_platform_info(platform: &str) -> Option<(&'static str, &'static st
 mod tests {
     use super::*;
 
+    fn card(colors: &CardColors) -> Card<'_> {
+        Card {
+            username: "alice",
+            theme: "tokyonight",
+            hide_border: false,
+            colors,
+        }
+    }
+
+    #[test]
+    fn test_service_overrides() {
+        let overrides = Services {
+            shields: Some("https://badges.example.com/".to_string()),
+            ..Services::default()
+        };
+        let services = ServiceUrls::new(Some(&overrides));
+        assert_eq!(services.shields, "https://badges.example.com");
+        assert_eq!(
+            services.shields_badge_url(&Badge {
+                style: "flat",
+                ..Badge::new("Rust", "000000", "rust")
+            }),
+            "https://badges.example.com/badge/Rust-000000?style=flat&logo=rust&logoColor=white"
+        );
+        assert_eq!(services.readme_stats, ServiceUrls::default().readme_stats);
+    }
+
     #[test]
     fn test_github_stats_url() {
-        let url: String = github_stats_url("alice", "tokyonight", true, false);
+        let url: String = ServiceUrls::default().github_stats_url(
+            &card(&CardColors::default()),
+            true,
+            &StatsCardOptions::default(),
+        );
         assert_eq!(
             url,
             "https://github-readme-stats.vercel.app/api?username=alice&theme=tokyonight&show_icons=true&hide_border=false"
@@ -252,25 +618,66 @@ mod tests {
 
     #[test]
     fn test_top_langs_url() {
-        let url: String = top_langs_url("alice", "compact", 8, "tokyonight", false);
+        let url: String = ServiceUrls::default().top_langs_url(
+            &card(&CardColors::default()),
+            "compact",
+            8,
+            &TopLangsOptions::default(),
+        );
         assert_eq!(
             url,
             "https://github-readme-stats.vercel.app/api/top-langs/?username=alice&layout=compact&langs_count=8&theme=tokyonight&hide_border=false"
         );
     }
 
+    #[test]
+    fn test_card_colors_drop_leading_hash() {
+        let colors = CardColors {
+            title: Some("#fff".to_string()),
+            bg: Some("1A1B27".to_string()),
+            ..CardColors::default()
+        };
+        let url: String = ServiceUrls::default().github_stats_url(
+            &card(&colors),
+            true,
+            &StatsCardOptions {
+                locale: Some("de".to_string()),
+                ..StatsCardOptions::default()
+            },
+        );
+        assert!(!url.contains('#'));
+        assert!(url.ends_with("&locale=de&title_color=fff&bg_color=1A1B27"));
+    }
+
     #[test]
     fn test_streak_stats_url() {
-        let url: String = streak_stats_url("alice", "tokyonight", false);
+        let colors = CardColors::default();
+        let url: String = ServiceUrls::default().streak_stats_url(&card(&colors));
         assert_eq!(
             url,
             "https://streak-stats.demolab.com/?user=alice&theme=tokyonight&hide_border=false"
         );
     }
 
+    #[test]
+    fn test_streak_stats_url_colors() {
+        let colors = CardColors {
+            title: Some("#fff".to_string()),
+            icon: Some("F7768E".to_string()),
+            bg: Some("1A1B27".to_string()),
+            ..CardColors::default()
+        };
+        let url: String = ServiceUrls::default().streak_stats_url(&card(&colors));
+        assert!(!url.contains('#'));
+        assert!(url.ends_with(
+            "&background=1A1B27&currStreakLabel=fff&sideLabels=fff&fire=F7768E&ring=F7768E"
+        ));
+    }
+
     #[test]
     fn test_typing_svg_url() {
-        let url: String = typing_svg_url(&["Hello", "World"], "Fira Code", "f75c7e", true);
+        let url: String =
+            ServiceUrls::default().typing_svg_url(&["Hello", "World"], "Fira Code", "f75c7e", true);
         assert!(url.starts_with("https://readme-typing-svg.demolab.com/"));
         assert!(url.contains("lines=Hello;World"));
         assert!(url.contains("font=Fira%20Code"));
@@ -280,7 +687,8 @@ mod tests {
 
     #[test]
     fn test_shields_badge_url() {
-        let url: String = shields_badge_url("Rust", "000000", "rust", "for-the-badge");
+        let url: String =
+            ServiceUrls::default().shields_badge_url(&Badge::new("Rust", "000000", "rust"));
         assert_eq!(
             url,
             "https://img.shields.io/badge/Rust-000000?style=for-the-badge&logo=rust&logoColor=white"
@@ -289,16 +697,48 @@ mod tests {
 
     #[test]
     fn test_shields_badge_url_with_spaces_and_dashes() {
-        let url: String = shields_badge_url("Vue.js", "4FC08D", "vuedotjs", "for-the-badge");
+        let url: String =
+            ServiceUrls::default().shields_badge_url(&Badge::new("Vue.js", "4FC08D", "vuedotjs"));
         assert!(url.contains("Vue.js-4FC08D"));
 
-        let url: String = shields_badge_url("C++", "00599C", "cplusplus", "for-the-badge");
+        let url: String =
+            ServiceUrls::default().shields_badge_url(&Badge::new("C++", "00599C", "cplusplus"));
         assert!(url.contains("C++-00599C"));
     }
 
+    #[test]
+    fn test_shields_badge_url_escapes_reserved_characters() {
+        let url: String =
+            ServiceUrls::default().shields_badge_url(&Badge::new("C#", "512BD4", "csharp"));
+        assert_eq!(
+            url,
+            "https://img.shields.io/badge/C%23-512BD4?style=for-the-badge&logo=csharp&logoColor=white"
+        );
+
+        let url: String = ServiceUrls::default().shields_badge_url(&Badge {
+            message: Some("100% of_it?"),
+            ..Badge::new("CI/CD", "333333", "githubactions")
+        });
+        assert!(url.contains("/badge/CI%2FCD-100%25_of__it%3F-333333?"));
+    }
+
+    #[test]
+    fn test_badge_message_and_logo_color() {
+        let url: String = ServiceUrls::default().shields_badge_url(&Badge {
+            message: Some("ABCD 1234"),
+            logo_color: "black",
+            style: "flat-square",
+            ..Badge::new("PGP", "333333", "gnuprivacyguard")
+        });
+        assert_eq!(
+            url,
+            "https://img.shields.io/badge/PGP-ABCD_1234-333333?style=flat-square&logo=gnuprivacyguard&logoColor=black"
+        );
+    }
+
     #[test]
     fn test_profile_views_url() {
-        let url: String = profile_views_url("alice");
+        let url: String = ServiceUrls::default().profile_views_url("alice");
         assert_eq!(
             url,
             "https://komarev.com/ghpvc/?username=alice&color=blue&style=flat"
@@ -307,7 +747,7 @@ mod tests {
 
     #[test]
     fn test_spotify_url() {
-        let url: String = spotify_url("USER");
+        let url: String = ServiceUrls::default().spotify_url("USER");
         assert_eq!(
             url,
             "https://spotify-github-profile.kittinan.vercel.app/api/view?uid=USER&cover_image=true"
@@ -316,16 +756,26 @@ mod tests {
 
     #[test]
     fn test_pin_card_url() {
-        let url: String = pin_card_url("alice", "cool-cli", "tokyonight", false);
+        let colors = CardColors::default();
+        let url: String = ServiceUrls::default().pin_card_url(&card(&colors), "cool-cli");
         assert_eq!(
             url,
             "https://github-readme-stats.vercel.app/api/pin/?username=alice&repo=cool-cli&theme=tokyonight&hide_border=false"
         );
+
+        let colors = CardColors {
+            text: Some("C0CAF5".to_string()),
+            border: Some("#414868".to_string()),
+            ..CardColors::default()
+        };
+        let url: String = ServiceUrls::default().pin_card_url(&card(&colors), "cool-cli");
+        assert!(url.ends_with("&hide_border=false&text_color=C0CAF5&border_color=414868"));
     }
 
     #[test]
     fn test_contributor_stats_url() {
-        let url: String = contributor_stats_url("alice", "tokyonight", false);
+        let url: String =
+            ServiceUrls::default().contributor_stats_url("alice", "tokyonight", false);
         assert_eq!(
             url,
             "https://github-contributor-stats.vercel.app/api?username=alice&theme=tokyonight&hide_border=false"
@@ -334,7 +784,7 @@ mod tests {
 
     #[test]
     fn test_trophies_url() {
-        let url: String = trophies_url("alice", "tokyonight");
+        let url: String = ServiceUrls::default().trophies_url("alice", "tokyonight");
         assert_eq!(
             url,
             "https://github-profile-trophy.vercel.app/?username=alice&theme=tokyonight"
@@ -342,19 +792,40 @@ mod tests {
     }
 
     #[test]
-    fn test_skill_badge_url() {
-        let url: String = skill_badge_url("Rust", "000000", "rust");
-        assert!(url.contains("style=for-the-badge"));
-        assert!(url.contains("logo=rust"));
-    }
-
-    #[test]
-    fn test_social_badge_markdown() {
-        let md: String =
-            social_badge_markdown("Twitter", "000000", "x", "https://twitter.com/alice");
-        assert!(md.starts_with("[![Twitter]"));
-        assert!(md.contains("https://twitter.com/alice"));
-        assert!(md.contains("style=for-the-badge"));
+    #[allow(deprecated)]
+    fn test_deprecated_free_functions_match_baseline() {
+        assert_eq!(
+            github_stats_url("alice", "tokyonight", true, false),
+            "https://github-readme-stats.vercel.app/api?username=alice&theme=tokyonight&show_icons=true&hide_border=false"
+        );
+        assert_eq!(
+            top_langs_url("alice", "compact", 8, "tokyonight", true),
+            "https://github-readme-stats.vercel.app/api/top-langs/?username=alice&layout=compact&langs_count=8&theme=tokyonight&hide_border=true"
+        );
+        assert_eq!(
+            streak_stats_url("alice", "tokyonight", false),
+            "https://streak-stats.demolab.com/?user=alice&theme=tokyonight&hide_border=false"
+        );
+        assert_eq!(
+            pin_card_url("alice", "cool-cli", "tokyonight", false),
+            "https://github-readme-stats.vercel.app/api/pin/?username=alice&repo=cool-cli&theme=tokyonight&hide_border=false"
+        );
+        assert_eq!(
+            shields_badge_url("Rust", "000000", "rust", "flat"),
+            "https://img.shields.io/badge/Rust-000000?style=flat&logo=rust&logoColor=white"
+        );
+        assert_eq!(
+            social_badge_markdown("GitHub", "181717", "github", "https://github.com/alice"),
+            "[![GitHub](https://img.shields.io/badge/GitHub-181717?style=for-the-badge&logo=github&logoColor=white)](https://github.com/alice)"
+        );
+        assert_eq!(
+            profile_views_url("alice"),
+            "https://komarev.com/ghpvc/?username=alice&color=blue&style=flat"
+        );
+        assert_eq!(
+            trophies_url("alice", "tokyonight"),
+            "https://github-profile-trophy.vercel.app/?username=alice&theme=tokyonight"
+        );
     }
 
     #[test]
@@ -374,6 +845,7 @@ mod tests {
             skill_icon_lookup("AWS"),
             Some(("amazonwebservices", "232F3E"))
         );
+        assert_eq!(skill_icon_lookup("k8s"), Some(("kubernetes", "326CE5")));
         assert!(skill_icon_lookup("unknown-tech").is_none());
     }
 
@@ -391,17 +863,52 @@ mod tests {
             social_platform_info("github"),
             Some(("GitHub", "github", "181717"))
         );
+        assert_eq!(
+            social_platform_info("Hugging Face"),
+            Some(("Hugging Face", "huggingface", "FFD21E"))
+        );
         assert!(social_platform_info("nonexistent").is_none());
     }
 
     #[test]
     fn test_spotify_badge_markdown() {
-        let md: String = spotify_badge_markdown("USER");
+        let md: String = ServiceUrls::default().spotify_badge_markdown("USER");
         assert!(md.contains("spotify-github-profile"));
         assert!(md.contains("uid=USER"));
         assert!(md.contains("redirect=true"));
     }
 
+    #[test]
+    fn test_avatar_url() {
+        assert_eq!(
+            avatar_url("alice", 100),
+            "https://github.com/alice.png?size=100"
+        );
+    }
+
+    #[test]
+    fn test_skill_category() {
+        assert_eq!(skill_category("Rust"), Some("languages"));
+        assert_eq!(skill_category("Django"), Some("frameworks"));
+        assert_eq!(skill_category("Redis"), Some("databases"));
+        assert_eq!(skill_category("golang"), Some("languages"));
+        assert_eq!(skill_category("Unknown"), None);
+    }
+
+    #[test]
+    fn test_org_badge_urls() {
+        assert!(
+            ServiceUrls::default()
+                .followers_badge_url("acme")
+                .starts_with("https://img.shields.io/github/followers/acme?")
+        );
+        assert!(
+            ServiceUrls::default()
+                .account_stars_badge_url("acme")
+                .starts_with("https://img.shields.io/github/stars/acme?")
+        );
+    }
+
     #[test]
     fn test_stackoverflow_badge_url() {
         let url: String = stackoverflow_badge_url("12345");
@@ -413,5 +920,6 @@ mod tests {
         assert_eq!(url_encode("Hello World"), "Hello%20World");
         assert_eq!(url_encode("a+b"), "a%2Bb");
         assert_eq!(url_encode("key=val&key2=val2"), "key%3Dval%26key2%3Dval2");
+        assert_eq!(url_encode("F#/100%"), "F%23%2F100%25");
     }
 }
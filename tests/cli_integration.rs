@@ -134,7 +134,7 @@ fn test_full_pipeline() {
     assert!(content.contains("Acme Corp"));
     // Social
     assert!(content.contains("Twitter"));
-    assert!(content.contains("twitter.com/alice"));
+    assert!(content.contains("x.com/alice"));
     // Skills
     assert!(content.contains("Tech Stack"));
     assert!(content.contains("logo=rust"));
@@ -185,3 +185,709 @@ fn test_init_then_render() {
     // The starter TOML has a placeholder username, so it should produce something
     assert!(readme_path.exists());
 }
+
+#[test]
+fn test_cli_render_legacy_config_warns() {
+    cmd()
+        .args(["render", "tests/fixtures/legacy_v1.toml", "--stdout"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cool-cli"))
+        .stderr(predicate::str::contains("projects.repos is deprecated"))
+        .stderr(predicate::str::contains("gh-profile-gen migrate"));
+}
+
+#[test]
+fn test_cli_migrate_rewrites_legacy_config() {
+    let dir = TempDir::new().unwrap();
+    let path: std::path::PathBuf = dir.path().join("profile.toml");
+    std::fs::copy("tests/fixtures/legacy_v1.toml", &path).unwrap();
+
+    cmd()
+        .args(["migrate", path.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("from schema version 1 to 2"));
+
+    let content: String = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("# Pinned repositories"));
+    assert!(content.contains("schema_version = 2"));
+    assert!(content.contains("[[projects.featured]]"));
+
+    cmd()
+        .args(["migrate", path.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already at schema version 2"));
+}
+
+#[test]
+fn test_cli_config_show_resolved() {
+    let output = cmd()
+        .args([
+            "config",
+            "show",
+            "tests/fixtures/extends.toml",
+            "--resolved",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let content: String = String::from_utf8(output.stdout).unwrap();
+
+    assert!(!content.contains("extends"));
+    assert!(content.contains("username = \"alice\""));
+    assert!(content.contains("theme = \"tokyonight\""));
+    assert!(content.contains("tools = [\n    \"Docker\",\n    \"Git\",\n    \"Neovim\",\n]"));
+}
+
+#[test]
+fn test_cli_config_show_raw() {
+    cmd()
+        .args(["config", "show", "tests/fixtures/extends.toml"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("extends = [\"team/base.toml\"]"));
+}
+
+#[test]
+fn test_cli_render_with_extends() {
+    cmd()
+        .args(["render", "tests/fixtures/extends.toml", "--stdout"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("github-readme-stats"))
+        .stdout(predicate::str::contains("sponsors/alice"));
+}
+
+#[test]
+fn test_cli_render_interpolates_env_vars() {
+    let dir = TempDir::new().unwrap();
+    let path: std::path::PathBuf = dir.path().join("profile.toml");
+    std::fs::write(
+        &path,
+        "[meta]\nusername = \"${GITHUB_ACTOR}\"\nname = \"${DISPLAY_NAME:-Octo Cat}\"\nschema_version = 2\n\n[header]\nstyle = \"text\"\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["render", path.to_str().unwrap(), "--stdout"])
+        .env("GITHUB_ACTOR", "octocat")
+        .env_remove("DISPLAY_NAME")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hey! I'm Octo Cat"));
+
+    cmd()
+        .args(["render", path.to_str().unwrap(), "--stdout"])
+        .env_remove("GITHUB_ACTOR")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "environment variable GITHUB_ACTOR is not set",
+        ));
+}
+
+#[test]
+fn test_cli_render_batch() {
+    let dir = TempDir::new().unwrap();
+    let out_dir: std::path::PathBuf = dir.path().join("out");
+
+    cmd()
+        .args([
+            "render",
+            "--batch",
+            "tests/fixtures/batch",
+            "--out-dir",
+            out_dir.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("ok      alice.toml"))
+        .stdout(predicate::str::contains("FAILED  carol.toml"))
+        .stdout(predicate::str::contains(
+            "3 profiles: 1 ok, 1 with warnings, 1 failed",
+        ))
+        .stderr(predicate::str::contains("projects.repos is deprecated"))
+        .stderr(predicate::str::contains("1 of 3 profiles failed to render"));
+
+    let alice: String = std::fs::read_to_string(out_dir.join("alice/README.md")).unwrap();
+    assert!(alice.contains("Hey! I'm Alice"));
+    assert!(out_dir.join("bob/README.md").exists());
+}
+
+#[test]
+fn test_cli_render_batch_rejects_output() {
+    cmd()
+        .args([
+            "render",
+            "--batch",
+            "tests/fixtures/batch",
+            "-o",
+            "README.md",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_cli_render_requires_file_or_batch() {
+    cmd()
+        .args(["render"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("required"));
+}
+
+#[test]
+fn test_cli_team_page() {
+    let output = cmd()
+        .args(["team", "tests/fixtures/team_page/team.toml", "--stdout"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let content: String = String::from_utf8(output.stdout).unwrap();
+
+    assert!(content.starts_with("# Acme Engineering"));
+    assert!(content.contains("## Platform"));
+    assert!(content.contains("## Developer Relations"));
+    assert!(content.contains("<sub>Backend Engineer</sub>"));
+    assert!(content.contains("https://github.com/carol.png"));
+    assert!(content.contains("alt=\"Docker\""));
+    assert!(!content.contains("alt=\"Git\""));
+    // Sorted by name within the group
+    assert!(content.find("<b>Alice</b>").unwrap() < content.find("<b>Bob</b>").unwrap());
+}
+
+#[test]
+fn test_cli_render_organization_default_output() {
+    let dir = TempDir::new().unwrap();
+    let config = std::fs::canonicalize("tests/fixtures/organization.toml").unwrap();
+
+    cmd()
+        .current_dir(dir.path())
+        .args(["render", config.to_str().unwrap()])
+        .assert()
+        .success();
+
+    let output = dir.path().join(".github/profile/README.md");
+    let content: String = std::fs::read_to_string(output).unwrap();
+    assert!(content.starts_with("## Welcome to Acme Labs"));
+    assert!(content.contains("### Our Mission"));
+    assert!(content.contains("[Discussions](https://github.com/orgs/acme/discussions)"));
+    assert!(content.contains("https://img.shields.io/github/stars/acme"));
+    assert!(content.contains("https://github.com/sponsors/acme"));
+    assert!(content.contains("### Members"));
+    assert!(!content.contains("github-readme-stats.vercel.app/api?"));
+    assert!(!dir.path().join("README.md").exists());
+}
+
+#[test]
+fn test_cli_import_readme() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("profile.toml");
+
+    cmd()
+        .args(["import", "tests/fixtures/handwritten_README.md", "-o"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("unmapped: ### Talks"))
+        .stderr(predicate::str::contains("1 block(s) could not be mapped"));
+
+    let content: String = std::fs::read_to_string(&output).unwrap();
+    let config: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(config["meta"]["username"].as_str(), Some("dana"));
+    assert_eq!(config["meta"]["name"].as_str(), Some("Dana"));
+    assert_eq!(config["about"]["company"].as_str(), Some("Initech"));
+    assert_eq!(
+        config["social"]["mastodon"].as_str(),
+        Some("https://hachyderm.io/@dana")
+    );
+    assert_eq!(config["skills"]["databases"][0].as_str(), Some("Redis"));
+    assert_eq!(config["stats"]["theme"].as_str(), Some("radical"));
+    assert_eq!(config["stats"]["top_langs_count"].as_integer(), Some(6));
+    assert_eq!(config["stats"]["profile_views"].as_bool(), Some(true));
+    assert!(
+        config["extras"]["custom_blocks"][0]
+            .as_str()
+            .unwrap()
+            .contains("RustConf")
+    );
+
+    // The imported config renders
+    cmd()
+        .args(["render", output.to_str().unwrap(), "--stdout"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("RustConf"));
+}
+
+#[test]
+fn test_cli_init_from_github_json() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("profile.toml");
+
+    cmd()
+        .args([
+            "init",
+            "--from-github-json",
+            "tests/fixtures/github/user.json",
+            "--repos",
+            "tests/fixtures/github/repos.json",
+            "-o",
+        ])
+        .arg(&output)
+        .assert()
+        .success();
+
+    let content: String = std::fs::read_to_string(&output).unwrap();
+    let config: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(config["meta"]["username"].as_str(), Some("octocat"));
+    assert_eq!(config["about"]["company"].as_str(), Some("github"));
+    assert_eq!(
+        config["social"]["website"].as_str(),
+        Some("https://github.blog")
+    );
+    let featured = config["projects"]["featured"].as_array().unwrap();
+    let repos: Vec<&str> = featured
+        .iter()
+        .map(|p| p["repo"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        repos,
+        vec![
+            "octocat/Spoon-Knife",
+            "octocat/Hello-World",
+            "octocat/git-consortium"
+        ]
+    );
+}
+
+#[test]
+fn test_cli_init_repos_requires_user_json() {
+    cmd()
+        .args(["init", "--repos", "tests/fixtures/github/repos.json"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_cli_init_from_json_resume_and_export() {
+    let dir = TempDir::new().unwrap();
+    let profile = dir.path().join("profile.toml");
+
+    cmd()
+        .args([
+            "init",
+            "--from-json-resume",
+            "tests/fixtures/resume.json",
+            "-o",
+        ])
+        .arg(&profile)
+        .assert()
+        .success();
+
+    let content: String = std::fs::read_to_string(&profile).unwrap();
+    let config: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(config["meta"]["username"].as_str(), Some("erinpark"));
+    assert_eq!(config["about"]["company"].as_str(), Some("Globex"));
+    assert_eq!(config["about"]["location"].as_str(), Some("Seoul, KR"));
+    assert_eq!(config["skills"]["cloud"][0].as_str(), Some("AWS"));
+    assert_eq!(config["skills"]["languages"][0].as_str(), Some("Go"));
+    assert_eq!(
+        config["extras"]["certifications"][0].as_str(),
+        Some("CKA (CNCF)")
+    );
+
+    let output = cmd()
+        .args(["export", "--json-resume", "--stdout"])
+        .arg(&profile)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let resume: String = String::from_utf8(output.stdout).unwrap();
+    assert!(resume.contains("\"name\": \"Erin Park\""));
+    assert!(resume.contains("\"network\": \"LinkedIn\""));
+    assert!(resume.contains("\"name\": \"Globex\""));
+    assert!(resume.contains("\"url\": \"https://erin.example/zdm\""));
+}
+
+#[test]
+fn test_cli_export_requires_format() {
+    cmd()
+        .args(["export", "tests/fixtures/minimal.toml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--json-resume"));
+}
+
+#[test]
+fn test_cli_init_yaml_and_render() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("profile.yaml");
+
+    cmd().args(["init", "-o"]).arg(&output).assert().success();
+
+    let content: String = std::fs::read_to_string(&output).unwrap();
+    assert!(content.contains("username: your-github-username"));
+
+    cmd()
+        .args(["render", "--stdout"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("your-github-username"));
+}
+
+#[test]
+fn test_cli_format_flag_overrides_extension() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("profile.cfg");
+
+    cmd()
+        .args(["init", "--format", "json", "-o"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    let content: String = std::fs::read_to_string(&output).unwrap();
+    let config: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(config["meta"]["username"], "your-github-username");
+
+    cmd()
+        .args(["config", "show", "--resolved", "--format", "json"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"username\": \"your-github-username\"",
+        ));
+}
+
+#[test]
+fn test_cli_set_add_remove_keep_comments() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("profile.toml");
+    std::fs::write(
+        &file,
+        "# My profile\n[meta]\nusername = \"alice\"  # GitHub login\nschema_version = 2\n\n# Find me here\n[social]\ntwitter = \"https://twitter.com/alice\"\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["set", "about.role", "Staff Engineer", "-f"])
+        .arg(&file)
+        .assert()
+        .success();
+    cmd()
+        .args(["add", "skill", "Rust", "-f"])
+        .arg(&file)
+        .assert()
+        .success();
+    cmd()
+        .args([
+            "add",
+            "project",
+            "alice/tool",
+            "--description",
+            "A tool",
+            "-f",
+        ])
+        .arg(&file)
+        .assert()
+        .success();
+    cmd()
+        .args([
+            "add",
+            "article",
+            "--title",
+            "Hello",
+            "--url",
+            "https://alice.dev/hello",
+            "-f",
+        ])
+        .arg(&file)
+        .assert()
+        .success();
+    cmd()
+        .args(["remove", "social.twitter", "-f"])
+        .arg(&file)
+        .assert()
+        .success();
+    cmd()
+        .args(["set", "stats.top_langs_count", "lots", "-f"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid value for stats.top_langs_count",
+        ));
+
+    let content: String = std::fs::read_to_string(&file).unwrap();
+    assert!(content.starts_with("# My profile\n"));
+    assert!(content.contains("username = \"alice\"  # GitHub login"));
+    let config: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(config["about"]["role"].as_str(), Some("Staff Engineer"));
+    assert_eq!(config["skills"]["languages"][0].as_str(), Some("Rust"));
+    assert_eq!(
+        config["projects"]["featured"][0]["description"].as_str(),
+        Some("A tool")
+    );
+    assert_eq!(
+        config["blog"]["articles"][0]["title"].as_str(),
+        Some("Hello")
+    );
+    assert!(config.get("social").is_none());
+}
+
+#[test]
+fn test_cli_init_preset() {
+    let dir = TempDir::new().unwrap();
+    let output = dir.path().join("profile.toml");
+
+    cmd()
+        .args(["init", "--preset", "rustacean", "-o"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rustacean preset"));
+
+    let content: String = std::fs::read_to_string(&output).unwrap();
+    let config: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(
+        config["layout"]["template"].as_str(),
+        Some("developer_card")
+    );
+    assert_eq!(config["skills"]["languages"][0].as_str(), Some("Rust"));
+
+    cmd()
+        .args(["init", "--list-presets"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("open-source-maintainer"))
+        .stdout(predicate::str::contains("data-scientist"));
+
+    cmd()
+        .args(["init", "--preset", "wizard", "-o"])
+        .arg(dir.path().join("other.toml"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown preset: wizard"));
+}
+
+#[test]
+fn test_cli_validate_reports_and_fixes_skills() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("profile.toml");
+    std::fs::write(
+        &file,
+        "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[skills]\n# What I write\nlanguages = [\"Rust\", \"Typescript \", \"golang\"]\ndatabases = [\"PostgresSQL\"]\nframeworks = [\"Vitet\"]\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["render", "--stdout"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "unknown skill \"PostgresSQL\" in skills.databases; did you mean PostgreSQL?",
+        ));
+    cmd()
+        .args(["validate", "-f"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown skill \"Typescript \" in skills.languages; did you mean TypeScript",
+        ))
+        .stderr(predicate::str::contains("3 unrecognized skill(s)"));
+
+    cmd()
+        .args(["validate", "--fix", "--file"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Fixed skills.languages: \"Typescript \" -> \"TypeScript\"",
+        ))
+        .stdout(predicate::str::contains(
+            "Fixed skills.languages: \"golang\" -> \"Go\"",
+        ))
+        .stdout(predicate::str::contains(
+            "Fixed skills.databases: \"PostgresSQL\" -> \"PostgreSQL\"",
+        ))
+        .stderr(predicate::str::contains(
+            "unknown skill \"Vitet\" in skills.frameworks; did you mean Vite, Vitest?",
+        ))
+        .stderr(predicate::str::contains("1 unrecognized skill(s)"));
+
+    let content: String = std::fs::read_to_string(&file).unwrap();
+    assert!(content.contains("# What I write\nlanguages = [\"Rust\", \"TypeScript\", \"Go\"]"));
+    assert!(content.contains("databases = [\"PostgreSQL\"]"));
+    assert!(content.contains("frameworks = [\"Vitet\"]"));
+
+    cmd()
+        .arg("validate")
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1 unrecognized skill(s)"));
+}
+
+#[test]
+fn test_cli_render_expands_social_handles() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("profile.toml");
+    std::fs::write(
+        &file,
+        "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[social]\ntwitter = \"@alice\"\nmastodon = \"@alice@hachyderm.io\"\nlinkedin = \"https://twitter.com/alice\"\nemail = \"alice@example.com\"\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["render", "--stdout"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(https://x.com/alice)"))
+        .stdout(predicate::str::contains(
+            "<a rel=\"me\" href=\"https://hachyderm.io/@alice\">",
+        ))
+        .stdout(predicate::str::contains("(mailto:alice@example.com)"))
+        .stderr(predicate::str::contains(
+            "social.linkedin points at twitter.com, not linkedin.com",
+        ));
+    cmd()
+        .args(["validate", "-f"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("1 problem(s) with [social] links"));
+}
+
+#[test]
+fn test_cli_services_select_providers_by_name() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("profile.toml");
+    std::fs::write(
+        &file,
+        "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[skills]\nlanguages = [\"Rust\"]\n\n[services]\nbadge = \"badgen\"\n",
+    )
+    .unwrap();
+
+    cmd()
+        .args(["render", "--stdout"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("https://badgen.net/badge/"))
+        .stdout(predicate::str::contains("img.shields.io").not());
+
+    std::fs::write(
+        &file,
+        "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[services]\nbadge = \"badgerz\"\n",
+    )
+    .unwrap();
+    cmd()
+        .args(["validate", "-f"])
+        .arg(&file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "services.badge = \"badgerz\" is not a known provider; expected shields or badgen",
+        ))
+        .stderr(predicate::str::contains("1 unknown provider(s)"));
+}
+
+#[test]
+fn test_cli_render_assets_dir_writes_local_svgs() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("profile.toml"),
+        "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[header]\nstyle = \"typing_svg\"\ntyping_lines = [\"Hello\"]\n\n[skills]\nlanguages = [\"Rust\"]\n\n[social]\ngithub = \"alice\"\n\n[sponsors]\ngithub_sponsors = true\n\n[extras]\npgp_fingerprint = \"ABCD1234\"\n\n[stats]\nstats_card = true\n",
+    )
+    .unwrap();
+
+    cmd()
+        .current_dir(dir.path())
+        .args([
+            "render",
+            "profile.toml",
+            "-o",
+            "docs/README.md",
+            "--assets-dir",
+            "assets",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote 5 asset(s) to assets"))
+        .stderr(predicate::str::contains(
+            "images still load from github-readme-stats.vercel.app",
+        ));
+
+    let readme: String = std::fs::read_to_string(dir.path().join("docs/README.md")).unwrap();
+    assert!(readme.contains("<img src=\"../assets/header.svg\""));
+    assert!(readme.contains("![Rust](../assets/rust.svg)"));
+    assert!(readme.contains("[![GitHub](../assets/github.svg)](https://github.com/alice)"));
+    assert!(readme.contains("[![Sponsor](../assets/sponsor.svg)]"));
+    assert!(readme.contains("![PGP](../assets/pgp-abcd1234.svg)"));
+    assert!(!readme.contains("img.shields.io"));
+    assert!(!readme.contains("readme-typing-svg"));
+    for name in ["header", "rust", "github", "sponsor", "pgp-abcd1234"] {
+        let svg: String =
+            std::fs::read_to_string(dir.path().join(format!("assets/{}.svg", name))).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    }
+}
+
+#[test]
+fn test_cli_render_assets_dir_logos_and_stale_files() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir(dir.path().join("icons")).unwrap();
+    std::fs::write(
+        dir.path().join("icons/rust.svg"),
+        "<svg role=\"img\" viewBox=\"0 0 24 24\" xmlns=\"http://www.w3.org/2000/svg\"><title>Rust</title><path d=\"M23.8 11.7z\"/></svg>",
+    )
+    .unwrap();
+    let profile = |languages: &str| {
+        std::fs::write(
+            dir.path().join("profile.toml"),
+            format!(
+                "[meta]\nusername = \"alice\"\nschema_version = 2\n\n[skills]\nlanguages = [{}]\n",
+                languages
+            ),
+        )
+        .unwrap();
+    };
+    let render = || {
+        cmd()
+            .current_dir(dir.path())
+            .args([
+                "render",
+                "profile.toml",
+                "--stdout",
+                "--assets-dir",
+                "assets",
+                "--logos-dir",
+                "icons",
+            ])
+            .assert()
+            .success()
+    };
+
+    profile("\"Rust\", \"Go\"");
+    render().stderr(predicate::str::contains(
+        "no logo for go in icons; those badges are drawn without one",
+    ));
+    let rust: String = std::fs::read_to_string(dir.path().join("assets/rust.svg")).unwrap();
+    assert!(rust.contains("d=\"M23.8 11.7z\""));
+    assert!(dir.path().join("assets/go.svg").exists());
+
+    profile("\"Rust\"");
+    render().stderr(predicate::str::contains("no logo").not());
+    assert!(dir.path().join("assets/rust.svg").exists());
+    assert!(!dir.path().join("assets/go.svg").exists());
+}
@@ -0,0 +1,305 @@
+//! Maintenance tasks, run with `cargo xtask <task>`.
+//!
+//! - `skill-icons [--check] <simple-icons>`: regenerate
+//!   `src/services/skill_icons.toml` from `xtask/skill_icons.toml` and the
+//!   icon data of a simple-icons checkout (or its `simple-icons.json`). With
+//!   `--check`, fail instead of writing if the catalog is out of date.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use serde::Deserialize;
+
+const USAGE: &str =
+    "usage: cargo xtask skill-icons [--check] <simple-icons checkout or simple-icons.json>";
+
+const CATALOG_HEADER: &str = "\
+# Built-in skill icon catalog.
+#
+# Generated by `cargo xtask skill-icons` from xtask/skill_icons.toml and the
+# simple-icons data (https://simpleicons.org); do not edit by hand. Each entry
+# maps a canonical skill name to its simple-icons slug and brand color.
+# Entries are grouped by the [skills] list they belong in; `aliases` are other
+# spellings that resolve to the same skill. Lookups are case-insensitive, so
+# aliases only need to cover genuinely different names.
+";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result: Result<(), String> = match args.first().map(String::as_str) {
+        Some("skill-icons") => skill_icons(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn skill_icons(args: &[String]) -> Result<(), String> {
+    let check: bool = args.iter().any(|arg: &String| arg == "--check");
+    let source: &String = args
+        .iter()
+        .find(|arg: &&String| !arg.starts_with("--"))
+        .ok_or_else(|| USAGE.to_string())?;
+
+    let root: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let spec: String = read(&root.join("xtask/skill_icons.toml"))?;
+    let data: String = read(&simple_icons_json(Path::new(source))?)?;
+    let catalog: String = generate(&spec, &data)?;
+
+    const CATALOG: &str = "src/services/skill_icons.toml";
+    let output: PathBuf = root.join(CATALOG);
+    if check {
+        if read(&output)? != catalog {
+            return Err(format!(
+                "{} is out of date; run `cargo xtask skill-icons {}`",
+                CATALOG, source
+            ));
+        }
+        println!("{} is up to date", CATALOG);
+    } else {
+        std::fs::write(&output, catalog)
+            .map_err(|e| format!("could not write {}: {}", CATALOG, e))?;
+        println!("Wrote {}", CATALOG);
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+/// The icon data file in a simple-icons checkout, which moved from `_data/`
+/// to `data/` over time, or `source` itself if it is a file.
+fn simple_icons_json(source: &Path) -> Result<PathBuf, String> {
+    if source.is_file() {
+        return Ok(source.to_path_buf());
+    }
+    ["_data/simple-icons.json", "data/simple-icons.json"]
+        .iter()
+        .map(|name: &&str| source.join(name))
+        .find(|path: &PathBuf| path.is_file())
+        .ok_or_else(|| format!("no simple-icons.json under {}", source.display()))
+}
+
+/// One skill in `xtask/skill_icons.toml`.
+#[derive(Deserialize)]
+struct SpecEntry {
+    slug: String,
+    /// Overrides the simple-icons brand color.
+    color: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+/// One icon in simple-icons' data.
+#[derive(Deserialize)]
+struct Icon {
+    title: String,
+    hex: String,
+    /// Only present when it differs from the slug derived from the title.
+    slug: Option<String>,
+}
+
+/// simple-icons.json is either `{ "icons": [...] }` or, in newer releases, a
+/// bare array.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IconData {
+    Wrapped { icons: Vec<Icon> },
+    Bare(Vec<Icon>),
+}
+
+/// The catalog for the skills in `spec`, with colors looked up in the
+/// simple-icons `data`. Every slug the spec uses must exist in `data`.
+fn generate(spec: &str, data: &str) -> Result<String, String> {
+    let spec: toml::Table = toml::from_str(spec).map_err(|e| format!("skill spec: {}", e))?;
+    let icons: Vec<Icon> =
+        match serde_json::from_str(data).map_err(|e| format!("simple-icons data: {}", e))? {
+            IconData::Wrapped { icons } | IconData::Bare(icons) => icons,
+        };
+    let hex_of = |slug: &str| {
+        icons
+            .iter()
+            .find(|icon: &&Icon| {
+                icon.slug
+                    .clone()
+                    .unwrap_or_else(|| title_to_slug(&icon.title))
+                    == slug
+            })
+            .map(|icon: &Icon| icon.hex.to_uppercase())
+    };
+
+    let mut out: String = CATALOG_HEADER.to_string();
+    let mut missing: Vec<String> = Vec::new();
+    for (category, entries) in &spec {
+        let entries: toml::Table = entries
+            .clone()
+            .try_into()
+            .map_err(|e| format!("skill spec [{}]: {}", category, e))?;
+        out.push_str(&format!("\n[{}]\n", category));
+        for (name, entry) in entries {
+            let entry: SpecEntry = entry
+                .try_into()
+                .map_err(|e| format!("skill spec {:?}: {}", name, e))?;
+            let color: String = match entry.color.or_else(|| hex_of(&entry.slug)) {
+                Some(color) => color,
+                None => {
+                    missing.push(format!("{} ({})", entry.slug, name));
+                    continue;
+                }
+            };
+            let aliases: String = if entry.aliases.is_empty() {
+                String::new()
+            } else {
+                let quoted: Vec<String> = entry.aliases.iter().map(|a: &String| quote(a)).collect();
+                format!(", aliases = [{}]", quoted.join(", "))
+            };
+            out.push_str(&format!(
+                "{} = {{ slug = {}, color = {}{} }}\n",
+                quote(&name),
+                quote(&entry.slug),
+                quote(&color),
+                aliases
+            ));
+        }
+    }
+    if !missing.is_empty() {
+        return Err(format!(
+            "simple-icons has no icon for {}",
+            missing.join(", ")
+        ));
+    }
+    Ok(out)
+}
+
+fn quote(s: &str) -> String {
+    toml::Value::String(s.to_string()).to_string()
+}
+
+/// simple-icons' `titleToSlug`: lowercase, spell out `+`, `.` and `&`, and
+/// drop everything else that is not an ASCII letter or digit. Accented
+/// letters keep their base letter.
+fn title_to_slug(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.to_lowercase().chars() {
+        match c {
+            '+' => slug.push_str("plus"),
+            '.' => slug.push_str("dot"),
+            '&' => slug.push_str("and"),
+            'đ' => slug.push('d'),
+            'ħ' => slug.push('h'),
+            'ı' => slug.push('i'),
+            'ĸ' => slug.push('k'),
+            'ŀ' | 'ł' => slug.push('l'),
+            'ß' => slug.push_str("ss"),
+            'ŧ' => slug.push('t'),
+            c if c.is_ascii_alphanumeric() => slug.push(c),
+            c => slug.extend(base_letter(c)),
+        }
+    }
+    slug
+}
+
+/// The ASCII letter an accented Latin letter decomposes to, if any.
+fn base_letter(c: char) -> Option<char> {
+    const FOLDS: &[(&str, char)] = &[
+        ("àáâãäåāăą", 'a'),
+        ("çćĉċč", 'c'),
+        ("ďđ", 'd'),
+        ("èéêëēĕėęě", 'e'),
+        ("ĝğġģ", 'g'),
+        ("ĥ", 'h'),
+        ("ìíîïĩīĭįı", 'i'),
+        ("ĵ", 'j'),
+        ("ķ", 'k'),
+        ("ĺļľ", 'l'),
+        ("ñńņňŉ", 'n'),
+        ("òóôõöōŏő", 'o'),
+        ("ŕŗř", 'r'),
+        ("śŝşš", 's'),
+        ("ţťŧ", 't'),
+        ("ùúûüũūŭůűų", 'u'),
+        ("ŵ", 'w'),
+        ("ýÿŷ", 'y'),
+        ("źżž", 'z'),
+    ];
+    FOLDS
+        .iter()
+        .find(|(accented, _): &&(&str, char)| accented.contains(c))
+        .map(|(_, base): &(&str, char)| *base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_to_slug() {
+        assert_eq!(title_to_slug("C++"), "cplusplus");
+        assert_eq!(title_to_slug("Node.js"), "nodedotjs");
+        assert_eq!(title_to_slug("AT&T"), "atandt");
+        assert_eq!(title_to_slug("GNU Bash"), "gnubash");
+        assert_eq!(title_to_slug("Pokémon"), "pokemon");
+    }
+
+    #[test]
+    fn test_generate() {
+        let spec = "[languages]\n\
+                    \"C++\" = { slug = \"cplusplus\", aliases = [\"cpp\"] }\n\
+                    \"Java\" = { slug = \"openjdk\", color = \"ED8B00\" }\n\n\
+                    [frameworks]\n\
+                    \"Vue\" = { slug = \"vuedotjs\" }\n";
+        let data = r#"{ "icons": [
+            { "title": "C++", "hex": "00599c", "source": "" },
+            { "title": "OpenJDK", "hex": "FFFFFF", "source": "" },
+            { "title": "Vue.js", "hex": "4FC08D", "source": "" }
+        ] }"#;
+        let catalog: String = generate(spec, data).unwrap();
+        assert!(catalog.starts_with(CATALOG_HEADER));
+        assert!(catalog.ends_with(
+            "\n[languages]\n\
+             \"C++\" = { slug = \"cplusplus\", color = \"00599C\", aliases = [\"cpp\"] }\n\
+             \"Java\" = { slug = \"openjdk\", color = \"ED8B00\" }\n\
+             \n[frameworks]\n\
+             \"Vue\" = { slug = \"vuedotjs\", color = \"4FC08D\" }\n"
+        ));
+    }
+
+    #[test]
+    fn test_generate_reads_bare_arrays_and_explicit_slugs() {
+        let spec = "[tools]\n\"Make\" = { slug = \"gnu\" }\n";
+        let data = r#"[{ "title": "GNU", "hex": "A42E2B", "slug": "gnu" }]"#;
+        assert!(generate(spec, data).unwrap().contains("color = \"A42E2B\""));
+    }
+
+    #[test]
+    fn test_generate_reports_missing_icons() {
+        let spec = "[tools]\n\"A\" = { slug = \"gone\" }\n\"B\" = { slug = \"alsogone\" }\n";
+        assert_eq!(
+            generate(spec, "[]").unwrap_err(),
+            "simple-icons has no icon for gone (A), alsogone (B)"
+        );
+    }
+
+    #[test]
+    fn test_checked_in_catalog_parses() {
+        let root: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let spec: String = read(&root.join("xtask/skill_icons.toml")).unwrap();
+        let catalog: String = read(&root.join("src/services/skill_icons.toml")).unwrap();
+        assert!(catalog.starts_with(CATALOG_HEADER));
+        // Every skill in the spec is in the catalog, in the same order.
+        let names = |source: &str| -> Vec<String> {
+            let table: toml::Table = toml::from_str(source).unwrap();
+            table
+                .values()
+                .flat_map(|group: &toml::Value| group.as_table().unwrap().keys().cloned())
+                .collect()
+        };
+        assert_eq!(names(&spec), names(&catalog));
+    }
+}